@@ -132,6 +132,10 @@ pub(crate) fn print_exported(text: &str) {
     print_colourful_prefix("Exported", text)
 }
 
+pub(crate) fn print_built(text: &str) {
+    print_colourful_prefix("Built", text)
+}
+
 pub(crate) fn print_checking(text: &str) {
     print_colourful_prefix("Checking", text)
 }
@@ -16,7 +16,10 @@ use gleam_core::{
     warning::WarningEmitter,
     Error, Result,
 };
-use std::{collections::HashSet, rc::Rc};
+use std::{
+    collections::{HashMap, HashSet},
+    rc::Rc,
+};
 
 pub fn command(options: CompilePackage) -> Result<()> {
     let ids = UniqueIdGenerator::new();
@@ -36,6 +39,7 @@ pub fn command(options: CompilePackage) -> Result<()> {
         },
         Target::Go => TargetCodegenConfiguration::Go {
             go_module_path: "example.com/todo".into(), // TODO
+            package_names: HashMap::new(),
         },
     };
 
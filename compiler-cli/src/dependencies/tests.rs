@@ -1203,7 +1203,11 @@ fn package_config(
                 location: None,
             },
         },
-        go: GoConfig {},
+        go: GoConfig {
+            goos: None,
+            goarch: None,
+            package_names: HashMap::new(),
+        },
         target: Target::Erlang,
         internal_modules: None,
     }
@@ -56,6 +56,7 @@ pub fn build(options: BuildOptions) -> Result<()> {
             warnings_as_errors: false,
             root_target_support: TargetSupport::Enforced,
             no_print_progress: false,
+            entrypoint: None,
         },
         crate::build::download_dependencies(cli::Reporter::new())?,
     )?;
@@ -146,6 +147,7 @@ pub fn publish() -> Result<()> {
             mode: Mode::Prod,
             target: None,
             no_print_progress: false,
+            entrypoint: None,
         },
         crate::build::download_dependencies(cli::Reporter::new())?,
     )?;
@@ -2,6 +2,7 @@ use camino::Utf8PathBuf;
 use gleam_core::{
     analyse::TargetSupport,
     build::{Codegen, Compile, Mode, Options, Target},
+    error::Error,
     Result,
 };
 
@@ -49,6 +50,7 @@ pub(crate) fn erlang_shipment() -> Result<()> {
             mode,
             target: Some(target),
             no_print_progress: false,
+            entrypoint: None,
         },
         crate::build::download_dependencies(crate::cli::Reporter::new())?,
     )?;
@@ -116,6 +118,60 @@ Your hex tarball has been generated in {}.
     Ok(())
 }
 
+/// Build the project for JavaScript in production mode, generating only the
+/// code reachable from the given module's main function (by default the
+/// module named after the package), along with an entrypoint calling it.
+pub(crate) fn javascript_prod(module: Option<String>) -> Result<()> {
+    let paths = crate::find_project_paths()?;
+    let config = crate::config::root_config()?;
+    let target = Target::JavaScript;
+    let mode = Mode::Prod;
+
+    if let Some(module) = &module {
+        if !crate::run::is_gleam_module(module) {
+            return Err(Error::InvalidModuleName {
+                module: module.to_owned(),
+            });
+        }
+    }
+    let module = module.unwrap_or_else(|| config.name.to_string());
+
+    let built = crate::build::main(
+        Options {
+            root_target_support: TargetSupport::Enforced,
+            warnings_as_errors: false,
+            codegen: Codegen::All,
+            compile: Compile::All,
+            mode,
+            target: Some(target),
+            no_print_progress: false,
+            entrypoint: Some(module.clone().into()),
+        },
+        crate::build::download_dependencies(crate::cli::Reporter::new())?,
+    )?;
+
+    // A module can not be the entrypoint if it does not have a public main
+    // function.
+    let _ = crate::run::get_or_suggest_main_function(built, &module, target)?;
+
+    let entrypoint = crate::run::write_javascript_entrypoint(&paths, mode, &config.name, &module)?;
+    let out = paths.build_directory_for_target(mode, target);
+
+    crate::cli::print_exported(&config.name);
+
+    println!(
+        "
+Your production JavaScript has been generated to {out}.
+
+It can be copied to a server and run with a JavaScript runtime.
+
+    node {entrypoint}
+",
+    );
+
+    Ok(())
+}
+
 pub fn javascript_prelude() -> Result<()> {
     print!("{}", gleam_core::javascript::PRELUDE);
     Ok(())
@@ -137,6 +193,7 @@ pub fn package_interface(path: Utf8PathBuf) -> Result<()> {
             warnings_as_errors: false,
             root_target_support: TargetSupport::Enforced,
             no_print_progress: false,
+            entrypoint: None,
         },
         crate::build::download_dependencies(crate::cli::Reporter::new())?,
     )?;
@@ -5,7 +5,7 @@ use gleam_core::{
         BeamCompiler, CommandExecutor, Content, DirEntry, FileSystemReader, FileSystemWriter,
         OutputFile, ReadDir, Stdio, WrappedReader,
     },
-    language_server::{DownloadDependencies, Locker, MakeLocker},
+    language_server::{CommandOutput, DownloadDependencies, Locker, MakeLocker, RunGleamCommand},
     manifest::Manifest,
     paths::ProjectPaths,
     warning::WarningEmitterIO,
@@ -15,8 +15,8 @@ use std::{
     collections::HashSet,
     fmt::Debug,
     fs::File,
-    io::{self, BufRead, BufReader, Write},
-    sync::{Arc, Mutex, OnceLock},
+    io::{self, BufRead, BufReader, Read, Write},
+    sync::{mpsc, Arc, Mutex, OnceLock},
     time::SystemTime,
 };
 
@@ -250,6 +250,63 @@ impl DownloadDependencies for ProjectIO {
     }
 }
 
+impl RunGleamCommand for ProjectIO {
+    fn run_gleam_command(
+        &self,
+        paths: &ProjectPaths,
+        arguments: Vec<String>,
+        mut output: Box<dyn FnMut(CommandOutput) + Send>,
+    ) -> Result<()> {
+        tracing::trace!(args=?arguments.join(" "), "gleam_command_run");
+        let shell_error = |error: io::Error| Error::ShellCommand {
+            program: "gleam".into(),
+            err: Some(error.kind()),
+        };
+
+        // The command is run by this very executable in a separate process so
+        // that what it prints doesn't end up mixed with the language server's
+        // own output.
+        let program = std::env::current_exe().map_err(shell_error)?;
+        let mut child = std::process::Command::new(program)
+            .args(&arguments)
+            .current_dir(paths.root())
+            .stdin(std::process::Stdio::null())
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped())
+            .spawn()
+            .map_err(shell_error)?;
+        let stdout = child.stdout.take().expect("gleam command stdout");
+        let stderr = child.stderr.take().expect("gleam command stderr");
+
+        let _ = std::thread::spawn(move || {
+            let (sender, receiver) = mpsc::channel();
+            let stderr_sender = sender.clone();
+            let _ = std::thread::spawn(move || forward_lines(stdout, sender));
+            let _ = std::thread::spawn(move || forward_lines(stderr, stderr_sender));
+
+            // Lines are received until both streams have been closed.
+            for line in receiver {
+                output(CommandOutput::Line(line));
+            }
+            let code = child
+                .wait()
+                .ok()
+                .and_then(|status| status.code())
+                .unwrap_or(-1);
+            output(CommandOutput::Exited(code));
+        });
+        Ok(())
+    }
+}
+
+fn forward_lines(reader: impl Read, sender: mpsc::Sender<String>) {
+    for line in BufReader::new(reader).lines().map_while(Result::ok) {
+        if sender.send(line).is_err() {
+            break;
+        }
+    }
+}
+
 pub fn delete_directory(dir: &Utf8Path) -> Result<(), Error> {
     tracing::trace!(path=?dir, "deleting_directory");
     if dir.exists() {
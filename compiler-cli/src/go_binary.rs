@@ -0,0 +1,191 @@
+use camino::Utf8PathBuf;
+use gleam_core::{
+    analyse::TargetSupport,
+    build::{Codegen, Compile, Mode, Target},
+    error::Error,
+    io::{CommandExecutor, Stdio},
+    Result,
+};
+
+use crate::{fs::ProjectIO, run::GO_EXECUTABLE};
+
+#[derive(Debug)]
+pub struct Options {
+    pub target: Option<Target>,
+    pub module: Option<String>,
+    pub mode: Mode,
+    pub warnings_as_errors: bool,
+    pub no_print_progress: bool,
+}
+
+/// Build a native executable for the Go target, running the main function
+/// of the given module (by default the module named after the package).
+///
+/// The executable is written to `build/<mode>/go/bin/`, named after the last
+/// segment of the module name. In production mode it is built without debug
+/// information or file system paths, and without cgo so it is statically
+/// linked. The `go.goos` and `go.goarch` settings of gleam.toml are used to
+/// cross-compile for another platform.
+pub fn build(options: Options) -> Result<()> {
+    let paths = crate::find_project_paths()?;
+    let root_config = crate::config::root_config()?;
+
+    if let Some(module) = &options.module {
+        if !crate::run::is_gleam_module(module) {
+            return Err(Error::InvalidModuleName {
+                module: module.to_owned(),
+            });
+        }
+    }
+    let module = options
+        .module
+        .unwrap_or_else(|| root_config.name.to_string());
+
+    let target = options.target.unwrap_or(root_config.target);
+    if target != Target::Go {
+        return Err(Error::UnsupportedBinaryTarget { target });
+    }
+
+    let manifest = if options.no_print_progress {
+        crate::build::download_dependencies(gleam_core::build::NullTelemetry)?
+    } else {
+        crate::build::download_dependencies(crate::cli::Reporter::new())?
+    };
+
+    let built = crate::build::main(
+        gleam_core::build::Options {
+            root_target_support: TargetSupport::Enforced,
+            warnings_as_errors: options.warnings_as_errors,
+            codegen: Codegen::All,
+            compile: Compile::All,
+            mode: options.mode,
+            target: Some(target),
+            no_print_progress: options.no_print_progress,
+            entrypoint: Some(module.clone().into()),
+        },
+        manifest,
+    )?;
+
+    // A module can not be the entrypoint if it does not have a public main
+    // function.
+    let _ = crate::run::get_or_suggest_main_function(built, &module, target)?;
+
+    let entry = crate::run::write_go_entrypoint(&paths, options.mode, &root_config.name, &module)?;
+
+    let goos = root_config.go.goos.as_deref();
+    let goarch = root_config.go.goarch.as_deref();
+    let binary = paths
+        .go_binary_directory(options.mode)
+        .join(binary_name(&module, goos));
+    crate::fs::mkdir(paths.go_binary_directory(options.mode))?;
+
+    let status = ProjectIO::new().exec(
+        GO_EXECUTABLE,
+        &go_build_arguments(options.mode, &binary),
+        &go_build_environment(options.mode, goos, goarch),
+        Some(&entry),
+        Stdio::Inherit,
+    )?;
+    if status != 0 {
+        return Err(Error::ShellCommand {
+            program: format!("{GO_EXECUTABLE} build"),
+            err: None,
+        });
+    }
+
+    crate::cli::print_built(binary.as_str());
+    Ok(())
+}
+
+/// The executable is named after the last segment of the entrypoint module,
+/// with the `.exe` extension when it is built for Windows.
+fn binary_name(module: &str, goos: Option<&str>) -> String {
+    let name = module.rsplit('/').next().unwrap_or(module);
+    let windows = match goos {
+        Some(goos) => goos == "windows",
+        None => cfg!(target_os = "windows"),
+    };
+    if windows {
+        format!("{name}.exe")
+    } else {
+        name.into()
+    }
+}
+
+fn go_build_arguments(mode: Mode, binary: &Utf8PathBuf) -> Vec<String> {
+    let mut args = vec!["build".to_string(), "-o".into(), binary.to_string()];
+    match mode {
+        Mode::Prod => {
+            // Strip the symbol table and DWARF debug information, and keep
+            // the paths of the build machine out of the executable.
+            args.push("-trimpath".into());
+            args.push("-ldflags=-s -w".into());
+        }
+        Mode::Dev | Mode::Lsp => (),
+    }
+    args.push(".".into());
+    args
+}
+
+fn go_build_environment(
+    mode: Mode,
+    goos: Option<&str>,
+    goarch: Option<&str>,
+) -> Vec<(&'static str, String)> {
+    let mut env = vec![];
+    if let Some(goos) = goos {
+        env.push(("GOOS", goos.to_string()));
+    }
+    if let Some(goarch) = goarch {
+        env.push(("GOARCH", goarch.to_string()));
+    }
+    match mode {
+        Mode::Prod => env.push(("CGO_ENABLED", "0".into())),
+        Mode::Dev | Mode::Lsp => (),
+    }
+    env
+}
+
+#[test]
+fn binary_name_is_last_module_segment() {
+    assert_eq!(binary_name("app/http/server", Some("linux")), "server");
+    assert_eq!(binary_name("app", Some("darwin")), "app");
+}
+
+#[test]
+fn binary_name_for_windows() {
+    assert_eq!(binary_name("app/server", Some("windows")), "server.exe");
+}
+
+#[test]
+fn go_build_arguments_in_production_mode() {
+    let binary = Utf8PathBuf::from("build/prod/go/bin/app");
+    assert_eq!(
+        go_build_arguments(Mode::Prod, &binary),
+        vec![
+            "build",
+            "-o",
+            "build/prod/go/bin/app",
+            "-trimpath",
+            "-ldflags=-s -w",
+            "."
+        ]
+    );
+    assert_eq!(
+        go_build_arguments(Mode::Dev, &binary),
+        vec!["build", "-o", "build/prod/go/bin/app", "."]
+    );
+}
+
+#[test]
+fn go_build_environment_cross_compilation() {
+    assert_eq!(
+        go_build_environment(Mode::Prod, Some("linux"), Some("arm64")),
+        vec![
+            ("GOOS", "linux".to_string()),
+            ("GOARCH", "arm64".into()),
+            ("CGO_ENABLED", "0".into())
+        ]
+    );
+    assert_eq!(go_build_environment(Mode::Dev, None, None), vec![]);
+}
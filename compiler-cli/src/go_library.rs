@@ -0,0 +1,150 @@
+use gleam_core::{
+    analyse::TargetSupport,
+    build::{Codegen, Compile, Mode, Target},
+    error::Error,
+    go::library::library,
+    io::FileSystemWriter,
+    Result,
+};
+
+use crate::{fs::ProjectIO, go_shell::GO_MODULE_PATH};
+
+#[derive(Debug)]
+pub struct Options {
+    pub target: Option<Target>,
+    pub modules: Vec<String>,
+    pub mode: Mode,
+    pub warnings_as_errors: bool,
+    pub no_print_progress: bool,
+}
+
+/// Build the project for the Go target along with a facade package through
+/// which Go code can call the public functions of the given modules, using Go
+/// types rather than the types Gleam values are represented with.
+///
+/// The facade is written to `build/<mode>/go/<package>/`, alongside a README
+/// describing its API, and is imported as `<go module path>/<package>`.
+pub fn build(options: Options) -> Result<()> {
+    let paths = crate::find_project_paths()?;
+    let root_config = crate::config::root_config()?;
+    let target = library_target(&options, root_config.target)?;
+
+    let manifest = if options.no_print_progress {
+        crate::build::download_dependencies(gleam_core::build::NullTelemetry)?
+    } else {
+        crate::build::download_dependencies(crate::cli::Reporter::new())?
+    };
+
+    let built = crate::build::main(
+        gleam_core::build::Options {
+            root_target_support: TargetSupport::Enforced,
+            warnings_as_errors: options.warnings_as_errors,
+            codegen: Codegen::All,
+            compile: Compile::All,
+            mode: options.mode,
+            target: Some(target),
+            no_print_progress: options.no_print_progress,
+            entrypoint: None,
+        },
+        manifest,
+    )?;
+
+    let modules = options
+        .modules
+        .iter()
+        .map(|name| {
+            built
+                .root_package
+                .modules
+                .iter()
+                .find(|module| module.name == name.as_str())
+                .map(|module| &module.ast)
+                .ok_or_else(|| Error::ModuleDoesNotExist {
+                    module: name.into(),
+                    suggestion: None,
+                })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let generated = library(&root_config.name, &modules, GO_MODULE_PATH);
+    let directory = paths
+        .build_directory_for_target(options.mode, Target::Go)
+        .join(root_config.name.as_str());
+    let io = ProjectIO::new();
+    io.write(&directory.join("gleam_library.go"), &generated.source)?;
+    io.write(&directory.join("README.md"), &generated.readme)?;
+
+    crate::cli::print_built(directory.as_str());
+    Ok(())
+}
+
+/// The target to build a library for, which is the project's target unless
+/// another one is given. Libraries can only be built for Go, out of modules
+/// with valid names.
+fn library_target(options: &Options, project_target: Target) -> Result<Target> {
+    for module in &options.modules {
+        if !crate::run::is_gleam_module(module) {
+            return Err(Error::InvalidModuleName {
+                module: module.to_owned(),
+            });
+        }
+    }
+
+    let target = options.target.unwrap_or(project_target);
+    if target != Target::Go {
+        return Err(Error::UnsupportedLibraryTarget { target });
+    }
+    Ok(target)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(target: Option<Target>, modules: &[&str]) -> Options {
+        Options {
+            target,
+            modules: modules.iter().map(|module| module.to_string()).collect(),
+            mode: Mode::Dev,
+            warnings_as_errors: false,
+            no_print_progress: true,
+        }
+    }
+
+    #[test]
+    fn library_target_defaults_to_the_project_target() {
+        assert_eq!(
+            library_target(&options(None, &["wibble/wobble"]), Target::Go),
+            Ok(Target::Go)
+        );
+    }
+
+    #[test]
+    fn library_target_must_be_go() {
+        assert_eq!(
+            library_target(&options(None, &["wibble"]), Target::Erlang),
+            Err(Error::UnsupportedLibraryTarget {
+                target: Target::Erlang
+            })
+        );
+        assert_eq!(
+            library_target(&options(Some(Target::JavaScript), &["wibble"]), Target::Go),
+            Err(Error::UnsupportedLibraryTarget {
+                target: Target::JavaScript
+            })
+        );
+    }
+
+    #[test]
+    fn library_modules_must_be_valid_module_names() {
+        assert_eq!(
+            library_target(
+                &options(Some(Target::Go), &["wibble", "Wobble.gleam"]),
+                Target::Go
+            ),
+            Err(Error::InvalidModuleName {
+                module: "Wobble.gleam".into()
+            })
+        );
+    }
+}
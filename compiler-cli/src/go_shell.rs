@@ -0,0 +1,275 @@
+use std::io::{BufRead, Write};
+
+use camino::Utf8Path;
+use ecow::EcoString;
+use gleam_core::{
+    analyse::TargetSupport,
+    build::{Codegen, Compile, Mode, Options, Target},
+    config::PackageConfig,
+    error::Error,
+    go::shell::{EntryKind, Session, MODULE_NAME},
+    io::{CommandExecutor, FileSystemWriter, Stdio},
+    type_::ModuleInterface,
+    Result,
+};
+
+use crate::{cli, fs::ProjectIO, run::GO_EXECUTABLE};
+
+/// The path of the Go module the project is compiled into.
+pub(crate) const GO_MODULE_PATH: &str = "example.com/todo";
+
+/// Start a Gleam shell for the Go target.
+///
+/// Each entry is type checked against the project's modules and compiled into
+/// a Go program in `build/dev/go/gleam_shell/`, which is run to print the
+/// entry's value. Imports and `let` bindings are remembered and replayed before
+/// every later entry, so the side effects of a binding happen again each time
+/// it is replayed.
+pub fn command() -> Result<()> {
+    let paths = crate::find_project_paths()?;
+    let root_config = crate::config::root_config()?;
+
+    let built = crate::build::main(
+        Options {
+            root_target_support: TargetSupport::Enforced,
+            warnings_as_errors: false,
+            codegen: Codegen::All,
+            compile: Compile::All,
+            mode: Mode::Dev,
+            target: Some(Target::Go),
+            no_print_progress: false,
+            entrypoint: None,
+        },
+        crate::build::download_dependencies(cli::Reporter::new())?,
+    )?;
+
+    // Don't exit on ctrl+c as it is used to stop the running entry
+    ctrlc::set_handler(move || {}).expect("Error setting Ctrl-C handler");
+
+    let io = ProjectIO::new();
+    let shell_directory = paths
+        .build_directory_for_target(Mode::Dev, Target::Go)
+        .join(MODULE_NAME);
+    let mut session = Session::new(built.ids().clone());
+    let mut stdin = std::io::stdin().lock();
+
+    cli::print_running("Gleam shell");
+
+    while let Some(entry) = read_entry(&mut stdin)? {
+        if entry.trim().is_empty() {
+            continue;
+        }
+        run_entry(
+            &mut session,
+            &entry,
+            &root_config,
+            built.module_interfaces(),
+            &shell_directory,
+            &io,
+            &io,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Compiles and runs an entry, recording it in the session if it succeeded.
+/// Errors in the entry are printed rather than returned, so the shell carries
+/// on with the next one.
+fn run_entry(
+    session: &mut Session,
+    entry: &str,
+    config: &PackageConfig,
+    importable_modules: &im::HashMap<EcoString, ModuleInterface>,
+    shell_directory: &Utf8Path,
+    files: &impl FileSystemWriter,
+    executor: &impl CommandExecutor,
+) -> Result<()> {
+    let compiled = match session.compile(entry, config, importable_modules, GO_MODULE_PATH) {
+        Ok(compiled) => compiled,
+        Err(error) => {
+            print_error(&error);
+            return Ok(());
+        }
+    };
+
+    // An import has nothing to run, it only needs to type check
+    if compiled.kind == EntryKind::Import {
+        session.record(entry);
+        return Ok(());
+    }
+
+    files.write(
+        &shell_directory.join(MODULE_NAME).join("gleam_generated.go"),
+        &compiled.module,
+    )?;
+    files.write(&shell_directory.join("main.go"), &compiled.main)?;
+
+    let status = executor.exec(
+        GO_EXECUTABLE,
+        &["run".into(), ".".into()],
+        &[],
+        Some(shell_directory),
+        Stdio::Inherit,
+    )?;
+    if status == 0 {
+        session.record(entry);
+    }
+    Ok(())
+}
+
+/// Reads lines until they form a complete entry, returning `None` once the
+/// input has ended.
+fn read_entry(stdin: &mut impl BufRead) -> Result<Option<String>> {
+    let mut entry = String::new();
+    let mut prompt = "> ";
+    loop {
+        print!("{prompt}");
+        std::io::stdout().flush().map_err(stdio_error)?;
+
+        let read = stdin.read_line(&mut entry).map_err(stdio_error)?;
+        if read == 0 {
+            println!();
+            return Ok(if entry.trim().is_empty() {
+                None
+            } else {
+                Some(entry)
+            });
+        }
+        if !Session::is_incomplete(&entry) {
+            return Ok(Some(entry));
+        }
+        prompt = "... ";
+    }
+}
+
+fn stdio_error(error: std::io::Error) -> Error {
+    Error::StandardIo {
+        action: gleam_core::error::StandardIoAction::Read,
+        err: Some(error.kind()),
+    }
+}
+
+fn print_error(error: &Error) {
+    let stderr = cli::stderr_buffer_writer();
+    let mut buffer = stderr.buffer();
+    error.pretty(&mut buffer);
+    stderr.print(&buffer).expect("Error writing error");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gleam_core::{
+        io::memory::InMemoryFileSystem,
+        type_::{build_prelude, PRELUDE_MODULE_NAME},
+        uid::UniqueIdGenerator,
+    };
+
+    /// Runs every program with the same exit status instead of running Go.
+    struct ExitStatus(i32);
+
+    impl CommandExecutor for ExitStatus {
+        fn exec(
+            &self,
+            _program: &str,
+            _args: &[String],
+            _env: &[(&str, String)],
+            _cwd: Option<&Utf8Path>,
+            _stdio: Stdio,
+        ) -> Result<i32> {
+            Ok(self.0)
+        }
+    }
+
+    fn read_entries(input: &str) -> Vec<String> {
+        let mut input = input.as_bytes();
+        std::iter::from_fn(|| read_entry(&mut input).expect("read entry")).collect()
+    }
+
+    fn run_entries(entries: &[&str], status: i32) -> (Session, InMemoryFileSystem) {
+        let ids = UniqueIdGenerator::new();
+        let mut session = Session::new(ids.clone());
+        let files = InMemoryFileSystem::new();
+        for entry in entries {
+            run_entry(
+                &mut session,
+                entry,
+                &PackageConfig::default(),
+                &modules(&ids),
+                Utf8Path::new("/shell"),
+                &files,
+                &ExitStatus(status),
+            )
+            .expect("run entry");
+        }
+        (session, files)
+    }
+
+    fn modules(ids: &UniqueIdGenerator) -> im::HashMap<EcoString, ModuleInterface> {
+        im::hashmap! { PRELUDE_MODULE_NAME.into() => build_prelude(ids) }
+    }
+
+    fn compiles(session: &Session, entry: &str) -> bool {
+        let ids = UniqueIdGenerator::new();
+        session
+            .compile(
+                entry,
+                &PackageConfig::default(),
+                &modules(&ids),
+                GO_MODULE_PATH,
+            )
+            .is_ok()
+    }
+
+    #[test]
+    fn read_entry_reads_one_line_per_complete_entry() {
+        assert_eq!(
+            read_entries("1 + 2\nlet x = 3\n"),
+            vec!["1 + 2\n", "let x = 3\n"]
+        );
+    }
+
+    #[test]
+    fn read_entry_keeps_reading_until_the_entry_is_complete() {
+        assert_eq!(
+            read_entries("[1,\n  2]\nlet x = {\n  1\n}\n"),
+            vec!["[1,\n  2]\n", "let x = {\n  1\n}\n"]
+        );
+    }
+
+    #[test]
+    fn read_entry_returns_an_unfinished_entry_at_the_end_of_the_input() {
+        assert_eq!(read_entries("[1,\n"), vec!["[1,\n"]);
+        assert_eq!(read_entries(""), Vec::<String>::new());
+        assert_eq!(read_entries("  \n"), vec!["  \n"]);
+    }
+
+    #[test]
+    fn run_entry_writes_the_program_and_replays_bindings_that_ran() {
+        let (session, files) = run_entries(&["let x = 1"], 0);
+        assert!(files.exists(Utf8Path::new("/shell/main.go")));
+        assert!(files.exists(Utf8Path::new("/shell/gleam_shell/gleam_generated.go")));
+        assert!(compiles(&session, "x + 1"));
+    }
+
+    #[test]
+    fn run_entry_does_not_replay_bindings_that_failed_to_run() {
+        let (session, _) = run_entries(&["let x = 1"], 1);
+        assert!(!compiles(&session, "x + 1"));
+    }
+
+    #[test]
+    fn run_entry_does_not_replay_bindings_that_failed_to_compile() {
+        let (session, files) = run_entries(&["let x = 1 + \"a\""], 0);
+        assert!(!files.exists(Utf8Path::new("/shell/main.go")));
+        assert!(!compiles(&session, "x"));
+    }
+
+    #[test]
+    fn run_entry_replays_imports_without_running_them() {
+        let (session, files) = run_entries(&["import gleam"], 1);
+        assert!(!files.exists(Utf8Path::new("/shell/main.go")));
+        assert!(compiles(&session, "gleam.Nil"));
+    }
+}
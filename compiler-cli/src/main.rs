@@ -61,6 +61,9 @@ mod export;
 mod fix;
 mod format;
 mod fs;
+mod go_binary;
+mod go_library;
+mod go_shell;
 mod hex;
 mod http;
 mod lsp;
@@ -139,6 +142,7 @@ struct TreeOptions {
 )]
 enum Command {
     /// Build the project
+    #[command(group = clap::ArgGroup::new("package").args(["binary", "library"]).multiple(true))]
     Build {
         /// Emit compile time warnings as errors
         #[arg(long)]
@@ -150,6 +154,25 @@ enum Command {
         /// Don't print progress information
         #[clap(long)]
         no_print_progress: bool,
+
+        /// Build a native executable. Only supported by the Go target
+        #[arg(long)]
+        binary: bool,
+
+        /// The module whose main function the executable runs
+        #[arg(short, long, requires = "binary")]
+        module: Option<String>,
+
+        /// Build a stripped and optimised executable, or a library, in
+        /// production mode
+        #[arg(long, requires = "package")]
+        prod: bool,
+
+        /// Generate a Go package through which Go code can call the public
+        /// functions of the given module. Can be given more than once. Only
+        /// supported by the Go target
+        #[arg(long, value_name = "MODULE", conflicts_with = "binary")]
+        library: Vec<String>,
     },
 
     /// Type check the project
@@ -208,8 +231,11 @@ enum Command {
     /// Rewrite deprecated Gleam code
     Fix,
 
-    /// Start an Erlang shell
-    Shell,
+    /// Start an Erlang shell, or a Gleam shell for the Go target
+    Shell {
+        #[arg(short, long, ignore_case = true, help = target_doc())]
+        target: Option<Target>,
+    },
 
     /// Run the project
     #[command(trailing_var_arg = true)]
@@ -299,6 +325,13 @@ pub enum ExportTarget {
     ErlangShipment,
     /// The package bundled into a tarball, suitable for publishing to Hex
     HexTarball,
+    /// Production JavaScript with only the code reachable from a module's main
+    /// function, suitable for deployment
+    JavascriptProd {
+        /// The module to run (by default the module named after the package)
+        #[arg(short, long)]
+        module: Option<String>,
+    },
     /// The JavaScript prelude module
     JavascriptPrelude,
     /// The TypeScript prelude module
@@ -483,6 +516,39 @@ fn main() {
             target,
             warnings_as_errors,
             no_print_progress,
+            binary: true,
+            module,
+            prod,
+            ..
+        } => go_binary::build(go_binary::Options {
+            target,
+            module,
+            mode: if prod { Mode::Prod } else { Mode::Dev },
+            warnings_as_errors,
+            no_print_progress,
+        }),
+
+        Command::Build {
+            target,
+            warnings_as_errors,
+            no_print_progress,
+            library,
+            prod,
+            ..
+        } if !library.is_empty() => go_library::build(go_library::Options {
+            target,
+            modules: library,
+            mode: if prod { Mode::Prod } else { Mode::Dev },
+            warnings_as_errors,
+            no_print_progress,
+        }),
+
+        Command::Build {
+            target,
+            warnings_as_errors,
+            no_print_progress,
+            binary: false,
+            ..
         } => command_build(target, warnings_as_errors, no_print_progress),
 
         Command::Check { target } => command_check(target),
@@ -515,7 +581,7 @@ fn main() {
 
         Command::New(options) => new::create(options, COMPILER_VERSION),
 
-        Command::Shell => shell::command(),
+        Command::Shell { target } => shell::command(target),
 
         Command::Run {
             target,
@@ -567,6 +633,7 @@ fn main() {
 
         Command::Export(ExportTarget::ErlangShipment) => export::erlang_shipment(),
         Command::Export(ExportTarget::HexTarball) => export::hex_tarball(),
+        Command::Export(ExportTarget::JavascriptProd { module }) => export::javascript_prod(module),
         Command::Export(ExportTarget::JavascriptPrelude) => export::javascript_prelude(),
         Command::Export(ExportTarget::TypescriptPrelude) => export::typescript_prelude(),
         Command::Export(ExportTarget::PackageInterface { output }) => {
@@ -598,6 +665,7 @@ fn command_check(target: Option<Target>) -> Result<()> {
             mode: Mode::Dev,
             target,
             no_print_progress: false,
+            entrypoint: None,
         },
         build::download_dependencies(cli::Reporter::new())?,
     )?;
@@ -623,6 +691,7 @@ fn command_build(
             mode: Mode::Dev,
             target,
             no_print_progress,
+            entrypoint: None,
         },
         manifest,
     )?;
@@ -14,6 +14,9 @@ use gleam_core::{
 
 use crate::{config::PackageKind, fs::ProjectIO};
 
+/// The Go toolchain executable used to run and build Go programs.
+pub(crate) const GO_EXECUTABLE: &str = "go1.24rc2";
+
 #[derive(Debug, Clone, Copy)]
 pub enum Which {
     Src,
@@ -95,6 +98,7 @@ pub fn command(
             PackageKind::Dependency => TargetSupport::NotEnforced,
         },
         no_print_progress,
+        entrypoint: None,
     };
 
     let built = crate::build::main(options, manifest)?;
@@ -182,7 +186,7 @@ fn run_go(
     arguments: Vec<String>,
 ) -> Result<i32, Error> {
     let mut args = vec!["run".to_string()];
-    let entry = write_go_entrypoint(paths, package, module)?;
+    let entry = write_go_entrypoint(paths, Mode::Dev, package, module)?;
 
     args.push(entry.to_string());
 
@@ -190,23 +194,24 @@ fn run_go(
         args.push(argument);
     }
 
-    ProjectIO::new().exec("go1.24rc2", &args, &[], Some(&entry), Stdio::Inherit)
+    ProjectIO::new().exec(GO_EXECUTABLE, &args, &[], Some(&entry), Stdio::Inherit)
 }
 
-fn write_go_entrypoint(
+/// Writes a `main.go` calling the module's main function into the root of the
+/// Go build directory, returning the directory it can be run or built from.
+pub(crate) fn write_go_entrypoint(
     paths: &ProjectPaths,
+    mode: Mode,
     package: &str,
     module: &str,
 ) -> Result<Utf8PathBuf, Error> {
-    let proj_path = paths
-        .build_directory_for_target(Mode::Dev, Target::Go)
-        .to_path_buf();
+    let proj_path = paths.build_directory_for_target(mode, Target::Go);
     let main_path = proj_path.join("main.go");
 
     let module = format!(
         r#"package main
 
-import {module}_P "example.com/todo/{package}/{module}"
+import entry_P "example.com/todo/{package}/{module}"
 import "flag"
 import "log"
 import "os"
@@ -244,7 +249,7 @@ func main() {{
         defer pprof.StopCPUProfile()
     }}
 
-    {module}_P.Main()
+    entry_P.Main()
 
     if *memprofile != "" {{
         log.Printf("Writing memory profile data to %s\n", *memprofile)
@@ -272,7 +277,7 @@ fn run_javascript_bun(
     arguments: Vec<String>,
 ) -> Result<i32, Error> {
     let mut args = vec!["run".to_string()];
-    let entry = write_javascript_entrypoint(paths, package, module)?;
+    let entry = write_javascript_entrypoint(paths, Mode::Dev, package, module)?;
 
     args.push(entry.to_string());
 
@@ -290,7 +295,7 @@ fn run_javascript_node(
     arguments: Vec<String>,
 ) -> Result<i32, Error> {
     let mut args = vec![];
-    let entry = write_javascript_entrypoint(paths, package, module)?;
+    let entry = write_javascript_entrypoint(paths, Mode::Dev, package, module)?;
 
     args.push(entry.to_string());
 
@@ -301,13 +306,14 @@ fn run_javascript_node(
     ProjectIO::new().exec("node", &args, &[], None, Stdio::Inherit)
 }
 
-fn write_javascript_entrypoint(
+pub(crate) fn write_javascript_entrypoint(
     paths: &ProjectPaths,
+    mode: Mode,
     package: &str,
     module: &str,
 ) -> Result<Utf8PathBuf, Error> {
     let path = paths
-        .build_directory_for_package(Mode::Dev, Target::JavaScript, package)
+        .build_directory_for_package(mode, Target::JavaScript, package)
         .to_path_buf()
         .join("gleam.main.mjs");
     let module = format!(
@@ -385,7 +391,7 @@ fn run_javascript_deno(
         );
     }
 
-    let entrypoint = write_javascript_entrypoint(paths, package, module)?;
+    let entrypoint = write_javascript_entrypoint(paths, Mode::Dev, package, module)?;
     args.push(entrypoint.to_string());
 
     for argument in arguments.into_iter() {
@@ -407,7 +413,7 @@ fn add_deno_flag(args: &mut Vec<String>, flag: &str, flags: &DenoFlag) {
 }
 
 /// Check if a module name is a valid gleam module name.
-fn is_gleam_module(module: &str) -> bool {
+pub(crate) fn is_gleam_module(module: &str) -> bool {
     use regex::Regex;
     static RE: OnceLock<Regex> = OnceLock::new();
 
@@ -423,7 +429,7 @@ fn is_gleam_module(module: &str) -> bool {
 }
 
 /// If provided module is not executable, suggest a possible valid module.
-fn get_or_suggest_main_function(
+pub(crate) fn get_or_suggest_main_function(
     built: Built,
     module: &str,
     target: Target,
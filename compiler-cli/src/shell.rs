@@ -5,7 +5,15 @@ use gleam_core::{
 };
 use std::process::Command;
 
-pub fn command() -> Result<(), Error> {
+pub fn command(target: Option<Target>) -> Result<(), Error> {
+    match target {
+        None | Some(Target::Erlang) => erlang_shell(),
+        Some(Target::Go) => crate::go_shell::command(),
+        Some(target @ Target::JavaScript) => Err(Error::UnsupportedShellTarget { target }),
+    }
+}
+
+fn erlang_shell() -> Result<(), Error> {
     let paths = crate::find_project_paths()?;
 
     // Build project
@@ -18,6 +26,7 @@ pub fn command() -> Result<(), Error> {
             mode: Mode::Dev,
             target: Some(Target::Erlang),
             no_print_progress: false,
+            entrypoint: None,
         },
         crate::build::download_dependencies(crate::cli::Reporter::new())?,
     )?;
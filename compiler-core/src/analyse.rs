@@ -1182,6 +1182,7 @@ impl<'a, A> ModuleAnalyzer<'a, A> {
             opaque,
             constructors,
             documentation,
+            external_go,
             ..
         } = t;
         // We exit early here as we don't yet have a good way to handle the two
@@ -1235,6 +1236,9 @@ impl<'a, A> ModuleAnalyzer<'a, A> {
                     publicity,
                     type_,
                     documentation: documentation.as_ref().map(|(_, doc)| doc.clone()),
+                    go_wrapped: external_go.as_ref().is_some_and(|(_, go_name, _)| {
+                        crate::go::wrapped_external_go_type(go_name).is_some()
+                    }),
                 },
             )
             .expect("name uniqueness checked above");
@@ -1308,6 +1312,7 @@ impl<'a, A> ModuleAnalyzer<'a, A> {
                     deprecation: deprecation.clone(),
                     publicity: *publicity,
                     documentation: documentation.as_ref().map(|(_, doc)| doc.clone()),
+                    go_wrapped: false,
                 },
             )?;
 
@@ -153,6 +153,9 @@ impl Default for Runtime {
 pub enum TargetCodegenConfiguration {
     Go {
         go_module_path: EcoString,
+        /// The names to declare the Go packages of modules with, from the
+        /// `[go]` section of the root package's `gleam.toml`.
+        package_names: HashMap<EcoString, EcoString>,
     },
     JavaScript {
         emit_typescript_definitions: bool,
@@ -412,6 +415,21 @@ impl<'a> Located<'a> {
             Self::Label(_, _) => None,
         }
     }
+
+    /// The type of the located node, if it has one.
+    pub fn type_(&self) -> Option<std::sync::Arc<Type>> {
+        match self {
+            Self::Pattern(pattern) => Some(pattern.type_()),
+            Self::Statement(statement) => Some(statement.type_()),
+            Self::Expression(expression) => Some(expression.type_()),
+            Self::Arg(arg) => Some(arg.type_.clone()),
+            Self::Annotation(_, type_) | Self::Label(_, type_) => Some(type_.clone()),
+            Self::PatternSpread { .. }
+            | Self::ModuleStatement(_)
+            | Self::FunctionBody(_)
+            | Self::UnqualifiedImport(_) => None,
+        }
+    }
 }
 
 // Looks up the type constructor for the given type
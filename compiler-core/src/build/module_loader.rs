@@ -55,6 +55,11 @@ where
 
         let read_source = |name| self.read_source(path, name, source_mtime);
 
+        if self.codegen == CodegenRequired::WholeProgram {
+            tracing::debug!(?name, "whole_program_codegen_cache_unused");
+            return read_source(name).map(Input::New);
+        }
+
         let meta = match self.read_cache_metadata(&artefact)? {
             Some(meta) => meta,
             None => return read_source(name).map(Input::New),
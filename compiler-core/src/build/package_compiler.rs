@@ -12,7 +12,8 @@ use crate::{
     codegen::{Erlang, ErlangApp, Go, JavaScript, TypeScriptDeclarations},
     config::PackageConfig,
     dep_tree, error,
-    io::{BeamCompiler, CommandExecutor, FileSystemReader, FileSystemWriter, Stdio},
+    go::ffi::GoDeclarations,
+    io::{BeamCompiler, CommandExecutor, DirWalker, FileSystemReader, FileSystemWriter, Stdio},
     metadata::ModuleEncoder,
     parse::extra::ModuleExtra,
     paths, type_,
@@ -42,6 +43,10 @@ pub struct PackageCompiler<'a, IO> {
     pub ids: UniqueIdGenerator,
     pub write_metadata: bool,
     pub perform_codegen: bool,
+    /// If set every module is analysed, without using the cache, but no code is
+    /// generated for them, as the caller will generate it with
+    /// `perform_codegen` once every package of the project has been analysed.
+    pub defer_codegen: bool,
     /// If set to false the compiler won't load and analyse any of the package's
     /// modules and always succeed compilation returning no compile modules.
     ///
@@ -82,6 +87,7 @@ where
             target,
             write_metadata: true,
             perform_codegen: true,
+            defer_codegen: false,
             compile_modules: true,
             write_entrypoint: false,
             copy_native_files: true,
@@ -114,7 +120,9 @@ where
         }
 
         let artefact_directory = self.out.join(paths::ARTEFACT_DIRECTORY_NAME);
-        let codegen_required = if self.perform_codegen {
+        let codegen_required = if self.defer_codegen {
+            CodegenRequired::WholeProgram
+        } else if self.perform_codegen {
             CodegenRequired::Yes
         } else {
             CodegenRequired::No
@@ -191,10 +199,30 @@ where
             module.attach_doc_and_module_comments();
         }
 
-        tracing::debug!("performing_code_generation");
+        if let TargetCodegenConfiguration::Go {
+            go_module_path,
+            package_names,
+        } = self.target
+        {
+            if let Err(error) = self.check_go_externals(&modules, go_module_path) {
+                return Outcome::PartialFailure(modules, error);
+            }
+            let module_names = existing_modules
+                .keys()
+                .chain(modules.iter().map(|module| &module.name));
+            if let Err(error) = crate::go::check_package_names(module_names, package_names) {
+                return Outcome::PartialFailure(modules, error);
+            }
+        }
 
-        if let Err(error) = self.perform_codegen(existing_modules, &modules) {
-            return error.into();
+        if self.defer_codegen {
+            tracing::debug!("deferring_code_generation");
+        } else {
+            tracing::debug!("performing_code_generation");
+
+            if let Err(error) = self.perform_codegen(existing_modules, &modules) {
+                return error.into();
+            }
         }
 
         if let Err(error) = self.encode_and_write_metadata(&modules) {
@@ -204,6 +232,52 @@ where
         Outcome::Ok(modules)
     }
 
+    /// Checks the Go externals of the modules against the declarations of
+    /// the package's Go FFI files, so that a missing or mismatched Go function
+    /// is reported as a Gleam error rather than a `go build` failure.
+    fn check_go_externals(&self, modules: &[Module], go_module_path: &str) -> Result<(), Error> {
+        if modules.is_empty() {
+            return Ok(());
+        }
+
+        let mut declarations = GoDeclarations::default();
+        let mut source_directories = vec![self.root.join("src")];
+        if self.mode.includes_tests() {
+            source_directories.push(self.root.join("test"));
+        }
+        for directory in source_directories {
+            if !self.io.is_directory(&directory) {
+                continue;
+            }
+            for path in DirWalker::new(directory.clone()).into_file_iter(&self.io) {
+                let path = path?;
+                let file_name = path.file_name().unwrap_or_default();
+                if path.extension() != Some("go") || file_name.ends_with("_test.go") {
+                    continue;
+                }
+                let relative_path = path
+                    .strip_prefix(&directory)
+                    .expect("Go file strip prefix")
+                    .to_path_buf();
+                declarations.register_file(&relative_path, &self.io.read(&path)?);
+            }
+        }
+
+        let package_import_prefix = format!("{go_module_path}/{}/", self.config.name);
+        for module in modules {
+            let errors = declarations.check_module(&module.ast, &package_import_prefix);
+            if let Ok(errors) = Vec1::try_from_vec(errors) {
+                return Err(Error::Type {
+                    names: module.ast.names.clone(),
+                    path: module.input_path.clone(),
+                    src: module.code.clone(),
+                    errors,
+                });
+            }
+        }
+        Ok(())
+    }
+
     fn compile_erlang_to_beam(&mut self, modules: &HashSet<Utf8PathBuf>) -> Result<(), Error> {
         if modules.is_empty() {
             tracing::debug!("no_erlang_to_compile");
@@ -275,7 +349,9 @@ where
             let path = artefact_dir.join(name);
             let info = CacheMetadata {
                 mtime: module.mtime,
-                codegen_performed: self.perform_codegen,
+                // Deferred code generation leaves out the code the program
+                // doesn't use, so it can't stand in for a full build's.
+                codegen_performed: self.perform_codegen && !self.defer_codegen,
                 dependencies: module.dependencies.clone(),
                 fingerprint: SourceFingerprint::new(&module.code),
                 line_numbers: module.ast.type_info.line_numbers.clone(),
@@ -297,7 +373,7 @@ where
         Ok(())
     }
 
-    fn perform_codegen(
+    pub(crate) fn perform_codegen(
         &mut self,
         existing_modules: &im::HashMap<EcoString, type_::ModuleInterface>,
         modules: &[Module],
@@ -308,9 +384,10 @@ where
         }
 
         match self.target {
-            TargetCodegenConfiguration::Go { go_module_path } => {
-                self.perform_go_codegen(existing_modules, modules, &go_module_path)
-            }
+            TargetCodegenConfiguration::Go {
+                go_module_path,
+                package_names,
+            } => self.perform_go_codegen(existing_modules, modules, go_module_path, package_names),
             TargetCodegenConfiguration::JavaScript {
                 emit_typescript_definitions,
                 prelude_location,
@@ -402,14 +479,17 @@ where
         existing_modules: &im::HashMap<EcoString, type_::ModuleInterface>,
         modules: &[Module],
         go_module_path: &str,
+        package_names: &HashMap<EcoString, EcoString>,
     ) -> Result<(), Error> {
         let mut written = HashSet::new();
 
-        Go::new(&self.out, go_module_path, self.target_support).render(
-            &self.io,
-            existing_modules,
-            modules,
-        )?;
+        Go::new(
+            &self.out,
+            go_module_path,
+            package_names,
+            self.target_support,
+        )
+        .render(&self.io, existing_modules, modules)?;
 
         if self.copy_native_files {
             self.copy_project_native_files(&self.out, &mut written)?;
@@ -41,15 +41,19 @@ use super::{
 pub enum CodegenRequired {
     Yes,
     No,
+    /// Code is generated for the whole program at once from the typed AST of
+    /// every module, which the cache does not hold, so no cache can be used.
+    WholeProgram,
 }
 
 impl CodegenRequired {
-    /// Returns `true` if the codegen required is [`Yes`].
+    /// Returns `true` if the codegen required is [`Yes`] or [`WholeProgram`].
     ///
     /// [`Yes`]: CodegenRequired::Yes
+    /// [`WholeProgram`]: CodegenRequired::WholeProgram
     #[must_use]
     pub fn is_required(&self) -> bool {
-        matches!(self, Self::Yes)
+        matches!(self, Self::Yes | Self::WholeProgram)
     }
 }
 
@@ -71,6 +71,15 @@ fn write_cache(
 }
 
 fn run_loader(fs: InMemoryFileSystem, root: &Utf8Path, artefact: &Utf8Path) -> LoaderTestOutput {
+    run_loader_with_codegen(fs, root, artefact, CodegenRequired::Yes)
+}
+
+fn run_loader_with_codegen(
+    fs: InMemoryFileSystem,
+    root: &Utf8Path,
+    artefact: &Utf8Path,
+    codegen: CodegenRequired,
+) -> LoaderTestOutput {
     let mut defined = im::HashMap::new();
     let ids = UniqueIdGenerator::new();
     let (emitter, warnings) = WarningEmitter::vector();
@@ -81,7 +90,7 @@ fn run_loader(fs: InMemoryFileSystem, root: &Utf8Path, artefact: &Utf8Path) -> L
         mode: Mode::Dev,
         root: &root,
         warnings: &emitter,
-        codegen: CodegenRequired::Yes,
+        codegen,
         artefact_directory: &artefact,
         package_name: &"my_package".into(),
         target: Target::JavaScript,
@@ -172,6 +181,20 @@ fn reading_cache() {
     assert_eq!(loaded.cached, vec![EcoString::from("one")]);
 }
 
+#[test]
+fn cache_is_not_used_for_whole_program_codegen() {
+    let fs = InMemoryFileSystem::new();
+    let root = Utf8Path::new("/");
+    let artefact = Utf8Path::new("/artefact");
+
+    write_src(&fs, "/src/one.gleam", 0, TEST_SOURCE_1);
+    write_cache(&fs, "one", 0, vec![], TEST_SOURCE_1);
+
+    let loaded = run_loader_with_codegen(fs, root, artefact, CodegenRequired::WholeProgram);
+    assert_eq!(loaded.to_compile, vec![EcoString::from("one")]);
+    assert!(loaded.cached.is_empty());
+}
+
 #[test]
 fn module_is_stale_if_cache_older() {
     let fs = InMemoryFileSystem::new();
@@ -1,14 +1,16 @@
 use crate::{
     analyse::TargetSupport,
+    ast::{Definition, Function, Publicity},
     build::{
         package_compiler, package_compiler::PackageCompiler, package_loader::StaleTracker,
         project_compiler, telemetry::Telemetry, Mode, Module, Origin, Package, Target,
     },
+    call_graph::reachable::{Node, Reachable},
     codegen::{self, ErlangApp},
     config::PackageConfig,
     dep_tree,
     error::{FileIoAction, FileKind},
-    io::{BeamCompiler, CommandExecutor, FileSystemReader, FileSystemWriter, Stdio},
+    io::{BeamCompiler, CommandExecutor, DirWalker, FileSystemReader, FileSystemWriter, Stdio},
     manifest::{ManifestPackage, ManifestPackageSource},
     metadata,
     paths::{self, ProjectPaths},
@@ -60,6 +62,10 @@ pub struct Options {
     pub warnings_as_errors: bool,
     pub root_target_support: TargetSupport,
     pub no_print_progress: bool,
+    /// The module whose `main` function starts the program being built.
+    /// Production builds for Go and JavaScript with an entrypoint only
+    /// generate the code that is reachable from it.
+    pub entrypoint: Option<EcoString>,
 }
 
 #[derive(Debug)]
@@ -67,6 +73,7 @@ pub struct Built {
     pub root_package: Package,
     module_interfaces: im::HashMap<EcoString, type_::ModuleInterface>,
     compiled_dependency_modules: Vec<Module>,
+    ids: UniqueIdGenerator,
 }
 
 impl Built {
@@ -84,6 +91,18 @@ impl Built {
         }
     }
 
+    /// The interfaces of every module of the project and its dependencies.
+    pub fn module_interfaces(&self) -> &im::HashMap<EcoString, type_::ModuleInterface> {
+        &self.module_interfaces
+    }
+
+    /// The id generator the modules were compiled with. Code compiled later
+    /// against their interfaces must use it too, so its type variables can't
+    /// share ids with the ones in the interfaces.
+    pub fn ids(&self) -> &UniqueIdGenerator {
+        &self.ids
+    }
+
     pub fn minimum_required_version(&self) -> Version {
         self.module_interfaces
             .values()
@@ -114,6 +133,18 @@ pub struct ProjectCompiler<IO> {
     /// We may want to silence subprocess stdout if we are running in LSP mode.
     /// The language server talks over stdio so printing would break that.
     pub subprocess_stdio: Stdio,
+    /// The packages that have been analysed but are waiting for the whole
+    /// program to be analysed before their code is generated, in the order
+    /// they were compiled.
+    deferred_codegen: Vec<DeferredCodegen>,
+}
+
+#[derive(Debug)]
+struct DeferredCodegen {
+    config: PackageConfig,
+    root_path: Utf8PathBuf,
+    is_root: bool,
+    module_count: usize,
 }
 
 // TODO: test that tests cannot be imported into src
@@ -145,6 +176,7 @@ where
             ids: UniqueIdGenerator::new(),
             warnings: WarningEmitter::new(warning_emitter),
             subprocess_stdio: Stdio::Inherit,
+            deferred_codegen: Vec::new(),
             telemetry,
             packages,
             options,
@@ -166,6 +198,17 @@ where
         self.options.target.unwrap_or(self.config.target)
     }
 
+    /// The entrypoint module to eliminate dead code from, if this build does
+    /// so. Erlang is excluded as BEAM modules are loaded individually at
+    /// runtime and may be called dynamically.
+    fn dead_code_entrypoint(&self) -> Option<EcoString> {
+        let entrypoint = self.options.entrypoint.clone()?;
+        let eliminates = self.mode() == Mode::Prod
+            && self.target() != Target::Erlang
+            && self.options.codegen == Codegen::All;
+        eliminates.then_some(entrypoint)
+    }
+
     /// Compiles all packages in the project and returns the compiled
     /// information from the root package
     pub fn compile(mut self) -> Result<Built> {
@@ -174,6 +217,8 @@ where
         // from a previous build. A ProjectCompiler instance is re-used by the
         // LSP engine so state could be reused if we don't reset it.
         self.stale_modules.empty();
+        self.deferred_codegen.clear();
+        let dead_code_entrypoint = self.dead_code_entrypoint();
 
         // Each package may specify a Gleam version that it supports, so we
         // verify that this version is appropriate.
@@ -183,13 +228,13 @@ where
         self.write_prelude()?;
 
         // Dependencies are compiled first.
-        let compiled_dependency_modules = self.compile_dependencies()?;
+        let mut compiled_dependency_modules = self.compile_dependencies()?;
 
         // We reset the warning count as we don't want to fail the build if a
         // dependency has warnings, only if the root package does.
         self.warnings.reset_count();
 
-        let root_package = self.compile_root_package().into_result()?;
+        let mut root_package = self.compile_root_package().into_result()?;
 
         // TODO: test
         if self.options.warnings_as_errors && self.warnings.count() > 0 {
@@ -198,10 +243,19 @@ where
             });
         }
 
+        if let Some(entrypoint) = dead_code_entrypoint {
+            self.perform_deferred_codegen(
+                entrypoint,
+                &mut compiled_dependency_modules,
+                &mut root_package.modules,
+            )?;
+        }
+
         Ok(Built {
             root_package,
             module_interfaces: self.importable_modules,
             compiled_dependency_modules,
+            ids: self.ids,
         })
     }
 
@@ -243,6 +297,111 @@ where
             })
     }
 
+    /// Generates the code of every package whose code generation was
+    /// deferred, leaving out the module functions, constants and types that
+    /// cannot be reached from the entrypoint's `main` function or the
+    /// packages' native files.
+    ///
+    /// This only happens in production builds, which don't include the test
+    /// modules, so tests are not roots of the program.
+    fn perform_deferred_codegen(
+        &mut self,
+        entrypoint: EcoString,
+        dependency_modules: &mut [Module],
+        root_modules: &mut [Module],
+    ) -> Result<()> {
+        let mut native_references = HashSet::new();
+        for package in &self.deferred_codegen {
+            native_references.extend(self.native_file_references(&package.root_path)?);
+        }
+
+        let mut roots = vec![Node::Value(entrypoint, "main".into())];
+        for module in dependency_modules.iter().chain(root_modules.iter()) {
+            if native_references.contains(&module.name) {
+                roots.extend(public_definitions(module));
+            }
+        }
+        let reachable = Reachable::find(
+            dependency_modules
+                .iter()
+                .chain(root_modules.iter())
+                .map(|module| &module.ast),
+            roots,
+        );
+        for module in dependency_modules.iter_mut().chain(root_modules.iter_mut()) {
+            reachable.retain(&mut module.ast);
+        }
+
+        let mut remaining_dependency_modules: &[Module] = dependency_modules;
+        for package in std::mem::take(&mut self.deferred_codegen) {
+            let modules = if package.is_root {
+                &*root_modules
+            } else {
+                let (modules, rest) = remaining_dependency_modules.split_at(package.module_count);
+                remaining_dependency_modules = rest;
+                modules
+            };
+
+            let out_path = self.paths.build_directory_for_package(
+                self.mode(),
+                self.target(),
+                &package.config.name,
+            );
+            let lib_path = self
+                .paths
+                .build_directory_for_target(self.mode(), self.target());
+            let target = self.codegen_configuration(package.is_root);
+            let mut compiler = PackageCompiler::new(
+                &package.config,
+                Mode::Prod,
+                &package.root_path,
+                &out_path,
+                &lib_path,
+                &target,
+                self.ids.clone(),
+                self.io.clone(),
+            );
+            compiler.subprocess_stdio = self.subprocess_stdio;
+            compiler.target_support = self.target_support(package.is_root);
+            compiler.perform_codegen(&self.importable_modules, modules)?;
+        }
+
+        Ok(())
+    }
+
+    /// The modules that the native files of the package at the given root
+    /// import, and so may use any public definition of.
+    fn native_file_references(&self, root: &Utf8Path) -> Result<HashSet<EcoString>> {
+        let extensions: &[&str] = match self.target() {
+            Target::Erlang => &[],
+            Target::JavaScript => &["mjs", "js", "ts"],
+            Target::Go => &["go"],
+        };
+        let go_module_path = match self.codegen_configuration(false) {
+            super::TargetCodegenConfiguration::Go { go_module_path, .. } => Some(go_module_path),
+            _ => None,
+        };
+
+        let src = root.join("src");
+        let mut references = HashSet::new();
+        let mut walker = DirWalker::new(src.clone());
+        while let Some(path) = walker.next_file(&self.io)? {
+            if !extensions.contains(&path.extension().unwrap_or_default()) {
+                continue;
+            }
+            let directory = path
+                .parent()
+                .and_then(|parent| parent.strip_prefix(&src).ok())
+                .unwrap_or(Utf8Path::new(""));
+            let source = self.io.read(&path)?;
+            references.extend(match &go_module_path {
+                Some(go_module_path) => go_imported_modules(go_module_path, &source),
+                None => javascript_imported_modules(directory, &source),
+            });
+        }
+        Ok(references)
+    }
+
     pub fn compile_dependencies(&mut self) -> Result<Vec<Module>, Error> {
         let sequence = order_packages(&self.packages)?;
         let mut modules = vec![];
@@ -561,7 +720,57 @@ golang.org/x/exp v0.0.0-20220518171630-0b5c67f07fdf/go.mod h1:yh0Ynu2b5ZUe3MQfp2
             .paths
             .build_directory_for_target(self.mode(), self.target());
         let mode = if is_root { self.mode() } else { Mode::Prod };
-        let target = match self.target() {
+        let target = self.codegen_configuration(is_root);
+
+        let mut compiler = PackageCompiler::new(
+            config,
+            mode,
+            &root_path,
+            &out_path,
+            &lib_path,
+            &target,
+            self.ids.clone(),
+            self.io.clone(),
+        );
+        compiler.write_metadata = true;
+        compiler.write_entrypoint = is_root;
+        compiler.perform_codegen = self.options.codegen.should_codegen(is_root);
+        compiler.compile_beam_bytecode = self.options.codegen.should_codegen(is_root);
+        compiler.compile_modules = !(self.options.compile == Compile::DepsOnly && is_root);
+        compiler.subprocess_stdio = self.subprocess_stdio;
+        compiler.target_support = self.target_support(is_root);
+        compiler.cached_warnings = if is_root {
+            CachedWarnings::Use
+        } else {
+            CachedWarnings::Ignore
+        };
+        let defer_codegen = self.dead_code_entrypoint().is_some();
+        compiler.defer_codegen = defer_codegen;
+
+        // Compile project to Erlang or JavaScript source code
+        let outcome = compiler.compile(
+            &mut self.warnings,
+            &mut self.importable_modules,
+            &mut self.defined_modules,
+            &mut self.stale_modules,
+            &mut self.incomplete_modules,
+            self.telemetry,
+        );
+
+        if let (true, Outcome::Ok(modules)) = (defer_codegen, &outcome) {
+            self.deferred_codegen.push(DeferredCodegen {
+                config: config.clone(),
+                root_path,
+                is_root,
+                module_count: modules.len(),
+            });
+        }
+
+        outcome
+    }
+
+    fn codegen_configuration(&self, is_root: bool) -> super::TargetCodegenConfiguration {
+        match self.target() {
             Target::Erlang => {
                 let package_name_overrides = self
                     .packages
@@ -587,26 +796,13 @@ golang.org/x/exp v0.0.0-20220518171630-0b5c67f07fdf/go.mod h1:yh0Ynu2b5ZUe3MQfp2
 
             Target::Go => super::TargetCodegenConfiguration::Go {
                 go_module_path: "example.com/todo".into(), // TODO
+                package_names: self.config.go.package_names.clone(),
             },
-        };
+        }
+    }
 
-        let mut compiler = PackageCompiler::new(
-            config,
-            mode,
-            &root_path,
-            &out_path,
-            &lib_path,
-            &target,
-            self.ids.clone(),
-            self.io.clone(),
-        );
-        compiler.write_metadata = true;
-        compiler.write_entrypoint = is_root;
-        compiler.perform_codegen = self.options.codegen.should_codegen(is_root);
-        compiler.compile_beam_bytecode = self.options.codegen.should_codegen(is_root);
-        compiler.compile_modules = !(self.options.compile == Compile::DepsOnly && is_root);
-        compiler.subprocess_stdio = self.subprocess_stdio;
-        compiler.target_support = if is_root {
+    fn target_support(&self, is_root: bool) -> TargetSupport {
+        if is_root {
             // When compiling the root package it is context specific as to whether we need to
             // enforce that all functions have an implementation for the current target.
             // Typically we do, but if we are using `gleam run -m $module` to run a module that
@@ -619,23 +815,92 @@ golang.org/x/exp v0.0.0-20220518171630-0b5c67f07fdf/go.mod h1:yh0Ynu2b5ZUe3MQfp2
             // implementation for the current target. It is OK if they have APIs that are
             // unaccessible so long as they are not used by the root package.
             TargetSupport::NotEnforced
+        }
+    }
+}
+
+/// The module functions, constants and custom types of the module that other
+/// modules can use.
+fn public_definitions(module: &Module) -> impl Iterator<Item = Node> + '_ {
+    let name = &module.name;
+    module
+        .ast
+        .definitions
+        .iter()
+        .filter_map(move |definition| match definition {
+            Definition::Function(Function {
+                name: Some((_, function)),
+                publicity,
+                ..
+            }) if !publicity.is_private() => Some(Node::Value(name.clone(), function.clone())),
+            Definition::ModuleConstant(constant) if !constant.publicity.is_private() => {
+                Some(Node::Value(name.clone(), constant.name.clone()))
+            }
+            Definition::CustomType(custom_type) if !custom_type.publicity.is_private() => {
+                Some(Node::Type(name.clone(), custom_type.name.clone()))
+            }
+            _ => None,
+        })
+}
+
+/// The Gleam modules a JavaScript native file in the given directory, relative
+/// to the package's `src` directory, imports with a relative path. Modules of
+/// other packages are imported through their sibling build directory.
+pub(crate) fn javascript_imported_modules(directory: &Utf8Path, source: &str) -> Vec<EcoString> {
+    let mut modules = vec![];
+    for (end, _) in source.match_indices(".mjs") {
+        let end = end + ".mjs".len();
+        let Some(quote) = source[end..].chars().next() else {
+            continue;
         };
-        compiler.cached_warnings = if is_root {
-            CachedWarnings::Use
-        } else {
-            CachedWarnings::Ignore
+        if !matches!(quote, '"' | '\'' | '`') {
+            continue;
+        }
+        let Some(start) = source[..end].rfind(quote) else {
+            continue;
         };
+        let specifier = Utf8Path::new(&source[start + 1..end - ".mjs".len()]);
+        if !specifier.starts_with(".") && !specifier.starts_with("..") {
+            continue;
+        }
 
-        // Compile project to Erlang or JavaScript source code
-        compiler.compile(
-            &mut self.warnings,
-            &mut self.importable_modules,
-            &mut self.defined_modules,
-            &mut self.stale_modules,
-            &mut self.incomplete_modules,
-            self.telemetry,
-        )
+        let mut path: Vec<&str> = directory.iter().collect();
+        let mut escaped = 0;
+        for component in specifier.iter() {
+            match component {
+                "." => (),
+                ".." => {
+                    if path.pop().is_none() {
+                        escaped += 1;
+                    }
+                }
+                _ => path.push(component),
+            }
+        }
+        // Leaving the package's directory goes into another package's one.
+        match (escaped, path.get(1..)) {
+            (0, _) => modules.push(path.join("/").into()),
+            (1, Some(module)) if !module.is_empty() => modules.push(module.join("/").into()),
+            _ => (),
+        }
     }
+    modules
+}
+
+/// The Gleam modules a Go native file imports, as the Go packages generated
+/// for them are imported with the project's Go module path followed by the
+/// name of their Gleam package and the module's name.
+pub(crate) fn go_imported_modules(go_module_path: &str, source: &str) -> Vec<EcoString> {
+    let prefix = format!("\"{go_module_path}/");
+    source
+        .match_indices(&prefix)
+        .filter_map(|(start, _)| {
+            let path = &source[start + prefix.len()..];
+            let path = &path[..path.find('"')?];
+            let (_package, module) = path.split_once('/')?;
+            Some(module.into())
+        })
+        .collect()
 }
 
 fn order_packages(packages: &HashMap<String, ManifestPackage>) -> Result<Vec<EcoString>, Error> {
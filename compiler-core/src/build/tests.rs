@@ -1,6 +1,11 @@
+use camino::Utf8Path;
+use ecow::EcoString;
+
 use crate::{manifest::ManifestPackage, Error};
 
-use super::project_compiler::{usable_build_tools, BuildTool};
+use super::project_compiler::{
+    go_imported_modules, javascript_imported_modules, usable_build_tools, BuildTool,
+};
 
 #[test]
 fn usable_build_tool_unknown() {
@@ -55,3 +60,47 @@ fn usable_build_tool_mix_then_rebar3() {
         Ok(vec![BuildTool::Mix, BuildTool::Rebar3])
     )
 }
+
+#[test]
+fn javascript_imported_modules_are_resolved_from_the_file() {
+    let source = r#"
+import { Ok } from "../gleam.mjs";
+import { wibble } from "./wibble.mjs";
+import * as wobble from '../../../other_package/wobble/woo.mjs';
+import { outside } from "../../../../outside.mjs";
+export { main } from "../app.mjs";
+// Not a module: wibble.mjs
+"#;
+    assert_eq!(
+        javascript_imported_modules(Utf8Path::new("app/ffi"), source),
+        vec![
+            EcoString::from("app/gleam"),
+            EcoString::from("app/ffi/wibble"),
+            EcoString::from("wobble/woo"),
+            EcoString::from("app/app"),
+        ]
+    );
+    assert_eq!(
+        javascript_imported_modules(Utf8Path::new(""), source),
+        vec![EcoString::from("wibble")]
+    );
+}
+
+#[test]
+fn go_imported_modules_are_found_by_import_path() {
+    let source = r#"
+package wibble_ffi
+
+import (
+	"fmt"
+
+	gleam "example.com/todo/gleam"
+	"example.com/todo/app/wibble/wobble"
+	other "example.com/todo/other_package/woo"
+)
+"#;
+    assert_eq!(
+        go_imported_modules("example.com/todo", source),
+        vec![EcoString::from("wibble/wobble"), EcoString::from("woo")]
+    );
+}
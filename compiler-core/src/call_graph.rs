@@ -3,6 +3,9 @@
 
 #[cfg(test)]
 mod into_dependency_order_tests;
+pub mod reachable;
+#[cfg(test)]
+mod reachable_tests;
 
 use crate::{
     ast::{
@@ -0,0 +1,473 @@
+//! Reachability of module functions, constants and types across all the
+//! modules of a program, used to skip generating code that can never run.
+//! The same walk also finds the calls a function makes, for the language
+//! server's call hierarchy.
+
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+use ecow::EcoString;
+
+use crate::{
+    ast::{
+        visit::{self, Visit},
+        ClauseGuard, Constant, Definition, SrcSpan, TypedClauseGuard, TypedConstant,
+        TypedCustomType, TypedExpr, TypedFunction, TypedModule, TypedModuleConstant, TypedPattern,
+    },
+    type_::{
+        ModuleValueConstructor, Type, TypeVar, TypedCallArg, ValueConstructor,
+        ValueConstructorVariant,
+    },
+};
+
+/// A module function or constant, or a type, identified by the name of the
+/// module that defines it and its name within that module.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Node {
+    Value(EcoString, EcoString),
+    Type(EcoString, EcoString),
+}
+
+/// The definitions that can be reached from a set of root functions.
+#[derive(Debug, Default)]
+pub struct Reachable {
+    values: HashSet<(EcoString, EcoString)>,
+    types: HashSet<(EcoString, EcoString)>,
+    /// The record constructors of the reachable types, which unqualified
+    /// imports may refer to.
+    constructors: HashSet<(EcoString, EcoString)>,
+    /// All the custom types defined by the modules, reachable or not.
+    custom_types: HashSet<(EcoString, EcoString)>,
+}
+
+impl Reachable {
+    /// Walks the given modules starting from the `roots`, following every
+    /// function, constant and type they refer to, including those defined in
+    /// other modules.
+    ///
+    /// Modules that are not in `modules` (such as the prelude) are treated as
+    /// opaque: references into them are recorded but not followed.
+    pub fn find<'a>(
+        modules: impl IntoIterator<Item = &'a TypedModule>,
+        roots: impl IntoIterator<Item = Node>,
+    ) -> Self {
+        let modules: HashMap<&EcoString, ModuleDefinitions<'a>> = modules
+            .into_iter()
+            .map(|module| (&module.name, ModuleDefinitions::new(module)))
+            .collect();
+
+        let mut reachable = Self {
+            custom_types: modules
+                .iter()
+                .flat_map(|(module, definitions)| {
+                    definitions
+                        .types
+                        .keys()
+                        .map(|name| ((*module).clone(), (*name).clone()))
+                })
+                .collect(),
+            ..Self::default()
+        };
+        let mut queue: Vec<Node> = roots.into_iter().collect();
+
+        while let Some(node) = queue.pop() {
+            let is_new = match &node {
+                Node::Value(module, name) => {
+                    reachable.values.insert((module.clone(), name.clone()))
+                }
+                Node::Type(module, name) => reachable.types.insert((module.clone(), name.clone())),
+            };
+            if !is_new {
+                continue;
+            }
+
+            let (Node::Value(module, name) | Node::Type(module, name)) = &node;
+            let Some(definitions) = modules.get(module) else {
+                continue;
+            };
+            let mut references = References {
+                aliases: &definitions.aliases,
+                nodes: &mut queue,
+                calls: None,
+            };
+
+            match &node {
+                Node::Value(..) => {
+                    if let Some(function) = definitions.functions.get(name) {
+                        references.function(function);
+                    } else if let Some(constant) = definitions.constants.get(name) {
+                        references.module_constant(constant);
+                    }
+                }
+                Node::Type(..) => {
+                    if let Some(custom_type) = definitions.types.get(name) {
+                        references.custom_type(custom_type);
+                        reachable.constructors.extend(
+                            custom_type
+                                .constructors
+                                .iter()
+                                .map(|constructor| (module.clone(), constructor.name.clone())),
+                        );
+                    }
+                }
+            }
+        }
+
+        reachable
+    }
+
+    /// Removes the functions, constants and custom types of the module that
+    /// are not reachable, along with any unqualified imports of unreachable
+    /// values and custom types, which would otherwise refer to code that was
+    /// never generated.
+    ///
+    /// Type aliases are kept as no code is generated for them.
+    pub fn retain(&self, module: &mut TypedModule) {
+        let module_name = module.name.clone();
+        let is_reachable_value = |module: &EcoString, name: &EcoString| {
+            let key = (module.clone(), name.clone());
+            self.values.contains(&key) || self.constructors.contains(&key)
+        };
+
+        module
+            .definitions
+            .retain_mut(|definition| match definition {
+                Definition::Function(function) => match &function.name {
+                    Some((_, name)) => is_reachable_value(&module_name, name),
+                    None => true,
+                },
+                Definition::ModuleConstant(constant) => {
+                    is_reachable_value(&module_name, &constant.name)
+                }
+                Definition::CustomType(custom_type) => self
+                    .types
+                    .contains(&(module_name.clone(), custom_type.name.clone())),
+                Definition::Import(import) => {
+                    import
+                        .unqualified_values
+                        .retain(|value| is_reachable_value(&import.module, &value.name));
+                    import.unqualified_types.retain(|type_| {
+                        let key = (import.module.clone(), type_.name.clone());
+                        self.types.contains(&key) || !self.custom_types.contains(&key)
+                    });
+                    true
+                }
+                Definition::TypeAlias(_) => true,
+            });
+    }
+}
+
+/// A call to a module function, which may be defined in another module.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Call {
+    /// The module defining the called function.
+    pub module: EcoString,
+    /// The location of the called function's definition.
+    pub definition: SrcSpan,
+    /// The location of the called function's name at the call site.
+    pub location: SrcSpan,
+}
+
+/// Finds all the calls to module functions made in the body of the given
+/// function, in the order they appear.
+///
+/// As pipelines, `use` expressions and function captures are all turned into
+/// regular calls when type checking, calls made through them are found too.
+/// A function that's referenced without being called, for example when it's
+/// passed as an argument, is not included.
+pub fn calls(function: &TypedFunction) -> Vec<Call> {
+    let aliases = HashMap::new();
+    let mut nodes = vec![];
+    let mut references = References {
+        aliases: &aliases,
+        nodes: &mut nodes,
+        calls: Some(vec![]),
+    };
+    references.function(function);
+    let mut calls = references.calls.unwrap_or_default();
+    calls.sort_by_key(|call| call.location.start);
+    calls
+}
+
+/// The top level definitions of a module, indexed by name.
+struct ModuleDefinitions<'a> {
+    functions: HashMap<&'a EcoString, &'a TypedFunction>,
+    constants: HashMap<&'a EcoString, &'a TypedModuleConstant>,
+    types: HashMap<&'a EcoString, &'a TypedCustomType>,
+    /// The module and original name of each value imported unqualified,
+    /// keyed by the name it is used by in this module.
+    aliases: HashMap<&'a EcoString, (&'a EcoString, &'a EcoString)>,
+}
+
+impl<'a> ModuleDefinitions<'a> {
+    fn new(module: &'a TypedModule) -> Self {
+        let mut definitions = Self {
+            functions: HashMap::new(),
+            constants: HashMap::new(),
+            types: HashMap::new(),
+            aliases: HashMap::new(),
+        };
+
+        for definition in &module.definitions {
+            match definition {
+                Definition::Function(function) => {
+                    if let Some((_, name)) = &function.name {
+                        let _ = definitions.functions.insert(name, function);
+                    }
+                }
+                Definition::ModuleConstant(constant) => {
+                    let _ = definitions.constants.insert(&constant.name, constant);
+                }
+                Definition::CustomType(custom_type) => {
+                    let _ = definitions.types.insert(&custom_type.name, custom_type);
+                }
+                Definition::Import(import) => {
+                    for value in &import.unqualified_values {
+                        let _ = definitions
+                            .aliases
+                            .insert(value.used_name(), (&import.module, &value.name));
+                    }
+                }
+                Definition::TypeAlias(_) => (),
+            }
+        }
+
+        definitions
+    }
+}
+
+/// Collects the functions, constants and types a definition refers to.
+struct References<'a, 'b> {
+    aliases: &'b HashMap<&'a EcoString, (&'a EcoString, &'a EcoString)>,
+    nodes: &'b mut Vec<Node>,
+    /// The calls made, only collected when they are asked for.
+    calls: Option<Vec<Call>>,
+}
+
+impl References<'_, '_> {
+    fn function(&mut self, function: &TypedFunction) {
+        for argument in &function.arguments {
+            self.type_(&argument.type_);
+        }
+        self.type_(&function.return_type);
+        self.visit_typed_function(function);
+    }
+
+    fn module_constant(&mut self, constant: &TypedModuleConstant) {
+        self.type_(&constant.type_);
+        self.constant(&constant.value);
+    }
+
+    fn custom_type(&mut self, custom_type: &TypedCustomType) {
+        for constructor in &custom_type.constructors {
+            for argument in &constructor.arguments {
+                self.type_(&argument.type_);
+            }
+        }
+    }
+
+    fn type_(&mut self, type_: &Type) {
+        match type_ {
+            Type::Named {
+                module, name, args, ..
+            } => {
+                self.nodes.push(Node::Type(module.clone(), name.clone()));
+                for argument in args {
+                    self.type_(argument);
+                }
+            }
+            Type::Fn { args, retrn } => {
+                for argument in args {
+                    self.type_(argument);
+                }
+                self.type_(retrn);
+            }
+            Type::Tuple { elems } => {
+                for element in elems {
+                    self.type_(element);
+                }
+            }
+            Type::Var { type_ } => match &*type_.borrow() {
+                TypeVar::Link { type_ } => self.type_(type_),
+                TypeVar::Unbound { .. } | TypeVar::Generic { .. } => (),
+            },
+        }
+    }
+
+    /// A reference to a module function or constant by the name it is used by
+    /// in this module.
+    fn value(&mut self, variant: &ValueConstructorVariant, used_name: &EcoString) {
+        match variant {
+            ValueConstructorVariant::ModuleFn { module, name, .. } => {
+                self.nodes.push(Node::Value(module.clone(), name.clone()));
+            }
+            ValueConstructorVariant::ModuleConstant { module, .. } => {
+                let name = match self.aliases.get(used_name) {
+                    Some((alias_module, name)) if *alias_module == module => (*name).clone(),
+                    Some(_) | None => used_name.clone(),
+                };
+                self.nodes.push(Node::Value(module.clone(), name));
+            }
+            ValueConstructorVariant::LocalConstant { literal } => self.constant(literal),
+            ValueConstructorVariant::LocalVariable { .. }
+            | ValueConstructorVariant::Record { .. } => (),
+        }
+    }
+
+    fn constant(&mut self, constant: &TypedConstant) {
+        self.type_(&constant.type_());
+        match constant {
+            Constant::Int { .. }
+            | Constant::Float { .. }
+            | Constant::String { .. }
+            | Constant::Invalid { .. } => (),
+            Constant::Tuple { elements, .. } | Constant::List { elements, .. } => {
+                for element in elements {
+                    self.constant(element);
+                }
+            }
+            Constant::Record { args, .. } => {
+                for argument in args {
+                    self.constant(&argument.value);
+                }
+            }
+            Constant::BitArray { segments, .. } => {
+                for segment in segments {
+                    self.constant(&segment.value);
+                }
+            }
+            Constant::Var {
+                name, constructor, ..
+            } => {
+                if let Some(constructor) = constructor {
+                    self.type_(&constructor.type_);
+                    self.value(&constructor.variant, name);
+                }
+            }
+            Constant::StringConcatenation { left, right, .. } => {
+                self.constant(left);
+                self.constant(right);
+            }
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for References<'_, '_> {
+    fn visit_typed_expr(&mut self, expr: &'ast TypedExpr) {
+        self.type_(&expr.type_());
+        visit::visit_typed_expr(self, expr);
+    }
+
+    fn visit_typed_expr_call(
+        &mut self,
+        location: &'ast SrcSpan,
+        type_: &'ast Arc<Type>,
+        fun: &'ast TypedExpr,
+        args: &'ast [TypedCallArg],
+    ) {
+        if let Some(calls) = &mut self.calls {
+            match fun {
+                TypedExpr::Var {
+                    location,
+                    constructor,
+                    ..
+                } => {
+                    if let ValueConstructorVariant::ModuleFn {
+                        module,
+                        location: definition,
+                        ..
+                    } = &constructor.variant
+                    {
+                        calls.push(Call {
+                            module: module.clone(),
+                            definition: *definition,
+                            location: *location,
+                        });
+                    }
+                }
+
+                TypedExpr::ModuleSelect {
+                    location,
+                    label,
+                    module_name,
+                    constructor:
+                        ModuleValueConstructor::Fn {
+                            location: definition,
+                            ..
+                        },
+                    ..
+                } => calls.push(Call {
+                    module: module_name.clone(),
+                    definition: *definition,
+                    location: SrcSpan::new(location.end - label.len() as u32, location.end),
+                }),
+
+                _ => (),
+            }
+        }
+        visit::visit_typed_expr_call(self, location, type_, fun, args);
+    }
+
+    fn visit_typed_expr_var(
+        &mut self,
+        location: &'ast SrcSpan,
+        constructor: &'ast ValueConstructor,
+        name: &'ast EcoString,
+    ) {
+        self.value(&constructor.variant, name);
+        visit::visit_typed_expr_var(self, location, constructor, name);
+    }
+
+    fn visit_typed_expr_module_select(
+        &mut self,
+        location: &'ast SrcSpan,
+        type_: &'ast Arc<Type>,
+        label: &'ast EcoString,
+        module_name: &'ast EcoString,
+        module_alias: &'ast EcoString,
+        constructor: &'ast ModuleValueConstructor,
+    ) {
+        match constructor {
+            ModuleValueConstructor::Fn { .. } | ModuleValueConstructor::Constant { .. } => {
+                self.nodes
+                    .push(Node::Value(module_name.clone(), label.clone()));
+            }
+            ModuleValueConstructor::Record { .. } => (),
+        }
+        visit::visit_typed_expr_module_select(
+            self,
+            location,
+            type_,
+            label,
+            module_name,
+            module_alias,
+            constructor,
+        );
+    }
+
+    fn visit_typed_clause_guard(&mut self, guard: &'ast TypedClauseGuard) {
+        self.type_(&guard.type_());
+        match guard {
+            ClauseGuard::Constant(constant) => self.constant(constant),
+            ClauseGuard::ModuleSelect {
+                label,
+                module_name,
+                literal,
+                ..
+            } => {
+                self.nodes
+                    .push(Node::Value(module_name.clone(), label.clone()));
+                self.constant(literal);
+            }
+            _ => (),
+        }
+        visit::visit_typed_clause_guard(self, guard);
+    }
+
+    fn visit_typed_pattern(&mut self, pattern: &'ast TypedPattern) {
+        self.type_(&pattern.type_());
+        visit::visit_typed_pattern(self, pattern);
+    }
+}
@@ -0,0 +1,298 @@
+use super::reachable::{Node, Reachable};
+use crate::{
+    analyse::TargetSupport,
+    ast::{Definition, Import, TypedModule},
+    build::{Origin, Target},
+    config::PackageConfig,
+    line_numbers::LineNumbers,
+    type_::{build_prelude, PRELUDE_MODULE_NAME},
+    uid::UniqueIdGenerator,
+    warning::{TypeWarningEmitter, WarningEmitter},
+};
+use camino::Utf8PathBuf;
+use ecow::{eco_format, EcoString};
+use itertools::Itertools;
+
+/// Analyses the modules in order, each one able to import the ones before it,
+/// and returns the sorted names of the definitions that remain in each once
+/// the code unreachable from `main/main` has been removed.
+fn retained(modules: &[(&str, &str)]) -> Vec<(EcoString, Vec<EcoString>)> {
+    let ids = UniqueIdGenerator::new();
+    let mut interfaces = im::HashMap::new();
+    let _ = interfaces.insert(PRELUDE_MODULE_NAME.into(), build_prelude(&ids));
+    let mut config = PackageConfig::default();
+    config.name = "thepackage".into();
+
+    let mut typed: Vec<TypedModule> = modules
+        .iter()
+        .map(|(name, src)| {
+            let mut ast = crate::parse::parse_module(
+                Utf8PathBuf::from("test/path"),
+                src,
+                &WarningEmitter::null(),
+            )
+            .expect("syntax error")
+            .module;
+            ast.name = (*name).into();
+            let module = crate::analyse::ModuleAnalyzerConstructor::<()> {
+                target: Target::JavaScript,
+                ids: &ids,
+                origin: Origin::Src,
+                importable_modules: &interfaces,
+                warnings: &TypeWarningEmitter::null(),
+                direct_dependencies: &std::collections::HashMap::new(),
+                target_support: TargetSupport::NotEnforced,
+                package_config: &config,
+            }
+            .infer_module(ast, LineNumbers::new(src), "".into())
+            .expect("should successfully infer");
+            let _ = interfaces.insert((*name).into(), module.type_info.clone());
+            module
+        })
+        .collect();
+
+    let reachable = Reachable::find(&typed, [Node::Value("main".into(), "main".into())]);
+    typed
+        .iter_mut()
+        .map(|module| {
+            reachable.retain(module);
+            let names = module
+                .definitions
+                .iter()
+                .filter_map(|definition| match definition {
+                    Definition::Function(function) => function.name.clone().map(|(_, name)| name),
+                    Definition::ModuleConstant(constant) => Some(constant.name.clone()),
+                    Definition::CustomType(custom_type) => Some(custom_type.name.clone()),
+                    Definition::TypeAlias(alias) => Some(alias.alias.clone()),
+                    Definition::Import(import) => Some(import_names(import)),
+                })
+                .sorted()
+                .collect_vec();
+            (module.name.clone(), names)
+        })
+        .collect()
+}
+
+fn import_names(import: &Import<EcoString>) -> EcoString {
+    let values = import
+        .unqualified_types
+        .iter()
+        .map(|type_| eco_format!("type {}", type_.name))
+        .chain(
+            import
+                .unqualified_values
+                .iter()
+                .map(|value| value.name.clone()),
+        )
+        .join(", ");
+    eco_format!("import {}.{{{values}}}", import.module)
+}
+
+fn names(names: &[&str]) -> Vec<EcoString> {
+    names.iter().map(|name| EcoString::from(*name)).collect()
+}
+
+#[test]
+fn unused_functions_are_removed() {
+    let retained = retained(&[(
+        "main",
+        "
+pub fn main() { used() }
+fn used() { 1 }
+fn unused() { 2 }
+pub fn public_but_unused() { unused() }
+",
+    )]);
+    assert_eq!(retained, vec![("main".into(), names(&["main", "used"]))]);
+}
+
+#[test]
+fn functions_are_followed_across_modules() {
+    let retained = retained(&[
+        (
+            "dep",
+            "
+pub fn wibble() { helper() }
+fn helper() { 1 }
+pub fn wobble() { 2 }
+",
+        ),
+        (
+            "main",
+            "
+import dep
+pub fn main() { dep.wibble() }
+",
+        ),
+    ]);
+    assert_eq!(
+        retained,
+        vec![
+            ("dep".into(), names(&["helper", "wibble"])),
+            ("main".into(), names(&["import dep.{}", "main"])),
+        ]
+    );
+}
+
+#[test]
+fn functions_referenced_as_values_are_kept() {
+    let retained = retained(&[(
+        "main",
+        "
+pub fn main() { apply(double) }
+fn apply(f) { f(1) }
+fn double(x) { x * 2 }
+",
+    )]);
+    assert_eq!(
+        retained,
+        vec![("main".into(), names(&["apply", "double", "main"]))]
+    );
+}
+
+#[test]
+fn constants_and_the_functions_they_reference_are_kept() {
+    let retained = retained(&[
+        (
+            "dep",
+            "
+pub const handler = wibble
+pub const unused = 1
+pub fn wibble() { 1 }
+",
+        ),
+        (
+            "main",
+            "
+import dep.{handler}
+pub fn main() { handler() }
+",
+        ),
+    ]);
+    assert_eq!(
+        retained,
+        vec![
+            ("dep".into(), names(&["handler", "wibble"])),
+            ("main".into(), names(&["import dep.{handler}", "main"])),
+        ]
+    );
+}
+
+#[test]
+fn aliased_unqualified_constants_are_kept() {
+    let retained = retained(&[
+        ("dep", "pub const wibble = 1\npub const wobble = 2\n"),
+        (
+            "main",
+            "
+import dep.{wibble as wubble, wobble}
+pub fn main() { wubble }
+",
+        ),
+    ]);
+    assert_eq!(
+        retained,
+        vec![
+            ("dep".into(), names(&["wibble"])),
+            ("main".into(), names(&["import dep.{wibble}", "main"])),
+        ]
+    );
+}
+
+#[test]
+fn types_of_reachable_values_are_kept() {
+    let retained = retained(&[
+        (
+            "dep",
+            "
+pub type Wrapper { Wrapper(inner: Inner) }
+pub type Inner { Inner }
+pub type Unused { Unused }
+pub fn new() { Wrapper(Inner) }
+",
+        ),
+        (
+            "main",
+            "
+import dep.{Wrapper, Unused}
+pub fn main() { dep.new() }
+",
+        ),
+    ]);
+    assert_eq!(
+        retained,
+        vec![
+            ("dep".into(), names(&["Inner", "Wrapper", "new"])),
+            ("main".into(), names(&["import dep.{Wrapper}", "main"])),
+        ]
+    );
+}
+
+#[test]
+fn unqualified_imports_of_removed_types_are_removed() {
+    let retained = retained(&[
+        (
+            "dep",
+            "
+pub type Used { Used }
+pub type Unused { Unused }
+pub type Alias = Int
+pub fn new() { Used }
+",
+        ),
+        (
+            "main",
+            "
+import dep.{type Alias, type Unused, type Used}
+pub fn main() -> Used { dep.new() }
+",
+        ),
+    ]);
+    assert_eq!(
+        retained,
+        vec![
+            ("dep".into(), names(&["Alias", "Used", "new"])),
+            (
+                "main".into(),
+                names(&["import dep.{type Alias, type Used}", "main"])
+            ),
+        ]
+    );
+}
+
+#[test]
+fn types_matched_on_are_kept() {
+    let retained = retained(&[
+        (
+            "dep",
+            "pub type Pet { Cat Dog }\npub type Unused { Unused }\n",
+        ),
+        (
+            "main",
+            "
+import dep
+pub fn main() { is_cat(dep.Cat) }
+fn is_cat(pet) { case pet { dep.Cat -> True dep.Dog -> False } }
+",
+        ),
+    ]);
+    assert_eq!(
+        retained,
+        vec![
+            ("dep".into(), names(&["Pet"])),
+            ("main".into(), names(&["import dep.{}", "is_cat", "main"])),
+        ]
+    );
+}
+
+#[test]
+fn type_aliases_are_kept() {
+    let retained = retained(&[(
+        "main",
+        "
+pub type Number = Int
+pub fn main() { 1 }
+",
+    )]);
+    assert_eq!(retained, vec![("main".into(), names(&["Number", "main"]))]);
+}
@@ -10,9 +10,28 @@ use crate::{
 };
 use ecow::EcoString;
 use itertools::Itertools;
-use std::fmt::Debug;
+use rayon::prelude::*;
+use std::{collections::HashMap, fmt::Debug};
 
-use camino::Utf8Path;
+use camino::{Utf8Path, Utf8PathBuf};
+
+/// Generates the files for each module in parallel, then writes them in module
+/// order so that neither the output nor which error gets returned depends on
+/// how the work was scheduled.
+///
+fn render_in_parallel<Writer: FileSystemWriter>(
+    writer: &Writer,
+    modules: &[Module],
+    generate: impl Fn(&Module) -> Result<Vec<(Utf8PathBuf, String)>> + Send + Sync,
+) -> Result<()> {
+    let generated: Vec<Result<Vec<_>>> = modules.par_iter().map(generate).collect();
+    for files in generated {
+        for (path, text) in files? {
+            writer.write(&path, &text)?;
+        }
+    }
+    Ok(())
+}
 
 /// A code generator that creates a .erl Erlang module and record header files
 /// for each Gleam module in the package.
@@ -35,40 +54,32 @@ impl<'a> Erlang<'a> {
         writer: Writer,
         modules: &[Module],
     ) -> Result<()> {
-        for module in modules {
+        render_in_parallel(&writer, modules, |module| {
             let erl_name = module.name.replace("/", "@");
-            self.erlang_module(&writer, module, &erl_name)?;
-            self.erlang_record_headers(&writer, module, &erl_name)?;
-        }
-        Ok(())
+            let mut files = vec![self.erlang_module(module, &erl_name)?];
+            files.extend(self.erlang_record_headers(module, &erl_name));
+            Ok(files)
+        })
     }
 
-    fn erlang_module<Writer: FileSystemWriter>(
-        &self,
-        writer: &Writer,
-        module: &Module,
-        erl_name: &str,
-    ) -> Result<()> {
+    fn erlang_module(&self, module: &Module, erl_name: &str) -> Result<(Utf8PathBuf, String)> {
         let name = format!("{erl_name}.erl");
         let path = self.build_directory.join(&name);
         let line_numbers = LineNumbers::new(&module.code);
         let output = erlang::module(&module.ast, &line_numbers);
         tracing::debug!(name = ?name, "Generated Erlang module");
-        writer.write(&path, &output?)
+        Ok((path, output?))
     }
 
-    fn erlang_record_headers<Writer: FileSystemWriter>(
-        &self,
-        writer: &Writer,
-        module: &Module,
-        erl_name: &str,
-    ) -> Result<()> {
-        for (name, text) in erlang::records(&module.ast) {
-            let name = format!("{erl_name}_{name}.hrl");
-            tracing::debug!(name = ?name, "Generated Erlang header");
-            writer.write(&self.include_directory.join(name), &text)?;
-        }
-        Ok(())
+    fn erlang_record_headers(&self, module: &Module, erl_name: &str) -> Vec<(Utf8PathBuf, String)> {
+        erlang::records(&module.ast)
+            .into_iter()
+            .map(|(name, text)| {
+                let name = format!("{erl_name}_{name}.hrl");
+                tracing::debug!(name = ?name, "Generated Erlang header");
+                (self.include_directory.join(name), text)
+            })
+            .collect()
     }
 }
 
@@ -180,13 +191,15 @@ impl<'a> JavaScript<'a> {
     }
 
     pub fn render(&self, writer: &impl FileSystemWriter, modules: &[Module]) -> Result<()> {
-        for module in modules {
+        render_in_parallel(writer, modules, |module| {
             let js_name = module.name.clone();
+            let mut files = vec![];
             if self.typescript == TypeScriptDeclarations::Emit {
-                self.ts_declaration(writer, module, &js_name)?;
+                files.push(self.ts_declaration(module, &js_name)?);
             }
-            self.js_module(writer, module, &js_name)?
-        }
+            files.push(self.js_module(module, &js_name)?);
+            Ok(files)
+        })?;
         self.write_prelude(writer)?;
         Ok(())
     }
@@ -218,25 +231,15 @@ impl<'a> JavaScript<'a> {
         Ok(())
     }
 
-    fn ts_declaration(
-        &self,
-        writer: &impl FileSystemWriter,
-        module: &Module,
-        js_name: &str,
-    ) -> Result<()> {
+    fn ts_declaration(&self, module: &Module, js_name: &str) -> Result<(Utf8PathBuf, String)> {
         let name = format!("{js_name}.d.mts");
         let path = self.output_directory.join(name);
         let output = javascript::ts_declaration(&module.ast, &module.input_path, &module.code);
         tracing::debug!(name = ?js_name, "Generated TS declaration");
-        writer.write(&path, &output?)
+        Ok((path, output?))
     }
 
-    fn js_module(
-        &self,
-        writer: &impl FileSystemWriter,
-        module: &Module,
-        js_name: &str,
-    ) -> Result<()> {
+    fn js_module(&self, module: &Module, js_name: &str) -> Result<(Utf8PathBuf, String)> {
         let name = format!("{js_name}.mjs");
         let path = self.output_directory.join(name);
         let line_numbers = LineNumbers::new(&module.code);
@@ -249,7 +252,7 @@ impl<'a> JavaScript<'a> {
             self.typescript,
         );
         tracing::debug!(name = ?js_name, "Generated js module");
-        writer.write(&path, &output?)
+        Ok((path, output?))
     }
 }
 
@@ -257,6 +260,7 @@ impl<'a> JavaScript<'a> {
 pub struct Go<'a> {
     output_directory: &'a Utf8Path,
     go_module_path: &'a str,
+    package_names: &'a HashMap<EcoString, EcoString>,
     target_support: TargetSupport,
 }
 
@@ -264,11 +268,13 @@ impl<'a> Go<'a> {
     pub fn new(
         output_directory: &'a Utf8Path,
         go_module_path: &'a str,
+        package_names: &'a HashMap<EcoString, EcoString>,
         target_support: TargetSupport,
     ) -> Self {
         Self {
             output_directory,
             go_module_path,
+            package_names,
             target_support,
         }
     }
@@ -279,22 +285,23 @@ impl<'a> Go<'a> {
         existing_modules: &im::HashMap<EcoString, type_::ModuleInterface>,
         modules: &[Module],
     ) -> Result<()> {
+        // Analysis has already produced the interface of every module, so each
+        // package can be generated independently of the others.
         let mut dep_modules = existing_modules.clone();
         for module in modules {
-            let go_name = module.name.clone();
-            self.go_package(writer, &dep_modules, module, &go_name)?;
             let _ = dep_modules.insert(module.name.clone(), module.ast.type_info.clone());
         }
-        Ok(())
+        render_in_parallel(writer, modules, |module| {
+            Ok(vec![self.go_package(&dep_modules, module, &module.name)?])
+        })
     }
 
     fn go_package(
         &self,
-        writer: &impl FileSystemWriter,
         dep_modules: &im::HashMap<EcoString, type_::ModuleInterface>,
         module: &Module,
         go_name: &str,
-    ) -> Result<()> {
+    ) -> Result<(Utf8PathBuf, String)> {
         let name = format!("{go_name}/gleam_generated.go");
         let path = self.output_directory.join(name);
         let line_numbers = LineNumbers::new(&module.code);
@@ -304,10 +311,13 @@ impl<'a> Go<'a> {
             &line_numbers,
             &module.input_path,
             &module.code,
-            self.target_support,
-            self.go_module_path,
+            go::ModuleOptions {
+                target_support: self.target_support,
+                go_module_path: self.go_module_path,
+                package_name: self.package_names.get(&module.name).map(EcoString::as_str),
+            },
         );
         tracing::debug!(name = ?go_name, "Generated go package");
-        writer.write(&path, &output?)
+        Ok((path, output?))
     }
 }
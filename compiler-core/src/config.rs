@@ -606,7 +606,21 @@ pub struct JavaScriptConfig {
 }
 
 #[derive(Deserialize, Debug, PartialEq, Default, Clone)]
-pub struct GoConfig {}
+pub struct GoConfig {
+    /// The operating system to build Go binaries for, passed to `go build`
+    /// as `GOOS`. Defaults to the host operating system.
+    #[serde(default)]
+    pub goos: Option<EcoString>,
+    /// The architecture to build Go binaries for, passed to `go build` as
+    /// `GOARCH`. Defaults to the host architecture.
+    #[serde(default)]
+    pub goarch: Option<EcoString>,
+    /// The names to declare the Go packages of modules with, keyed by module
+    /// name, in place of the names derived from the modules' names. This is
+    /// the name Go FFI files placed alongside the module must declare.
+    #[serde(default)]
+    pub package_names: HashMap<EcoString, EcoString>,
+}
 
 #[derive(Deserialize, Debug, PartialEq, Eq, Clone)]
 pub enum DenoFlag {
@@ -903,3 +917,40 @@ name = "1"
         "Package names may only contain lowercase letters, numbers, and underscores for key `name` at line 1 column 1"
     )
 }
+
+#[test]
+fn go_cross_compilation_settings() {
+    let input = r#"
+name = "wibble"
+target = "go"
+
+[go]
+goos = "linux"
+goarch = "arm64"
+"#;
+    let config = toml::from_str::<PackageConfig>(input).unwrap();
+    assert_eq!(
+        config.go,
+        GoConfig {
+            goos: Some("linux".into()),
+            goarch: Some("arm64".into()),
+            package_names: HashMap::new(),
+        }
+    );
+}
+
+#[test]
+fn go_package_names() {
+    let input = r#"
+name = "wibble"
+target = "go"
+
+[go.package_names]
+"wibble/http/router" = "router"
+"#;
+    let config = toml::from_str::<PackageConfig>(input).unwrap();
+    assert_eq!(
+        config.go.package_names,
+        HashMap::from([("wibble/http/router".into(), "router".into())])
+    );
+}
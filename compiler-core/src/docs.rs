@@ -18,7 +18,7 @@ use crate::{
     package_interface::PackageInterface,
     paths::ProjectPaths,
     pretty,
-    type_::Deprecation,
+    type_::{expression::Implementations, Deprecation},
     version::COMPILER_VERSION,
 };
 use askama::Template;
@@ -195,6 +195,8 @@ pub fn generate_html<IO: FileSystemReader>(
             .sorted()
             .collect();
 
+        let unavailable = unavailable_definitions(&functions, &constants);
+
         types.iter().for_each(|type_| {
             let constructors = type_
                 .constructors
@@ -280,6 +282,7 @@ pub fn generate_html<IO: FileSystemReader>(
             functions,
             types,
             constants,
+            unavailable,
             rendering_timestamp: &rendering_timestamp,
         };
 
@@ -536,6 +539,7 @@ fn function<'a>(
             return_type: ret,
             location,
             deprecation,
+            implementations,
             ..
         }) => {
             let (_, name) = name
@@ -556,6 +560,7 @@ fn function<'a>(
                     Deprecation::NotDeprecated => "".to_string(),
                     Deprecation::Deprecated { message } => message.to_string(),
                 },
+                targets: target_badges(implementations),
             })
         }
 
@@ -563,6 +568,71 @@ fn function<'a>(
     }
 }
 
+fn target_badges(implementations: &Implementations) -> Vec<TargetBadge> {
+    let Implementations {
+        gleam: _,
+        can_run_on_erlang,
+        can_run_on_javascript,
+        can_run_on_go,
+        uses_erlang_externals,
+        uses_javascript_externals,
+        uses_go_externals,
+    } = *implementations;
+
+    vec![
+        TargetBadge {
+            name: "Erlang",
+            available: can_run_on_erlang,
+            uses_externals: uses_erlang_externals,
+        },
+        TargetBadge {
+            name: "JavaScript",
+            available: can_run_on_javascript,
+            uses_externals: uses_javascript_externals,
+        },
+        TargetBadge {
+            name: "Go",
+            available: can_run_on_go,
+            uses_externals: uses_go_externals,
+        },
+    ]
+}
+
+/// For each target, the names of the functions and constants of a module that
+/// can't be used on it. Targets everything is available on are left out.
+fn unavailable_definitions<'a>(
+    functions: &[DocsFunction<'a>],
+    constants: &[Constant<'a>],
+) -> Vec<UnavailableDefinitions<'a>> {
+    let definitions = constants
+        .iter()
+        .map(|constant| (constant.name, &constant.targets))
+        .chain(
+            functions
+                .iter()
+                .map(|function| (function.name, &function.targets)),
+        )
+        .collect_vec();
+
+    let Some((_, targets)) = definitions.first() else {
+        return vec![];
+    };
+
+    targets
+        .iter()
+        .enumerate()
+        .map(|(index, target)| UnavailableDefinitions {
+            target: target.name,
+            names: definitions
+                .iter()
+                .filter(|(_, targets)| targets.get(index).is_some_and(|target| !target.available))
+                .map(|(name, _)| *name)
+                .collect(),
+        })
+        .filter(|unavailable| !unavailable.names.is_empty())
+        .collect()
+}
+
 fn text_documentation(doc: &Option<(u32, EcoString)>) -> String {
     let raw_text = doc
         .as_ref()
@@ -715,6 +785,7 @@ fn constant<'a>(
             name,
             value,
             location,
+            implementations,
             ..
         }) => Some(Constant {
             name,
@@ -722,6 +793,7 @@ fn constant<'a>(
             documentation: markdown_documentation(doc),
             text_documentation: text_documentation(doc),
             source_url: source_links.url(*location),
+            targets: target_badges(implementations),
         }),
 
         _ => None,
@@ -746,6 +818,32 @@ struct DocsFunction<'a> {
     text_documentation: String,
     source_url: String,
     deprecation_message: String,
+    targets: Vec<TargetBadge>,
+}
+
+/// Whether a function or constant can be used on a target, shown as a badge
+/// next to its name.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug)]
+struct TargetBadge {
+    name: &'static str,
+    available: bool,
+    uses_externals: bool,
+}
+
+impl TargetBadge {
+    fn title(&self) -> String {
+        match (self.available, self.uses_externals) {
+            (false, _) => format!("Not available on {}", self.name),
+            (true, true) => format!("Available on {0} using {0} externals", self.name),
+            (true, false) => format!("Available on {}", self.name),
+        }
+    }
+}
+
+/// The functions and constants of a module that can't be used on a target.
+struct UnavailableDefinitions<'a> {
+    target: &'static str,
+    names: Vec<&'a str>,
 }
 
 #[derive(PartialEq, Eq, PartialOrd, Ord, Debug)]
@@ -781,6 +879,7 @@ struct Constant<'a> {
     documentation: String,
     text_documentation: String,
     source_url: String,
+    targets: Vec<TargetBadge>,
 }
 
 #[derive(Template)]
@@ -819,6 +918,7 @@ struct ModuleTemplate<'a> {
     functions: Vec<DocsFunction<'a>>,
     types: Vec<Type<'a>>,
     constants: Vec<Constant<'a>>,
+    unavailable: Vec<UnavailableDefinitions<'a>>,
     documentation: String,
     rendering_timestamp: &'a str,
 }
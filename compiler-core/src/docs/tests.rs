@@ -481,3 +481,24 @@ pub fn one() {
         }
     ));
 }
+
+#[test]
+fn target_availability_is_shown() {
+    let mut config = PackageConfig::default();
+    config.name = EcoString::from("test_project_name");
+    let modules = vec![(
+        "app.gleam",
+        r#"
+pub fn pure() { 1 }
+
+@external(erlang, "app_ffi", "erlang_only")
+pub fn erlang_only() -> Int
+
+@external(erlang, "app_ffi", "with_fallback")
+pub fn with_fallback() -> Int { 1 }
+
+pub const also_erlang_only = erlang_only
+"#,
+    )];
+    insta::assert_snapshot!(compile(config, modules));
+}
@@ -15,8 +15,8 @@ use crate::{
     line_numbers::LineNumbers,
     pretty::*,
     type_::{
-        ModuleValueConstructor, PatternConstructor, Type, TypeVar, TypedCallArg, ValueConstructor,
-        ValueConstructorVariant,
+        ModuleValueConstructor, PatternConstructor, Type, TypeVar, TypeVarCell, TypedCallArg,
+        ValueConstructor, ValueConstructorVariant,
     },
     Result,
 };
@@ -324,7 +324,7 @@ fn register_imports(
                 .filter(|&id| !constructor_var_usages.contains_key(id))
                 .sorted()
                 .map(|&id| Type::Var {
-                    type_: Arc::new(std::cell::RefCell::new(TypeVar::Generic { id })),
+                    type_: Arc::new(TypeVarCell::new(TypeVar::Generic { id })),
                 })
                 .collect();
             let phantom_vars_constructor = if !phantom_vars.is_empty() {
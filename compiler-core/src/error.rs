@@ -230,6 +230,25 @@ file_names.iter().map(|x| x.as_str()).join(", "))]
         invalid_runtime: Runtime,
     },
 
+    #[error("Executables can not be built for the {target} target")]
+    UnsupportedBinaryTarget { target: Target },
+
+    #[error("Go libraries can not be built for the {target} target")]
+    UnsupportedLibraryTarget { target: Target },
+
+    #[error("The shell can not be started for the {target} target")]
+    UnsupportedShellTarget { target: Target },
+
+    #[error("modules {first} and {second} have the same Go package name {name}")]
+    GoPackageNameClash {
+        name: EcoString,
+        first: Name,
+        second: Name,
+    },
+
+    #[error("invalid Go package name {name} for module {module}")]
+    InvalidGoPackageName { module: Name, name: EcoString },
+
     #[error("package downloading failed: {error}")]
     DownloadPackageError {
         package_name: String,
@@ -3089,6 +3108,93 @@ implementation but the function name `{function}` is not valid."
                     }
                 }
 
+                TypeError::UnknownGoExternalFunction {
+                    location,
+                    package,
+                    function,
+                } => {
+                    let text = wrap_format!(
+                        "The Go package `{package}` does not declare a function \
+named `{function}`."
+                    );
+                    Diagnostic {
+                        title: "Unknown Go function".into(),
+                        text,
+                        hint: Some("Check the function name and the Go files of the package.".into()),
+                        level: Level::Error,
+                        location: Some(Location {
+                            label: Label {
+                                text: None,
+                                span: *location,
+                            },
+                            path: path.clone(),
+                            src: src.clone(),
+                            extra_labels: vec![],
+                        }),
+                    }
+                }
+
+                TypeError::IncorrectGoExternalArity {
+                    location,
+                    function,
+                    expected,
+                    given,
+                    variadic,
+                } => {
+                    let at_least = if *variadic { "at least " } else { "" };
+                    let expected = if *variadic { expected - 1 } else { *expected };
+                    let text = wrap_format!(
+                        "The Go function `{function}` takes {at_least}{expected} \
+argument{}, but this Gleam function takes {given}.",
+                        if expected == 1 { "" } else { "s" }
+                    );
+                    Diagnostic {
+                        title: "Incorrect arity".into(),
+                        text,
+                        hint: None,
+                        level: Level::Error,
+                        location: Some(Location {
+                            label: Label {
+                                text: None,
+                                span: *location,
+                            },
+                            path: path.clone(),
+                            src: src.clone(),
+                            extra_labels: vec![],
+                        }),
+                    }
+                }
+
+                TypeError::IncompatibleGoExternalParameter {
+                    location,
+                    function,
+                    position,
+                    gleam_type,
+                    go_type,
+                } => {
+                    let mut printer = Printer::new(names);
+                    let gleam_type = printer.print_type(gleam_type);
+                    let text = wrap_format!(
+                        "Argument {position} of this function is of type `{gleam_type}`, \
+but the Go function `{function}` expects it to be of type `{go_type}`."
+                    );
+                    Diagnostic {
+                        title: "Incompatible Go type".into(),
+                        text,
+                        hint: None,
+                        level: Level::Error,
+                        location: Some(Location {
+                            label: Label {
+                                text: None,
+                                span: *location,
+                            },
+                            path: path.clone(),
+                            src: src.clone(),
+                            extra_labels: vec![],
+                        }),
+                    }
+                }
+
                 TypeError::InexhaustiveLetAssignment { location, missing } => {
                     let mut text =wrap(
                         "This assignment uses a pattern that does not \
@@ -4009,6 +4115,84 @@ satisfying {required_version} but you are using v{gleam_version}.",
                 }]
             }
 
+            Error::UnsupportedBinaryTarget { target } => {
+                let text = format!(
+                    "Native executables can only be built for the Go target, \
+but this project is being built for the {target} target."
+                );
+                vec![Diagnostic {
+                    title: "Unsupported target for executable".into(),
+                    text,
+                    hint: Some("Run the build again with `--target go`.".into()),
+                    location: None,
+                    level: Level::Error,
+                }]
+            }
+
+            Error::UnsupportedLibraryTarget { target } => {
+                let text = format!(
+                    "Go library packages can only be built for the Go target, \
+but this project is being built for the {target} target."
+                );
+                vec![Diagnostic {
+                    title: "Unsupported target for library".into(),
+                    text,
+                    hint: Some("Run the build again with `--target go`.".into()),
+                    location: None,
+                    level: Level::Error,
+                }]
+            }
+
+            Error::UnsupportedShellTarget { target } => {
+                let text = format!(
+                    "An interactive shell is available for the Erlang and Go targets, \
+but this project is being built for the {target} target."
+                );
+                vec![Diagnostic {
+                    title: "Unsupported target for shell".into(),
+                    text,
+                    hint: Some("Run the shell again with `--target erlang` or `--target go`.".into()),
+                    location: None,
+                    level: Level::Error,
+                }]
+            }
+
+            Error::GoPackageNameClash {
+                name,
+                first,
+                second,
+            } => {
+                let text = format!(
+                    "The modules `{first}` and `{second}` are both compiled to the \
+Go package `{name}`, so they can't be imported into the same Go package."
+                );
+                vec![Diagnostic {
+                    title: "Clashing Go package names".into(),
+                    text,
+                    hint: Some("Rename one of the modules.".into()),
+                    location: None,
+                    level: Level::Error,
+                }]
+            }
+
+            Error::InvalidGoPackageName { module, name } => {
+                let text = format!(
+                    "The Go package name `{name}` given to the module `{module}` in \
+gleam.toml is not a valid Go identifier."
+                );
+                vec![Diagnostic {
+                    title: "Invalid Go package name".into(),
+                    text,
+                    hint: Some(
+                        "Go package names may only contain letters, numbers, and underscores, \
+and must not start with a number or be a Go keyword."
+                            .into(),
+                    ),
+                    location: None,
+                    level: Level::Error,
+                }]
+            }
+
             Error::JavaScriptPreludeRequired => vec![Diagnostic {
                 title: "JavaScript prelude required".into(),
                 text: "The --javascript-prelude flag must be given when compiling to JavaScript."
@@ -41,13 +41,13 @@ use crate::{
         collapse_links,
         error::{UnknownTypeConstructorError, UnreachableCaseClauseReason},
         is_prelude_module, Environment, Type, TypeValueConstructor, TypeValueConstructorField,
-        TypeVar,
+        TypeVar, TypeVarCell,
     },
 };
 use ecow::EcoString;
 use id_arena::Arena;
 use itertools::Itertools;
-use std::{cell::RefCell, collections::HashMap, sync::Arc};
+use std::{collections::HashMap, sync::Arc};
 
 pub use self::pattern::PatternArena;
 
@@ -1026,7 +1026,7 @@ impl ConstructorSpecialiser {
             },
 
             Type::Var { type_ } => Type::Var {
-                type_: Arc::new(RefCell::new(self.specialise_var(type_))),
+                type_: Arc::new(TypeVarCell::new(self.specialise_var(type_))),
             },
 
             Type::Tuple { elems } => Type::Tuple {
@@ -1035,7 +1035,7 @@ impl ConstructorSpecialiser {
         })
     }
 
-    fn specialise_var(&self, type_: &RefCell<TypeVar>) -> TypeVar {
+    fn specialise_var(&self, type_: &TypeVarCell) -> TypeVar {
         match &*type_.borrow() {
             TypeVar::Unbound { id } => TypeVar::Unbound { id: *id },
 
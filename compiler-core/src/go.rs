@@ -1,10 +1,14 @@
 mod endianness;
 mod expression;
+pub mod ffi;
 mod import;
+pub mod library;
 mod pattern;
+pub mod shell;
 #[cfg(test)]
 mod tests;
 
+use std::collections::{hash_map::Entry, HashMap};
 use std::ops::Deref;
 use std::sync::Arc;
 
@@ -42,8 +46,17 @@ pub struct Generator<'a> {
     module: &'a TypedModule,
     tracker: UsageTracker,
     module_scope: im::HashMap<EcoString, (bool, usize)>,
-    target_support: TargetSupport,
-    go_module_path: &'a str,
+    options: ModuleOptions<'a>,
+}
+
+/// How a module is compiled to Go.
+#[derive(Debug, Clone, Copy)]
+pub struct ModuleOptions<'a> {
+    pub target_support: TargetSupport,
+    /// The path of the Go module that the packages are generated in.
+    pub go_module_path: &'a str,
+    /// The name given to the module's Go package in `gleam.toml`, if any.
+    pub package_name: Option<&'a str>,
 }
 
 enum DynamicSpecialCases {
@@ -55,8 +68,7 @@ impl<'a> Generator<'a> {
         dep_modules: &'a im::HashMap<EcoString, ModuleInterface>,
         line_numbers: &'a LineNumbers,
         module: &'a TypedModule,
-        target_support: TargetSupport,
-        go_module_path: &'a str,
+        options: ModuleOptions<'a>,
     ) -> Self {
         Self {
             dep_modules,
@@ -64,20 +76,16 @@ impl<'a> Generator<'a> {
             module,
             tracker: UsageTracker::default(),
             module_scope: Default::default(),
-            target_support,
-            go_module_path,
+            options,
         }
     }
 
     pub fn compile(&mut self) -> Output<'a> {
-        let package_short_name = self
-            .module
-            .name
-            .split('/')
-            .last()
-            .expect("failed to get short package name");
-        let package_decl: EcoString =
-            format!("package {}\n", to_go_package_name(package_short_name)).into();
+        let package_name = match self.options.package_name {
+            Some(name) => name.into(),
+            None => to_go_package_name(&self.module.name),
+        };
+        let package_decl: EcoString = format!("package {package_name}\n").into();
 
         // Determine what Go imports we need to generate
         let mut imports = self.collect_imports();
@@ -167,6 +175,10 @@ impl<'a> Generator<'a> {
             self.register_prelude_usage(&mut imports, "divideFloat", None);
         };
 
+        if self.tracker.float_check_used {
+            self.register_prelude_usage(&mut imports, "checkFloat", None);
+        };
+
         if self.tracker.int_division_used {
             self.register_prelude_usage(&mut imports, "divideInt", None);
         };
@@ -291,7 +303,7 @@ impl<'a> Generator<'a> {
         publicity: Publicity,
         constructors: &'a [TypedRecordConstructor],
         opaque: bool,
-        _parameters: &Vec<EcoString>,
+        parameters: &[EcoString],
         typed_parameters: &[Arc<Type>],
         external_go: Option<(EcoString, EcoString)>,
     ) -> Vec<Output<'a>> {
@@ -325,6 +337,26 @@ impl<'a> Generator<'a> {
         let type_name = to_go_type_name(name, publicity.is_public());
         let cons_public = publicity.is_public() && !opaque;
 
+        if let Some((go_pkg, go_name)) = &external_go {
+            if let Some(go_type) = wrapped_external_go_type(go_name) {
+                return self.wrapped_external_type(
+                    &type_name,
+                    external_go_type(go_pkg, go_type, parameters, typed_parameters),
+                    type_params_full_doc,
+                    type_params_sqparen,
+                );
+            }
+            if go_name.contains('[') {
+                return vec![Ok(docvec![
+                    "type ",
+                    &type_name,
+                    type_params_full_doc,
+                    " = ",
+                    external_go_type(go_pkg, go_name, parameters, typed_parameters),
+                ])];
+            }
+        }
+
         if constructors.len() == 1 {
             match &external_go {
                 Some((go_pkg, go_name)) if go_pkg == "" && go_name == &type_name => vec![],
@@ -334,7 +366,7 @@ impl<'a> Generator<'a> {
                     type_params_full_doc.clone(),
                     " = ",
                     if go_pkg != "" {
-                        docvec![to_go_package_name(go_pkg), "."]
+                        docvec![to_go_package_name(external_go_package_alias(go_pkg)), "."]
                     } else {
                         nil()
                     },
@@ -380,57 +412,13 @@ impl<'a> Generator<'a> {
                     ];
 
                     let con_hash = docvec![
-                        "func (c ",
-                        &con_name,
-                        type_params_sqparen.clone(),
-                        ") Hash() uint32 {",
-                        if con.arguments.is_empty() {
-                            docvec![
-                                "return ",
-                                to_go_package_name(PRELUDE_MODULE_NAME),
-                                ".NilHash }"
-                            ]
-                        } else {
-                            docvec![
-                                docvec![
-                                    line(),
-                                    "h := ",
-                                    to_go_package_name(PRELUDE_MODULE_NAME),
-                                    ".NewHash()",
-                                    line(),
-                                    "var hh uint32",
-                                    con.arguments.iter().enumerate().map(
-                                        |(i, arg)| {
-                                            docvec![
-                                                line(),
-                                                "hh = c.",
-                                                arg.label
-                                                    .as_ref()
-                                                    .map(|(_, s)| to_go_common_field_name(
-                                                        s,
-                                                        cons_public,
-                                                        true,
-                                                        false
-                                                    ))
-                                                    .unwrap_or(to_go_positional_field_name(
-                                                        i.try_into().unwrap(),
-                                                        cons_public
-                                                    )),
-                                                ".Hash()",
-                                                line(),
-                                                "if _, err := h.Write([]byte{byte(hh), byte(hh >> 8), byte(hh >> 16), byte(hh >> 24)}); err != nil { panic (err) }"
-                                            ]
-                                        }
-                                    )
-                                    .collect::<Vec<_>>(),
-                                    line(),
-                                    "return h.Sum32()",
-                                ]
-                                .nest(INDENT),
-                                line(),
-                                "}",
-                            ]
-                        },
+                        hash_method(
+                            &con_name,
+                            type_params_sqparen.clone(),
+                            None,
+                            &con.arguments,
+                            cons_public
+                        ),
                         line(),
                         "func (c ",
                         &con_name,
@@ -531,8 +519,7 @@ impl<'a> Generator<'a> {
                     ".Bool_t"
                 ];
                 let as_doc = docvec![
-                    if cons_public { "As" } else { "as" },
-                    clean_name,
+                    to_go_variant_cast_name(&con.name, cons_public),
                     "() ",
                     go_name,
                     type_params_sqparen.clone()
@@ -548,7 +535,7 @@ impl<'a> Generator<'a> {
                     docvec![
                         "= ",
                         if go_pkg != "" {
-                            docvec![to_go_package_name(go_pkg), "."]
+                            docvec![to_go_package_name(external_go_package_alias(go_pkg)), "."]
                         } else {
                             nil()
                         },
@@ -667,6 +654,33 @@ impl<'a> Generator<'a> {
                                 ])
                             })
                             .collect::<Vec<_>>();
+                        let with_docs = con
+                            .arguments
+                            .iter()
+                            .filter_map(|arg| {
+                                let (_, label) = arg.label.as_ref()?;
+                                Some(Ok(docvec![
+                                    "func (c ",
+                                    &con_name,
+                                    type_params_sqparen.clone(),
+                                    ") ",
+                                    to_go_with_method_name(label, cons_public),
+                                    "(v ",
+                                    type_doc(
+                                        self.module,
+                                        &arg.type_,
+                                        &mut self.tracker,
+                                        &generic_ids
+                                    ),
+                                    ") ",
+                                    &con_name,
+                                    type_params_sqparen.clone(),
+                                    " { c.",
+                                    to_go_field_name(label, cons_public),
+                                    " = v; return c }",
+                                ]))
+                            })
+                            .collect::<Vec<_>>();
                         let cast_docs = constructors.iter().map(|con2| {
                             let clean_name = EcoString::from(con2.name.to_upper_camel_case());
                             let go_name = to_go_constructor_name(&con2.name, cons_public);
@@ -692,8 +706,7 @@ impl<'a> Generator<'a> {
                                 &con_name,
                                 type_params_sqparen.clone(),
                                 ") ",
-                                if cons_public { "As" } else { "as" },
-                                clean_name.clone(),
+                                to_go_variant_cast_name(&con2.name, cons_public),
                                 "() ",
                                 go_name,
                                 type_params_sqparen.clone(),
@@ -731,42 +744,13 @@ impl<'a> Generator<'a> {
                         };
 
                         let con_hash_doc = Ok(docvec![
-                            "func (c ",
-                            &con_name,
-                            type_params_sqparen.clone(),
-                            ") Hash() uint32 { return ",
-                            to_go_package_name(PRELUDE_MODULE_NAME),
-                            ".HashConstructor(",
-                            con_idx,
-                            if con.arguments.is_empty() {
-                                nil()
-                            } else {
-                                docvec![
-                                    ", ",
-                                    comma_separated_list(con.arguments.iter().enumerate().map(
-                                        |(arg_idx, arg)| {
-                                            Ok(docvec![
-                                                "c.",
-                                                arg.label
-                                                    .as_ref()
-                                                    .map(|(_, s)| to_go_common_field_name(
-                                                        s,
-                                                        cons_public,
-                                                        true,
-                                                        false
-                                                    ))
-                                                    .unwrap_or(to_go_positional_field_name(
-                                                        arg_idx.try_into().unwrap(),
-                                                        cons_public
-                                                    )),
-                                                ".Hash()",
-                                            ])
-                                        }
-                                    ))
-                                    .unwrap(),
-                                ]
-                            },
-                            ") }",
+                            hash_method(
+                                &con_name,
+                                type_params_sqparen.clone(),
+                                Some(con_idx),
+                                &con.arguments,
+                                cons_public
+                            ),
                             line(),
                             "func (c ",
                             &con_name,
@@ -823,6 +807,7 @@ impl<'a> Generator<'a> {
                         std::iter::once(con_def_doc)
                             .chain(std::iter::once(con_interface_impl_doc))
                             .chain(common_field_docs)
+                            .chain(with_docs)
                             .chain(cast_docs)
                             .chain(dyn_interface_impl_doc)
                             .chain(std::iter::once(con_hash_doc))
@@ -838,6 +823,45 @@ impl<'a> Generator<'a> {
         }
     }
 
+    /// Defines an external type whose Go type doesn't implement `Type`, see
+    /// `wrapped_external_go_type`. It is wrapped in a struct whose `Hash` and
+    /// `Equal` methods compare the Go values structurally.
+    ///
+    fn wrapped_external_type(
+        &mut self,
+        type_name: &EcoString,
+        go_type: Document<'a>,
+        type_params_full_doc: Document<'a>,
+        type_params_sqparen: Document<'a>,
+    ) -> Vec<Output<'a>> {
+        let prelude = to_go_package_name(PRELUDE_MODULE_NAME);
+        let receiver = docvec!["func (c ", type_name, type_params_sqparen.clone(), ") "];
+        vec![
+            Ok(docvec![
+                "type ",
+                type_name,
+                type_params_full_doc,
+                " struct { Value ",
+                go_type,
+                " }"
+            ]),
+            Ok(docvec![
+                receiver.clone(),
+                "Hash() uint32 { return ",
+                prelude.clone(),
+                ".HashExternal(c.Value) }",
+                line(),
+                receiver,
+                "Equal(o ",
+                type_name,
+                type_params_sqparen,
+                ") bool { return ",
+                prelude,
+                ".EqualExternal(c.Value, o.Value) }",
+            ]),
+        ]
+    }
+
     // fn record_definition(
     //     &self,
     //     constructor: &'a TypedRecordConstructor,
@@ -1234,9 +1258,9 @@ impl<'a> Generator<'a> {
 
     fn import_path(&self, package: &'a str, module: &'a str) -> EcoString {
         if package.is_empty() {
-            eco_format!("{}/{}", self.go_module_path, module)
+            eco_format!("{}/{}", self.options.go_module_path, module)
         } else {
-            eco_format!("{}/{}/{}", self.go_module_path, package, module)
+            eco_format!("{}/{}/{}", self.options.go_module_path, package, module)
         }
     }
 
@@ -1263,20 +1287,9 @@ impl<'a> Generator<'a> {
         as_name: &'a Option<(AssignName, SrcSpan)>,
         unqualified: &'a [UnqualifiedImport],
     ) {
-        let get_name = |module: &'a str| {
-            module
-                .split('/')
-                .last()
-                .expect("Go generator could not identify imported module name.")
-        };
-
-        let (discarded, module_name) = match as_name {
-            None => (false, get_name(module)),
-            Some((AssignName::Discard(_), _)) => (true, get_name(module)),
-            Some((AssignName::Variable(name), _)) => (false, name.as_str()),
-        };
-
-        let module_name = eco_format!("{module_name}");
+        // Modules are imported by the name of their Go package whatever name
+        // they are imported with in Gleam, see `to_go_package_name`.
+        let discarded = matches!(as_name, Some((AssignName::Discard(_), _)));
         let path = self.import_path(package, module);
         let unqualified_imports = unqualified.iter().map(|i| {
             let alias = i.as_name.as_ref().map(|n| {
@@ -1287,21 +1300,14 @@ impl<'a> Generator<'a> {
             Member { name, alias }
         });
 
-        let aliases = if discarded {
-            vec![module.replace("/", "ʹ").into()]
-        } else {
-            vec![module_name]
-        };
         if !discarded || !unqualified.is_empty() {
-            imports.register_module(path, aliases, unqualified_imports);
+            imports.register_module(path, [module.into()], unqualified_imports);
         }
     }
 
     fn register_external_type(&mut self, imports: &mut Imports<'a>, module: &'a str) {
-        let (alias, path) = match module.split_once(' ') {
-            Some((alias, path)) => (alias, path),
-            None => (module.split('/').last().unwrap(), module),
-        };
+        let path = module.split_once(' ').map_or(module, |(_, path)| path);
+        let alias = external_go_package_alias(module);
         imports.register_module(path.into(), [alias.into()], []);
     }
 
@@ -1313,10 +1319,8 @@ impl<'a> Generator<'a> {
         module: &'a str,
         _fun: &'a str,
     ) {
-        let (alias, path) = match module.split_once(' ') {
-            Some((alias, path)) => (alias, path),
-            None => (module.split('/').last().unwrap(), module),
-        };
+        let path = module.split_once(' ').map_or(module, |(_, path)| path);
+        let alias = external_go_package_alias(module);
         imports.register_module(path.into(), [alias.into()], []);
     }
 
@@ -1327,6 +1331,7 @@ impl<'a> Generator<'a> {
         value: &'a TypedConstant,
     ) -> Output<'a> {
         let go_name = to_go_name(name, publicity.is_public());
+        let generic_ids = collect_generic_usages(HashSet::new(), std::iter::once(&value.type_()));
 
         let document = expression::constant_expression(
             &self.dep_modules,
@@ -1334,21 +1339,33 @@ impl<'a> Generator<'a> {
             &mut self.tracker,
             value,
             &self.module_scope,
-            &HashSet::new(),
+            &generic_ids,
         )?;
+        let type_ = type_doc(
+            &self.module,
+            &value.type_(),
+            &mut self.tracker,
+            &generic_ids,
+        );
+
+        // Go variables can't be generic, so a constant whose type still has
+        // type variables becomes a generic function that builds the value.
+        // References to it are called with the type arguments of their use.
+        if generic_ids.is_empty() {
+            return Ok(docvec!["var ", go_name, " ", type_, " = ", document]);
+        }
 
+        let generic_names = generic_ids.iter().sorted().map(|id| id_to_type_var(*id));
         Ok(docvec![
-            "var ",
+            "func ",
             go_name,
-            " ",
-            type_doc(
-                &self.module,
-                &value.type_(),
-                &mut self.tracker,
-                &HashSet::new()
-            ),
-            " = ",
-            document,
+            wrap_generic_params(generic_names),
+            "() ",
+            type_,
+            " {",
+            docvec![line(), "return ", document].nest(INDENT).group(),
+            line(),
+            "}",
         ])
     }
 
@@ -1397,16 +1414,37 @@ impl<'a> Generator<'a> {
                 if external_module == "" && external_name == &go_name {
                     return None;
                 }
-                let go_pkg = match external_module.split_once(' ') {
-                    Some((alias, _)) => alias,
-                    None => external_module.split('/').last().unwrap(),
-                };
-                let argument_names = argument_names
-                    .iter()
-                    .filter_map(|n| n.map(|n| to_go_name(n, false).to_doc()));
+                let go_pkg = external_go_package_alias(external_module);
+                let dep_modules = self.dep_modules;
+                if std::iter::once(&function.return_type)
+                    .chain(function.arguments.iter().map(|argument| &argument.type_))
+                    .any(|type_| {
+                        contains_nested_wrapped_external_type(self.module, dep_modules, type_)
+                    })
+                {
+                    return Some(Err(Error::Unsupported {
+                        feature: "A wrapped external type inside another type in the \
+arguments or return type of a Go external function"
+                            .into(),
+                        location: function.location,
+                    }));
+                }
+                // Wrapped external types are passed to Go and returned from it
+                // as the Go values they wrap.
+                let argument_names = argument_names.iter().zip(&function.arguments).filter_map(
+                    |(name, argument)| {
+                        let name = to_go_name((*name)?, false);
+                        Some(
+                            if is_wrapped_external_type(self.module, dep_modules, &argument.type_) {
+                                docvec![name, ".Value"]
+                            } else {
+                                name.to_doc()
+                            },
+                        )
+                    },
+                );
 
-                docvec![
-                    "return ",
+                let call = docvec![
                     if go_pkg != "" {
                         docvec![to_go_package_name(go_pkg), "."]
                     } else {
@@ -1419,7 +1457,23 @@ impl<'a> Generator<'a> {
                         wrap_generic_args(generic_names.clone())
                     },
                     wrap_args(argument_names),
-                ]
+                ];
+                if is_wrapped_external_type(self.module, dep_modules, &function.return_type) {
+                    docvec![
+                        "return ",
+                        type_doc(
+                            self.module,
+                            &function.return_type,
+                            generator.tracker,
+                            &generic_ids
+                        ),
+                        "{",
+                        call,
+                        "}"
+                    ]
+                } else {
+                    docvec!["return ", call]
+                }
             }
             None => match generator.function_body(&function.body, function.arguments.as_slice()) {
                 // No error, let's continue!
@@ -1428,7 +1482,9 @@ impl<'a> Generator<'a> {
                 // There is an error coming from some expression that is not supported on Go
                 // and the target support is not enforced. In this case we do not error, instead
                 // returning nothing which will cause no function to be generated.
-                Err(error) if error.is_unsupported() && !self.target_support.is_enforced() => {
+                Err(error)
+                    if error.is_unsupported() && !self.options.target_support.is_enforced() =>
+                {
                     return None
                 }
 
@@ -1607,7 +1663,7 @@ fn type_doc(
                 if generic_ids_in_scope.contains(id) {
                     id_to_type_var(*id)
                 } else {
-                    docvec![to_go_package_name(PRELUDE_MODULE_NAME), ".Type"]
+                    docvec![to_go_package_name(PRELUDE_MODULE_NAME), ".Unbound_t"]
                 }
             }
             TypeVar::Link { type_ } => type_doc(self_module, type_, tracker, generic_ids_in_scope),
@@ -1748,6 +1804,146 @@ fn generic_ids(type_: &Type, ids: &mut HashSet<u64>) {
     }
 }
 
+/// The Go type of an external type that has to be wrapped in a struct to
+/// implement `Type`, if it does. Go types that can't have methods, such as maps
+/// and slices, are always wrapped. Any other type is wrapped only if its
+/// external asks for it with the `wrap` prefix, as in
+/// `@external(go, "sync", "wrap *Map[k, v]")`, as its `Hash` and `Equal`
+/// methods are otherwise expected to be defined in Go.
+///
+pub(crate) fn wrapped_external_go_type(go_name: &str) -> Option<&str> {
+    if let Some(go_type) = go_name.strip_prefix("wrap ") {
+        return Some(go_type.trim_start());
+    }
+    let unnamed = [
+        "[",
+        "map[",
+        "chan ",
+        "chan<-",
+        "<-chan",
+        "func(",
+        "struct",
+        "interface",
+    ]
+    .iter()
+    .any(|prefix| go_name.starts_with(prefix));
+    let name = go_name
+        .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .next()
+        .unwrap_or(go_name);
+    if unnamed || (name == go_name && !is_usable_go_identifier(name)) {
+        Some(go_name)
+    } else {
+        None
+    }
+}
+
+/// Whether a type is an external type that is wrapped in a struct, see
+/// `wrapped_external_go_type`. The types of other modules are looked up in
+/// their interfaces, so every module unwraps them in the same way.
+///
+fn is_wrapped_external_type(
+    module: &TypedModule,
+    dep_modules: &im::HashMap<EcoString, ModuleInterface>,
+    type_: &Type,
+) -> bool {
+    let Some((type_module, type_name)) = type_.named_type_name() else {
+        return false;
+    };
+    let interface = if type_module == module.name {
+        Some(&module.type_info)
+    } else {
+        dep_modules.get(&type_module)
+    };
+    interface
+        .and_then(|interface| interface.types.get(&type_name))
+        .is_some_and(|constructor| constructor.go_wrapped)
+}
+
+/// Whether a wrapped external type is used inside another type, such as
+/// `List(Map(k, v))`. Only a wrapped value passed to or returned from Go
+/// directly is unwrapped, so these can't be used in Go externals.
+///
+fn contains_nested_wrapped_external_type(
+    module: &TypedModule,
+    dep_modules: &im::HashMap<EcoString, ModuleInterface>,
+    type_: &Type,
+) -> bool {
+    let contains = |type_: &Arc<Type>| {
+        is_wrapped_external_type(module, dep_modules, type_)
+            || contains_nested_wrapped_external_type(module, dep_modules, type_)
+    };
+    match type_ {
+        Type::Named { .. } if is_wrapped_external_type(module, dep_modules, type_) => false,
+        Type::Named { args, .. } => args.iter().any(contains),
+        Type::Fn { args, retrn } => args.iter().any(contains) || contains(retrn),
+        Type::Tuple { elems } => elems.iter().any(contains),
+        Type::Var { type_ } => match &*type_.borrow() {
+            TypeVar::Link { type_ } => {
+                contains_nested_wrapped_external_type(module, dep_modules, type_)
+            }
+            TypeVar::Unbound { .. } | TypeVar::Generic { .. } => false,
+        },
+    }
+}
+
+/// The Go type an external type is defined as, written as in the external
+/// with the names of the Gleam type parameters replaced by their Go type
+/// parameters, and its first name qualified by the Go package of the external.
+/// A trailing empty type argument list is dropped, so `Map[]` is `Map`.
+///
+fn external_go_type(
+    go_pkg: &str,
+    template: &str,
+    parameters: &[EcoString],
+    typed_parameters: &[Arc<Type>],
+) -> Document<'static> {
+    let type_vars: HashMap<&str, u64> = parameters
+        .iter()
+        .zip(typed_parameters)
+        .filter_map(|(name, type_)| match type_.as_ref() {
+            Type::Var { type_ } => match type_.borrow().deref() {
+                TypeVar::Generic { id } | TypeVar::Unbound { id } => Some((name.as_str(), *id)),
+                TypeVar::Link { .. } => None,
+            },
+            _ => None,
+        })
+        .collect();
+
+    let template = template.strip_suffix("[]").unwrap_or(template);
+    let mut qualify = !go_pkg.is_empty();
+    let mut pieces = vec![];
+    let mut chars = template.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        if !(c.is_alphabetic() || c == '_') {
+            pieces.push(EcoString::from(c).to_doc());
+            continue;
+        }
+        let mut end = start + c.len_utf8();
+        while let Some(&(i, c)) = chars.peek() {
+            if !(c.is_alphanumeric() || c == '_') {
+                break;
+            }
+            end = i + c.len_utf8();
+            let _ = chars.next();
+        }
+        let name = &template[start..end];
+        if let Some(id) = type_vars.get(name) {
+            pieces.push(id_to_type_var(*id));
+        } else if qualify && is_usable_go_identifier(name) {
+            qualify = false;
+            pieces.push(docvec![
+                to_go_package_name(external_go_package_alias(go_pkg)),
+                ".",
+                EcoString::from(name)
+            ]);
+        } else {
+            pieces.push(EcoString::from(name).to_doc());
+        }
+    }
+    concat(pieces)
+}
+
 /// Converts a usize into base 26 A-Z.
 fn id_to_type_var(id: u64) -> Document<'static> {
     if id < 26 {
@@ -1804,31 +2000,137 @@ where
         .group()
 }
 
+/// The `Hash` method of a record constructor. The hashes of the fields are
+/// folded into the prelude's inline hash combiner, preceded by the index of
+/// the constructor when the type has more than one.
+fn hash_method<'a>(
+    con_name: &EcoString,
+    type_params_sqparen: Document<'a>,
+    tag: Option<usize>,
+    arguments: &'a [TypedRecordConstructorArg],
+    cons_public: bool,
+) -> Document<'a> {
+    let prelude = to_go_package_name(PRELUDE_MODULE_NAME);
+    let header = docvec![
+        "func (c ",
+        con_name,
+        type_params_sqparen,
+        ") Hash() uint32 {"
+    ];
+    let initial = match tag {
+        Some(tag) => docvec![
+            &prelude,
+            ".HashWrite32(",
+            &prelude,
+            ".NewHash(), ",
+            tag,
+            ")"
+        ],
+        None => docvec![&prelude, ".NewHash()"],
+    };
+
+    if arguments.is_empty() {
+        let initial = match tag {
+            Some(_) => initial,
+            None => docvec![&prelude, ".NilHash"],
+        };
+        return docvec![header, " return ", initial, " }"];
+    }
+
+    let fields = arguments.iter().enumerate().map(|(i, arg)| {
+        let field = arg
+            .label
+            .as_ref()
+            .map(|(_, s)| to_go_common_field_name(s, cons_public, true, false))
+            .unwrap_or(to_go_positional_field_name(
+                i.try_into().unwrap(),
+                cons_public,
+            ));
+        docvec![
+            line(),
+            "h = ",
+            &prelude,
+            ".HashWrite32(h, c.",
+            field,
+            ".Hash())"
+        ]
+    });
+
+    docvec![
+        header,
+        docvec![
+            line(),
+            "h := ",
+            initial,
+            fields.collect::<Vec<_>>(),
+            line(),
+            "return h"
+        ]
+        .nest(INDENT),
+        line(),
+        "}"
+    ]
+}
+
 pub fn module<'a>(
     dep_modules: &im::HashMap<EcoString, ModuleInterface>,
     module: &'a TypedModule,
     line_numbers: &'a LineNumbers,
     path: &Utf8Path,
     src: &EcoString,
-    target_support: TargetSupport,
-    go_module_path: &'a str,
+    options: ModuleOptions<'a>,
 ) -> Result<String, crate::Error> {
-    let document = Generator::new(
-        dep_modules,
-        line_numbers,
-        module,
-        target_support,
-        go_module_path,
-    )
-    .compile()
-    .map_err(|error| crate::Error::Go {
-        path: path.to_path_buf(),
-        src: src.clone(),
-        error,
-    })?;
+    let document = Generator::new(dep_modules, line_numbers, module, options)
+        .compile()
+        .map_err(|error| crate::Error::Go {
+            path: path.to_path_buf(),
+            src: src.clone(),
+            error,
+        })?;
     Ok(document.to_pretty_string(80))
 }
 
+/// Checks that no two modules are compiled to Go packages with the same name,
+/// as they could not both be imported into the same Go package, and that the
+/// package names given to modules in `gleam.toml` are usable.
+///
+pub fn check_package_names<'b>(
+    modules: impl IntoIterator<Item = &'b EcoString>,
+    package_names: &HashMap<EcoString, EcoString>,
+) -> Result<(), crate::Error> {
+    for (module, name) in package_names.iter().sorted() {
+        let mut chars = name.chars();
+        let valid = chars
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+            && is_usable_go_identifier(name);
+        if !valid {
+            return Err(crate::Error::InvalidGoPackageName {
+                module: module.clone(),
+                name: name.clone(),
+            });
+        }
+    }
+
+    let mut seen: HashMap<EcoString, &EcoString> = HashMap::new();
+    for module in modules.into_iter().sorted().dedup() {
+        match seen.entry(to_go_package_name(module)) {
+            Entry::Vacant(entry) => {
+                let _ = entry.insert(module);
+            }
+            Entry::Occupied(entry) => {
+                return Err(crate::Error::GoPackageNameClash {
+                    name: entry.key().clone(),
+                    first: (*entry.get()).clone(),
+                    second: module.clone(),
+                })
+            }
+        }
+    }
+    Ok(())
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Error {
     Unsupported { feature: String, location: SrcSpan },
@@ -1977,10 +2279,24 @@ fn maybe_escape_identifier_doc(word: &str) -> Document<'_> {
     }
 }
 
+/// The name a Go package referred to by a Go external is imported with: either
+/// the alias given before its path, as in `"str strings"`, or the last segment
+/// of its path.
+fn external_go_package_alias(module: &str) -> &str {
+    match module.split_once(' ') {
+        Some((alias, _)) => alias,
+        None => module.split('/').last().unwrap_or(module),
+    }
+}
+
+/// The name of the Go package of a Gleam module, which is also the name it is
+/// imported with. Every segment of the module's name is kept so that modules
+/// with the same last segment don't clash when imported into the same package;
+/// `check_package_names` reports any names that clash nonetheless.
 fn to_go_package_name(name: &str) -> EcoString {
     eco_format!(
         "{}_P",
-        maybe_escape_identifier_string(name.split('/').last().unwrap())
+        maybe_escape_identifier_string(&name.replace('/', "_"))
     )
 }
 
@@ -2044,6 +2360,23 @@ fn to_go_common_field_name(
     }
 }
 
+/// The name of the method returning a copy of a variant with the given field
+/// replaced, used to update records of types with multiple constructors.
+///
+/// `to_go_name` camel cases labels, so it never produces an underscore after
+/// the first character. The `_with` suffix therefore can't clash with the
+/// field, getter or update method of another label, such as `name_with`.
+fn to_go_with_method_name(name: &str, public: bool) -> EcoString {
+    eco_format!("{}_with", to_go_name(name, public))
+}
+
+/// The name of the method converting a value of a type with multiple
+/// constructors to the struct of the given variant.
+fn to_go_variant_cast_name(name: &str, public: bool) -> EcoString {
+    let prefix = if public { "As" } else { "as" };
+    eco_format!("{prefix}{}", name.to_upper_camel_case())
+}
+
 fn to_go_positional_field_name(i: u64, public: bool) -> EcoString {
     if public {
         eco_format!("P_{i}")
@@ -2072,6 +2405,7 @@ pub(crate) struct UsageTracker {
     pub custom_type_used: bool,
     pub int_division_used: bool,
     pub float_division_used: bool,
+    pub float_check_used: bool,
     pub object_equality_used: bool,
     pub bit_array_used: bool,
     pub utf_codepoint_used: bool,
@@ -235,11 +235,12 @@ impl<'module> Generator<'module> {
                 self.force_use(res, unused)
             }
             TypedExpr::RecordUpdate {
+                type_,
                 record,
                 constructor,
                 args,
                 ..
-            } => self.record_update(record, constructor, args, unused),
+            } => self.record_update(type_, record, constructor, args, unused),
 
             TypedExpr::Var {
                 name, constructor, ..
@@ -260,9 +261,13 @@ impl<'module> Generator<'module> {
             }
 
             TypedExpr::BinOp {
-                name, left, right, ..
+                name,
+                left,
+                right,
+                location,
+                ..
             } => {
-                let res = self.bin_op(name, left, right);
+                let res = self.bin_op(name, left, right, *location);
                 self.force_use(res, unused)
             }
 
@@ -281,12 +286,19 @@ impl<'module> Generator<'module> {
 
             TypedExpr::ModuleSelect {
                 module_alias,
+                module_name,
                 label,
                 constructor,
                 type_,
                 ..
             } => {
-                let res = Ok(self.module_select(module_alias, label, constructor, type_.clone()));
+                let res = Ok(self.module_select(
+                    module_alias,
+                    module_name,
+                    label,
+                    constructor,
+                    type_.clone(),
+                ));
                 self.force_use(res, unused)
             }
 
@@ -677,15 +689,7 @@ impl<'module> Generator<'module> {
                             .expect("dependent module exists");
 
                         Some((
-                            docvec![
-                                &to_go_package_name(
-                                    &import
-                                        .used_name()
-                                        .unwrap_or(import.module.clone())
-                                        .replace("/", "ʹ")
-                                ),
-                                "."
-                            ],
+                            docvec![&to_go_package_name(&import.module), "."],
                             &value.name,
                             origin_module
                                 .get_public_value(&value.name)
@@ -750,12 +754,7 @@ impl<'module> Generator<'module> {
                             .expect("dependent module exists");
 
                         Some((
-                            docvec![
-                                &to_go_package_name(
-                                    &import.used_name().unwrap_or(import.module.clone())
-                                ),
-                                "."
-                            ],
+                            docvec![&to_go_package_name(&import.module), "."],
                             &value.name,
                             origin_module
                                 .get_public_value(&value.name)
@@ -791,13 +790,15 @@ impl<'module> Generator<'module> {
             })
             .collect::<Vec<_>>();
 
+        // Generic constants are generated as functions, see `module_constant`
         if type_args.is_empty() {
             Ok(docvec![module, to_go_name(name, public)])
         } else {
             Ok(docvec![
                 module,
                 to_go_name(name, public),
-                wrap_generic_args(type_args)
+                wrap_generic_args(type_args),
+                "()"
             ])
         }
     }
@@ -1133,7 +1134,7 @@ impl<'module> Generator<'module> {
             // Qualified record construction
             TypedExpr::ModuleSelect {
                 constructor: ModuleValueConstructor::Record { name, type_, .. },
-                module_alias,
+                module_name,
                 ..
             } => {
                 let (_, _, type_args) = type_
@@ -1145,7 +1146,7 @@ impl<'module> Generator<'module> {
                     &self.module,
                     &mut self.tracker,
                     &self.generic_type_ids_in_scope,
-                    Some(module_alias.clone()),
+                    Some(module_name.clone()),
                     name.to_owned(),
                     &type_args,
                     arguments,
@@ -1312,35 +1313,111 @@ impl<'module> Generator<'module> {
         let single_constructor =
             is_type_single_constructor(&self.dep_modules, &self.module, &type_module, &type_name);
 
+        // When the variant of the record is known the field is read from the
+        // struct of that variant, as fields that aren't shared by all the
+        // variants have no getter on the interface.
+        let field = match self.variant_cast(&record.type_()) {
+            Some(cast) if !single_constructor => {
+                docvec![cast, "().", to_go_field_name(label, public)]
+            }
+            _ => to_go_common_field_name(label, public, single_constructor, true).to_doc(),
+        };
+
         self.not_in_tail_position(|gen| {
             let record = gen.wrap_expression(record)?;
-            Ok(docvec![
-                record,
-                ".",
-                to_go_common_field_name(label, public, single_constructor, true)
-            ])
+            Ok(docvec![record, ".", field.clone()])
         })
     }
 
     fn record_update<'a>(
         &mut self,
+        type_: &Type,
         record: &'a TypedAssignment,
         constructor: &'a TypedExpr,
         args: &'a [TypedCallArg],
         unused: bool,
     ) -> Output<'a> {
+        let assignment = self.not_in_tail_position(|gen| gen.assignment(record))?;
+        let update = match self.record_update_with(type_, record, args)? {
+            Some(update) => self.wrap_return(update),
+            None => self.call(constructor, args)?,
+        };
         Ok(docvec![
-            self.not_in_tail_position(|gen| gen.assignment(record))?,
+            assignment,
             line(),
             if !self.scope_position.is_tail() && unused {
                 "_ = ".to_doc()
             } else {
                 nil()
             },
-            self.call(constructor, args)?,
+            update,
         ])
     }
 
+    /// Updates a record of a type with multiple constructors by converting it to
+    /// the struct of its variant and replacing the given fields with the `with`
+    /// helpers of that variant, rather than building a new struct from the
+    /// interface getters.
+    ///
+    /// Returns `None` if the record is built from scratch instead, which is the
+    /// case for types with a single constructor or if the update changes the
+    /// type parameters of the record.
+    fn record_update_with<'a>(
+        &mut self,
+        update_type: &Type,
+        record: &'a TypedAssignment,
+        args: &'a [TypedCallArg],
+    ) -> Result<Option<Document<'a>>, Error> {
+        let Pattern::Variable { name, .. } = &record.pattern else {
+            return Ok(None);
+        };
+        let type_ = record.value.type_();
+        let Some((type_module, type_name)) = type_.named_type_name() else {
+            return Ok(None);
+        };
+        if is_type_single_constructor(self.dep_modules, self.module, &type_module, &type_name) {
+            return Ok(None);
+        }
+        let Some(cast) = self.variant_cast(&type_) else {
+            return Ok(None);
+        };
+        if !update_type.same_as(&type_) {
+            return Ok(None);
+        }
+
+        let public = is_type_public_and_transparent(self.module, &type_name);
+        let mut doc = docvec![self.local_var(name), ".", cast, "()"];
+        for arg in args.iter().filter(|arg| arg.implicit.is_none()) {
+            let label = arg.label.as_ref().expect("record update label");
+            let value = self.not_in_tail_position(|gen| gen.wrap_expression(&arg.value))?;
+            doc = docvec![
+                doc,
+                ".",
+                to_go_with_method_name(label, public),
+                "(",
+                value,
+                ")"
+            ];
+        }
+        Ok(Some(doc))
+    }
+
+    /// The name of the method converting a value of the given type to the
+    /// struct of its variant, if the type has a known variant.
+    fn variant_cast(&self, type_: &Type) -> Option<EcoString> {
+        let index = type_.custom_type_inferred_variant()?;
+        let (type_module, type_name) = type_.named_type_name()?;
+        let constructor = type_constructor_name(
+            self.dep_modules,
+            self.module,
+            &type_module,
+            &type_name,
+            index,
+        )?;
+        let public = is_type_public_and_transparent(self.module, &type_name);
+        Some(to_go_variant_cast_name(&constructor, public))
+    }
+
     fn tuple_index<'a>(&mut self, tuple: &'a TypedExpr, index: u64) -> Output<'a> {
         self.not_in_tail_position(|gen| {
             let tuple = gen.wrap_expression(tuple)?;
@@ -1357,6 +1434,7 @@ impl<'module> Generator<'module> {
         name: &'a BinOp,
         left: &'a TypedExpr,
         right: &'a TypedExpr,
+        location: SrcSpan,
     ) -> Output<'a> {
         match name {
             BinOp::And => self.print_bin_op(left, right, "&&"),
@@ -1367,17 +1445,52 @@ impl<'module> Generator<'module> {
             BinOp::NotEq => self.equal(left, right, false),
             BinOp::GtInt | BinOp::GtFloat => self.print_bin_op(left, right, ">"),
             BinOp::GtEqInt | BinOp::GtEqFloat => self.print_bin_op(left, right, ">="),
-            BinOp::Concatenate | BinOp::AddInt | BinOp::AddFloat => {
-                self.print_bin_op(left, right, "+")
-            }
-            BinOp::SubInt | BinOp::SubFloat => self.print_bin_op(left, right, "-"),
-            BinOp::MultInt | BinOp::MultFloat => self.print_bin_op(left, right, "*"),
+            BinOp::Concatenate | BinOp::AddInt => self.print_bin_op(left, right, "+"),
+            BinOp::SubInt => self.print_bin_op(left, right, "-"),
+            BinOp::MultInt => self.print_bin_op(left, right, "*"),
+            BinOp::AddFloat => self.float_op(left, right, "+", location),
+            BinOp::SubFloat => self.float_op(left, right, "-", location),
+            BinOp::MultFloat => self.float_op(left, right, "*", location),
             BinOp::RemainderInt => self.remainder_int(left, right),
             BinOp::DivInt => self.div_int(left, right),
-            BinOp::DivFloat => self.div_float(left, right),
+            BinOp::DivFloat => self.div_float(left, right, location),
         }
     }
 
+    fn float_op<'a>(
+        &mut self,
+        left: &'a TypedExpr,
+        right: &'a TypedExpr,
+        op: &'a str,
+        location: SrcSpan,
+    ) -> Output<'a> {
+        let operation = self.print_bin_op(left, right, op)?;
+        Ok(self.check_float(operation, location))
+    }
+
+    /// Go floats overflow to infinity where Erlang raises an error, so the
+    /// result of float arithmetic is checked to be finite.
+    pub(super) fn check_float<'a>(
+        &mut self,
+        value: Document<'a>,
+        location: SrcSpan,
+    ) -> Document<'a> {
+        self.tracker.float_check_used = true;
+        let module = self.module.name.clone().to_doc().surround('"', '"');
+        let function = self
+            .function_name
+            .clone()
+            .unwrap_or_default()
+            .to_doc()
+            .surround("\"", "\"");
+        let line = self.line_numbers.line_number(location.start).to_doc();
+        docvec![
+            to_go_package_name(PRELUDE_MODULE_NAME),
+            ".CheckFloat",
+            wrap_args([value, module, line, function])
+        ]
+    }
+
     fn div_int<'a>(&mut self, left: &'a TypedExpr, right: &'a TypedExpr) -> Output<'a> {
         let left = self.not_in_tail_position(|gen| gen.child_expression(left))?;
         let right = self.not_in_tail_position(|gen| gen.child_expression(right))?;
@@ -1400,15 +1513,21 @@ impl<'module> Generator<'module> {
         ])
     }
 
-    fn div_float<'a>(&mut self, left: &'a TypedExpr, right: &'a TypedExpr) -> Output<'a> {
+    fn div_float<'a>(
+        &mut self,
+        left: &'a TypedExpr,
+        right: &'a TypedExpr,
+        location: SrcSpan,
+    ) -> Output<'a> {
         let left = self.not_in_tail_position(|gen| gen.child_expression(left))?;
         let right = self.not_in_tail_position(|gen| gen.child_expression(right))?;
         self.tracker.float_division_used = true;
-        Ok(docvec![
+        let division = docvec![
             to_go_package_name(PRELUDE_MODULE_NAME),
             ".DivideFloat",
             wrap_args([left, right])
-        ])
+        ];
+        Ok(self.check_float(division, location))
     }
 
     fn equal<'a>(
@@ -1417,19 +1536,19 @@ impl<'module> Generator<'module> {
         right: &'a TypedExpr,
         should_be_equal: bool,
     ) -> Output<'a> {
-        // If it is a simple scalar type then we can use Go's reference identity
+        // If it is a simple scalar type then we can use Go's `==` operator,
+        // which unlike a method call also works on untyped constants
         if is_go_scalar(left.type_()) {
             let left_doc = self.not_in_tail_position(|gen| gen.child_expression(left))?;
             let right_doc = self.not_in_tail_position(|gen| gen.child_expression(right))?;
-            let negation = if should_be_equal { "" } else { "!" };
+            let operator = if should_be_equal { " == " } else { " != " };
             return Ok(docvec![
                 to_go_package_name(PRELUDE_MODULE_NAME),
                 ".Bool_t(",
-                negation,
                 left_doc,
-                ".Equal(",
+                operator,
                 right_doc,
-                "))"
+                ")"
             ]);
         }
 
@@ -1533,6 +1652,7 @@ impl<'module> Generator<'module> {
     fn module_select<'a>(
         &mut self,
         module: &'a str,
+        module_name: &'a str,
         label: &'a str,
         constructor: &'a ModuleValueConstructor,
         type_: Arc<Type>,
@@ -1555,12 +1675,7 @@ impl<'module> Generator<'module> {
                                     .expect("dependent module exists");
 
                                 Some((
-                                    to_go_package_name(
-                                        &import
-                                            .used_name()
-                                            .unwrap_or(import.module.clone())
-                                            .replace("/", "ʹ"),
-                                    ),
+                                    to_go_package_name(&import.module),
                                     origin_module
                                         .get_public_value(label)
                                         .expect("value exists")
@@ -1601,7 +1716,38 @@ impl<'module> Generator<'module> {
             }
 
             ModuleValueConstructor::Constant { .. } => {
-                docvec![to_go_package_name(module), ".", to_go_name(label, true)]
+                let generic_type = self
+                    .dep_modules
+                    .get(module_name)
+                    .and_then(|module| module.get_public_value(label))
+                    .expect("constant exists")
+                    .type_
+                    .clone();
+
+                let mut id_map = im::HashMap::new();
+                solve_type_apps(generic_type, type_, &mut id_map);
+
+                let type_args = id_map
+                    .iter()
+                    .sorted_by_key(|(k, _)| *k)
+                    .map(|(_, v)| {
+                        type_doc(self.module, v, self.tracker, self.generic_type_ids_in_scope)
+                    })
+                    .collect::<Vec<_>>();
+
+                // Generic constants are generated as functions, see `module_constant`
+                let type_args_doc = if type_args.is_empty() {
+                    nil()
+                } else {
+                    docvec![wrap_generic_args(type_args), "()"]
+                };
+
+                docvec![
+                    to_go_package_name(module_name),
+                    ".",
+                    to_go_name(label, true),
+                    type_args_doc
+                ]
             }
 
             ModuleValueConstructor::Record {
@@ -1609,7 +1755,7 @@ impl<'module> Generator<'module> {
             } => record_constructor(
                 &self.module,
                 type_.clone(),
-                Some(module.into()),
+                Some(module_name.into()),
                 name.clone(),
                 *arity,
                 self.tracker,
@@ -1858,6 +2004,49 @@ pub(crate) fn is_type_single_constructor(
     }
 }
 
+/// The name of the constructor of a custom type with the given index.
+fn type_constructor_name(
+    dep_modules: &im::HashMap<EcoString, ModuleInterface>,
+    self_module: &TypedModule,
+    module: &EcoString,
+    name: &EcoString,
+    index: u16,
+) -> Option<EcoString> {
+    let index = usize::from(index);
+    if module == &self_module.name {
+        self_module.definitions.iter().find_map(|def| match def {
+            Definition::CustomType(custom_type) if &custom_type.name == name => custom_type
+                .constructors
+                .get(index)
+                .map(|constructor| constructor.name.clone()),
+            _ => None,
+        })
+    } else {
+        dep_modules
+            .get(module)?
+            .types_value_constructors
+            .get(name)?
+            .variants
+            .get(index)
+            .map(|variant| variant.name.clone())
+    }
+}
+
+/// The name of the module imported with the given name, which is either the
+/// alias given to it with `as` or the last segment of its name.
+fn imported_module_name(self_module: &TypedModule, alias: &str) -> EcoString {
+    self_module
+        .definitions
+        .iter()
+        .find_map(|def| match def {
+            Definition::Import(import) if import.used_name().as_deref() == Some(alias) => {
+                Some(import.module.clone())
+            }
+            _ => None,
+        })
+        .expect("module imported")
+}
+
 fn is_public_constructor(
     self_module: &TypedModule,
     module: Option<&EcoString>,
@@ -2010,7 +2199,9 @@ pub(crate) fn guard_constant_expression<'a>(
             type_,
             ..
         } => {
-            let mut module = module.as_ref().map(|(module, _)| module.clone());
+            let mut module = module
+                .as_ref()
+                .map(|(alias, _)| imported_module_name(self_module, alias));
             if type_.is_result() {
                 if tag == "Ok" {
                     tracker.ok_used = true;
@@ -2168,7 +2359,7 @@ pub(crate) fn constant_expression<'a>(
             ..
         } => {
             let name = match module {
-                Some(module) => (Some(module.0.clone()), name.clone()),
+                Some((alias, _)) => (Some(imported_module_name(self_module, alias)), name.clone()),
                 None if type_.is_result() => {
                     if tag == "Ok" {
                         tracker.ok_used = true;
@@ -2267,27 +2458,23 @@ pub(crate) fn constant_expression<'a>(
             name,
             module,
             type_,
+            constructor,
             ..
         } => {
+            let is_constant = constructor.as_ref().is_some_and(|constructor| {
+                matches!(
+                    constructor.variant,
+                    ValueConstructorVariant::ModuleConstant { .. }
+                )
+            });
             let (module, name, generic_type) = match module {
-                Some((module, _)) => {
-                    let orig_module = self_module
-                        .definitions
-                        .iter()
-                        .find_map(|def| match def {
-                            Definition::Import(import)
-                                if import.used_name().as_ref() == Some(module) =>
-                            {
-                                Some(import.module.clone())
-                            }
-                            _ => None,
-                        })
-                        .expect("module imported");
+                Some((alias, _)) => {
+                    let module = imported_module_name(self_module, alias);
                     (
                         Some(module.clone()),
                         name.clone(),
                         dep_modules
-                            .get(&orig_module)
+                            .get(&module)
                             .expect("dependent module")
                             .get_public_value(name)
                             .expect("value exists")
@@ -2351,8 +2538,11 @@ pub(crate) fn constant_expression<'a>(
                 .map(|(_, v)| type_doc(self_module, &v, tracker, generic_type_ids_in_scope))
                 .collect::<Vec<_>>();
 
+            // Generic constants are generated as functions, see `module_constant`
             let type_args_doc = if type_args.is_empty() {
                 nil()
+            } else if is_constant {
+                docvec![wrap_generic_args(type_args), "()"]
             } else {
                 wrap_generic_args(type_args)
             };
@@ -2588,17 +2778,11 @@ pub fn string(value: &str) -> Document<'_> {
         format!("\\U{:0>8}", digits.as_str())
     };
     let with_go_unicode = UNICODE_RE.replace_all(value, &to_go_unicode);
-    EcoString::from(with_go_unicode)
+    // Go's interpreted string literals can't span multiple lines, so any
+    // newline written directly in the Gleam source has to be escaped.
+    EcoString::from(with_go_unicode.replace('\n', r"\n"))
         .to_doc()
         .surround("\"", "\"")
-
-    // if value.contains('\n') {
-    //     EcoString::from(value.replace('\n', r"\n"))
-    //         .to_doc()
-    //         .surround("\"", "\"")
-    // } else {
-    //     value.to_doc().surround("\"", "\"")
-    // }
 }
 
 pub fn comma_separated_list<'a, Elements: IntoIterator<Item = Output<'a>>>(
@@ -2700,7 +2884,7 @@ fn construct_record<'a>(
         .find_map(|def| match def {
             Definition::Import(import) => import.unqualified_values.iter().find_map(|v| {
                 if v.used_name() == &name {
-                    Some((import.used_name(), v.name.clone()))
+                    Some((Some(import.module.clone()), v.name.clone()))
                 } else {
                     None
                 }
@@ -2786,15 +2970,12 @@ impl BinOp {
             | BinOp::GtEqFloat
             | BinOp::GtFloat
             | BinOp::AddInt
-            | BinOp::AddFloat
             | BinOp::SubInt
-            | BinOp::SubFloat
-            | BinOp::MultFloat
             | BinOp::DivInt
             | BinOp::DivFloat
             | BinOp::RemainderInt
             | BinOp::Concatenate => true,
-            BinOp::MultInt => false,
+            BinOp::AddFloat | BinOp::SubFloat | BinOp::MultFloat | BinOp::MultInt => false,
         }
     }
 }
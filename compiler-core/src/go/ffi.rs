@@ -0,0 +1,483 @@
+//! A lightweight scanner for the function declarations of Go FFI files, used
+//! to check `@external(go, ...)` functions against the Go code they refer to
+//! before `go build` gets to see the generated code.
+//!
+//! This is not a Go parser! It only understands enough of Go's syntax to find
+//! the top level functions of a file along with their type parameters and
+//! parameters, so anything it does not understand is treated as compatible.
+
+use std::{collections::HashMap, sync::Arc};
+
+use camino::Utf8PathBuf;
+use ecow::{eco_format, EcoString};
+
+use crate::{
+    ast::{Definition, SrcSpan, TypedModule},
+    type_::{self, is_prelude_module, Type},
+};
+
+/// A top level function declared in a Go file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GoFunction {
+    pub name: EcoString,
+    pub type_parameters: Vec<EcoString>,
+    /// The type of each parameter, with a variadic parameter's `...` removed.
+    pub parameters: Vec<EcoString>,
+    pub variadic: bool,
+}
+
+impl GoFunction {
+    fn accepts_arity(&self, arity: usize) -> bool {
+        if self.variadic {
+            arity + 1 >= self.parameters.len()
+        } else {
+            arity == self.parameters.len()
+        }
+    }
+}
+
+/// The functions declared by the Go FFI files of a package, grouped by the
+/// directory (relative to `src` or `test`) that forms their Go package.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GoDeclarations {
+    packages: HashMap<Utf8PathBuf, HashMap<EcoString, GoFunction>>,
+}
+
+impl GoDeclarations {
+    /// Registers the functions declared in a Go file found at `path`, relative
+    /// to the package's `src` or `test` directory.
+    pub fn register_file(&mut self, path: &Utf8PathBuf, src: &str) {
+        let directory = path.parent().map(Utf8PathBuf::from).unwrap_or_default();
+        let functions = self.packages.entry(directory).or_default();
+        for function in scan_functions(src) {
+            let _ = functions.insert(function.name.clone(), function);
+        }
+    }
+
+    /// Checks the Go externals of a module, returning an error for each one
+    /// that refers to a function of a Go package of this Gleam package that
+    /// is missing or obviously can't be called with the Gleam arguments.
+    ///
+    /// `package_import_prefix` is the Go import path that the directories of
+    /// this package are found under, e.g. `example.com/todo/my_package/`.
+    /// Externals referring to any other Go package are not checked.
+    ///
+    pub fn check_module(
+        &self,
+        module: &TypedModule,
+        package_import_prefix: &str,
+    ) -> Vec<type_::Error> {
+        let mut errors = vec![];
+        for definition in &module.definitions {
+            let Definition::Function(function) = definition else {
+                continue;
+            };
+            let Some((go_package, go_function, location)) = &function.external_go else {
+                continue;
+            };
+            let Some(directory) =
+                self.local_directory(&module.name, go_package, package_import_prefix)
+            else {
+                continue;
+            };
+            let parameters = function
+                .arguments
+                .iter()
+                .map(|argument| argument.type_.clone())
+                .collect::<Vec<_>>();
+            if let Some(error) =
+                self.check_function(directory, go_package, go_function, &parameters, *location)
+            {
+                errors.push(error);
+            }
+        }
+        errors
+    }
+
+    /// The directory of the package's Go files that an external's Go package
+    /// refers to, if it refers to one at all. The empty package is the Go
+    /// package that the Gleam module itself is compiled into.
+    fn local_directory(
+        &self,
+        module_name: &EcoString,
+        go_package: &EcoString,
+        package_import_prefix: &str,
+    ) -> Option<Utf8PathBuf> {
+        if go_package.is_empty() {
+            return Some(Utf8PathBuf::from(module_name.as_str()));
+        }
+        // The package may be given an alias: `alias path/to/package`
+        let path = match go_package.split_once(' ') {
+            Some((_alias, path)) => path,
+            None => go_package.as_str(),
+        };
+        path.strip_prefix(package_import_prefix)
+            .map(Utf8PathBuf::from)
+    }
+
+    fn check_function(
+        &self,
+        directory: Utf8PathBuf,
+        go_package: &EcoString,
+        go_function: &EcoString,
+        parameters: &[Arc<Type>],
+        location: SrcSpan,
+    ) -> Option<type_::Error> {
+        let function = self
+            .packages
+            .get(&directory)
+            .and_then(|functions| functions.get(go_function));
+        let Some(function) = function else {
+            return Some(type_::Error::UnknownGoExternalFunction {
+                location,
+                package: if go_package.is_empty() {
+                    eco_format!("{directory}")
+                } else {
+                    go_package.clone()
+                },
+                function: go_function.clone(),
+            });
+        };
+
+        if !function.accepts_arity(parameters.len()) {
+            return Some(type_::Error::IncorrectGoExternalArity {
+                location,
+                function: go_function.clone(),
+                expected: function.parameters.len(),
+                given: parameters.len(),
+                variadic: function.variadic,
+            });
+        }
+
+        parameters
+            .iter()
+            .zip(function.parameters.iter())
+            .enumerate()
+            .find(|(_, (gleam_type, go_type))| {
+                is_incompatible(gleam_type, go_type, &function.type_parameters)
+            })
+            .map(
+                |(index, (gleam_type, go_type))| type_::Error::IncompatibleGoExternalParameter {
+                    location,
+                    function: go_function.clone(),
+                    position: index + 1,
+                    gleam_type: gleam_type.clone(),
+                    go_type: go_type.clone(),
+                },
+            )
+    }
+}
+
+/// Whether a Gleam value of the given type obviously can't be passed to a Go
+/// parameter of the given type. Only types that are built into Gleam are
+/// checked, as the Go representation of any other type is up to the FFI.
+fn is_incompatible(gleam_type: &Type, go_type: &str, type_parameters: &[EcoString]) -> bool {
+    let Some(expected) = prelude_go_type(gleam_type) else {
+        return false;
+    };
+
+    if go_type == "any"
+        || go_type.starts_with("interface")
+        || type_parameters.iter().any(|name| name == go_type)
+    {
+        return false;
+    }
+
+    match go_type_name(go_type) {
+        // Go's own types are never Gleam's types
+        GoTypeName::Builtin => true,
+        GoTypeName::Prelude(name) => name != expected,
+        GoTypeName::Other => false,
+    }
+}
+
+/// The name of the type in the Go prelude that represents a type built into
+/// Gleam.
+fn prelude_go_type(type_: &Type) -> Option<EcoString> {
+    match type_ {
+        Type::Named { module, name, .. } if is_prelude_module(module) => match name.as_str() {
+            "Int" | "Float" | "String" | "Bool" | "Nil" | "BitArray" | "List" | "Result"
+            | "UtfCodepoint" => Some(eco_format!("{name}_t")),
+            _ => None,
+        },
+        Type::Named { .. } => None,
+        Type::Tuple { elems } => Some(eco_format!("Tuple{}_t", elems.len())),
+        Type::Fn { args, .. } => Some(eco_format!("Func{}_t", args.len())),
+        Type::Var { type_ } => match &*type_.borrow() {
+            type_::TypeVar::Link { type_ } => prelude_go_type(type_),
+            type_::TypeVar::Unbound { .. } | type_::TypeVar::Generic { .. } => None,
+        },
+    }
+}
+
+enum GoTypeName<'a> {
+    /// A type built into Go, such as `int64` or `[]string`.
+    Builtin,
+    /// A type defined by the Go prelude, such as `gleam.Int_t`.
+    Prelude(&'a str),
+    Other,
+}
+
+fn go_type_name(go_type: &str) -> GoTypeName<'_> {
+    if go_type.starts_with("[]")
+        || go_type.starts_with("map[")
+        || go_type.starts_with("chan ")
+        || go_type.starts_with('*')
+    {
+        return GoTypeName::Builtin;
+    }
+
+    // Remove any type arguments and the package qualifier.
+    let name = go_type.split('[').next().unwrap_or(go_type);
+    let (qualified, name) = match name.rsplit_once('.') {
+        Some((_package, name)) => (true, name),
+        None => (false, name),
+    };
+
+    match name {
+        "int" | "int8" | "int16" | "int32" | "int64" | "uint" | "uint8" | "uint16" | "uint32"
+        | "uint64" | "uintptr" | "float32" | "float64" | "complex64" | "complex128" | "string"
+        | "bool" | "byte" | "rune" | "error"
+            if !qualified =>
+        {
+            GoTypeName::Builtin
+        }
+        "Int_t" | "Float_t" | "String_t" | "Bool_t" | "Nil_t" | "BitArray_t" | "List_t"
+        | "Result_t" | "UtfCodepoint_t" => GoTypeName::Prelude(name),
+        _ if is_numbered_prelude_type(name, "Tuple") || is_numbered_prelude_type(name, "Func") => {
+            GoTypeName::Prelude(name)
+        }
+        _ => GoTypeName::Other,
+    }
+}
+
+fn is_numbered_prelude_type(name: &str, prefix: &str) -> bool {
+    name.strip_prefix(prefix)
+        .and_then(|rest| rest.strip_suffix("_t"))
+        .is_some_and(|number| !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// Finds the top level functions declared in a Go source file. Methods are
+/// skipped as they can't be referred to by an external.
+pub fn scan_functions(src: &str) -> Vec<GoFunction> {
+    let src = strip_comments_and_literals(src);
+    let mut functions = vec![];
+    let mut depth = 0usize;
+    let mut chars = src.char_indices().peekable();
+
+    while let Some((index, char)) = chars.next() {
+        match char {
+            '{' | '(' | '[' => depth += 1,
+            '}' | ')' | ']' => depth = depth.saturating_sub(1),
+            'f' if depth == 0
+                && src[index..].starts_with("func")
+                && is_word_boundary(&src, index, 4) =>
+            {
+                if let Some(function) = scan_function(&src[index + 4..]) {
+                    functions.push(function);
+                }
+                // Skip the keyword so its letters are not scanned again.
+                let _ = chars.nth(2);
+            }
+            _ => (),
+        }
+    }
+    functions
+}
+
+fn is_word_boundary(src: &str, start: usize, length: usize) -> bool {
+    let is_identifier = |c: char| c.is_alphanumeric() || c == '_';
+    let before = src[..start].chars().next_back();
+    let after = src[start + length..].chars().next();
+    !before.is_some_and(is_identifier) && !after.is_some_and(is_identifier)
+}
+
+/// Scans the signature that follows a `func` keyword.
+fn scan_function(src: &str) -> Option<GoFunction> {
+    let src = src.trim_start();
+    // A method has a receiver before its name.
+    if src.starts_with('(') {
+        return None;
+    }
+
+    let name_end = src
+        .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .unwrap_or(src.len());
+    let name = &src[..name_end];
+    if name.is_empty() {
+        return None;
+    }
+    let mut rest = src[name_end..].trim_start();
+
+    let mut type_parameters = vec![];
+    if rest.starts_with('[') {
+        let (inside, after) = balanced(rest, '[', ']')?;
+        type_parameters = parameter_list(inside)
+            .into_iter()
+            .filter_map(|(name, _)| name)
+            .collect();
+        rest = after.trim_start();
+    }
+
+    if !rest.starts_with('(') {
+        return None;
+    }
+    let (inside, _) = balanced(rest, '(', ')')?;
+    let mut variadic = false;
+    let parameters = parameter_list(inside)
+        .into_iter()
+        .map(|(_, type_)| match type_.strip_prefix("...") {
+            Some(type_) => {
+                variadic = true;
+                EcoString::from(type_.trim())
+            }
+            None => type_,
+        })
+        .collect();
+
+    Some(GoFunction {
+        name: name.into(),
+        type_parameters,
+        parameters,
+        variadic,
+    })
+}
+
+/// Splits a string starting with `open` into the text inside the brackets
+/// and the text after the matching `close`.
+fn balanced(src: &str, open: char, close: char) -> Option<(&str, &str)> {
+    let mut depth = 0usize;
+    for (index, char) in src.char_indices() {
+        if char == open {
+            depth += 1;
+        } else if char == close {
+            depth -= 1;
+            if depth == 0 {
+                return Some((&src[open.len_utf8()..index], &src[index + 1..]));
+            }
+        }
+    }
+    None
+}
+
+/// Parses a Go parameter (or type parameter) list into pairs of names and
+/// types. In Go either every parameter is named or none is, and consecutive
+/// parameters may share a type: `a, b int64, c string`.
+fn parameter_list(src: &str) -> Vec<(Option<EcoString>, EcoString)> {
+    let items = split_top_level(src);
+    let named = items.iter().any(|item| split_name(item).is_some());
+    if !named {
+        return items
+            .into_iter()
+            .map(|type_| (None, normalise_whitespace(type_)))
+            .collect();
+    }
+
+    let mut parameters = vec![];
+    let mut pending_names = vec![];
+    for item in items {
+        match split_name(item) {
+            None => pending_names.push(EcoString::from(item)),
+            Some((name, type_)) => {
+                let type_ = normalise_whitespace(type_);
+                for name in pending_names.drain(..) {
+                    parameters.push((Some(name), type_.clone()));
+                }
+                parameters.push((Some(name.into()), type_));
+            }
+        }
+    }
+    parameters
+}
+
+/// Splits `name Type` into its name and type, returning `None` if the item
+/// is a type on its own.
+fn split_name(item: &str) -> Option<(&str, &str)> {
+    let (name, type_) = item.split_once(char::is_whitespace)?;
+    let is_identifier = !name.is_empty()
+        && name.chars().all(|c| c.is_alphanumeric() || c == '_')
+        && !matches!(name, "chan" | "func" | "map" | "interface" | "struct");
+    if is_identifier {
+        Some((name, type_.trim()))
+    } else {
+        None
+    }
+}
+
+fn split_top_level(src: &str) -> Vec<&str> {
+    let mut items = vec![];
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (index, char) in src.char_indices() {
+        match char {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                items.push(src[start..index].trim());
+                start = index + 1;
+            }
+            _ => (),
+        }
+    }
+    items.push(src[start..].trim());
+    items.retain(|item| !item.is_empty());
+    items
+}
+
+fn normalise_whitespace(src: &str) -> EcoString {
+    src.split_whitespace().collect::<Vec<_>>().join(" ").into()
+}
+
+/// Replaces comments with whitespace and empties string and rune literals, so
+/// that brackets and keywords inside them are not mistaken for code.
+fn strip_comments_and_literals(src: &str) -> String {
+    let mut out = String::with_capacity(src.len());
+    let mut chars = src.chars().peekable();
+    while let Some(char) = chars.next() {
+        match char {
+            '/' if chars.peek() == Some(&'/') => {
+                for char in chars.by_ref() {
+                    if char == '\n' {
+                        out.push('\n');
+                        break;
+                    }
+                }
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                let _ = chars.next();
+                let mut previous = ' ';
+                for char in chars.by_ref() {
+                    if previous == '*' && char == '/' {
+                        break;
+                    }
+                    previous = char;
+                }
+                out.push(' ');
+            }
+            '"' | '\'' => {
+                let mut escaped = false;
+                for inner in chars.by_ref() {
+                    if escaped {
+                        escaped = false;
+                    } else if inner == '\\' {
+                        escaped = true;
+                    } else if inner == char || inner == '\n' {
+                        break;
+                    }
+                }
+                out.push(char);
+                out.push(char);
+            }
+            '`' => {
+                for char in chars.by_ref() {
+                    if char == '`' {
+                        break;
+                    }
+                }
+                out.push_str("``");
+            }
+            _ => out.push(char),
+        }
+    }
+    out
+}
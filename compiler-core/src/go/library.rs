@@ -0,0 +1,437 @@
+//! The Go facade package generated by `gleam build --target go --library`.
+//!
+//! The facade lets a Go program call into a Gleam package without knowing how
+//! Gleam values are represented in Go. It re-exports the public functions of
+//! the chosen modules with Go types in place of Gleam ones: `Int` is `int64`,
+//! `List(a)` is `[]a`, a `Result` is returned as a value and an `error`, and so
+//! on. Functions that take or return any other type are left out, and listed as
+//! such in the README generated alongside the facade.
+
+use std::{collections::HashSet, fmt::Write, sync::Arc};
+
+use ecow::{eco_format, EcoString};
+use heck::ToLowerCamelCase;
+use itertools::Itertools;
+
+use crate::{
+    ast::{Definition, TypedArg, TypedModule},
+    build::Target,
+    type_::{collapse_links, Type, PRELUDE_MODULE_NAME},
+};
+
+use super::{generic_ids, maybe_escape_identifier_string, to_go_name, to_go_package_name};
+
+/// The Go source of a facade package and the README describing its API.
+#[derive(Debug)]
+pub struct Library {
+    /// To be written to `<package>/gleam_library.go` in the Go build directory.
+    pub source: String,
+    /// To be written to `<package>/README.md` in the Go build directory.
+    pub readme: String,
+}
+
+/// Generates the facade package for the given modules of a Gleam package. It is
+/// named after the package and imported from `<go_module_path>/<package>`.
+pub fn library(package: &str, modules: &[&TypedModule], go_module_path: &str) -> Library {
+    let mut functions: Vec<Function> = vec![];
+    let mut omitted = vec![];
+    let mut names = HashSet::new();
+
+    for module in modules {
+        for definition in &module.definitions {
+            let Definition::Function(function) = definition else {
+                continue;
+            };
+            let Some((_, name)) = &function.name else {
+                continue;
+            };
+            if !function.publicity.is_public() {
+                continue;
+            }
+            let qualified_name = eco_format!("{}.{name}", module.name);
+            let omit = |reason: EcoString| (qualified_name.clone(), reason);
+
+            if !function.implementations.supports(Target::Go) {
+                omitted.push(omit("it can't run on Go".into()));
+                continue;
+            }
+            let mut type_variables = im::HashSet::new();
+            for argument in &function.arguments {
+                generic_ids(&argument.type_, &mut type_variables);
+            }
+            generic_ids(&function.return_type, &mut type_variables);
+            if !type_variables.is_empty() {
+                omitted.push(omit("it is generic".into()));
+                continue;
+            }
+
+            let Some(parameters) = parameters(&function.arguments) else {
+                omitted.push(omit("it takes an argument with no Go equivalent".into()));
+                continue;
+            };
+            let Some(returns) = Returns::of(&function.return_type) else {
+                omitted.push(omit("its return type has no Go equivalent".into()));
+                continue;
+            };
+
+            functions.push(Function {
+                go_name: to_go_name(name, true),
+                module_go_name: to_go_name(name, true),
+                qualified_name,
+                module: module.name.clone(),
+                package: module.type_info.package.clone(),
+                parameters,
+                returns,
+                documentation: function.documentation.as_ref().map(|(_, doc)| doc.clone()),
+            });
+        }
+    }
+
+    // Functions of different modules with the same name are all exported with
+    // their module's name in front of theirs, so `wibble.parse` and
+    // `wobble.parse` become `WibbleParse` and `WobbleParse`.
+    let clashing: HashSet<EcoString> = functions
+        .iter()
+        .map(|function| &function.go_name)
+        .duplicates()
+        .cloned()
+        .collect();
+    let functions = functions
+        .into_iter()
+        .filter_map(|mut function| {
+            if clashing.contains(&function.go_name) {
+                let name = function.qualified_name.as_str().replace(['/', '.'], "_");
+                function.go_name = to_go_name(&name, true);
+            }
+            if names.insert(function.go_name.clone()) {
+                Some(function)
+            } else {
+                omitted.push((
+                    function.qualified_name.clone(),
+                    eco_format!(
+                        "a function named `{}` is already exported",
+                        function.go_name
+                    ),
+                ));
+                None
+            }
+        })
+        .collect_vec();
+
+    // The error type is renamed if a function is exported with its name, such
+    // as `error`.
+    let mut error_type = EcoString::from("Error");
+    while names.contains(&error_type) {
+        error_type = eco_format!("Gleam{error_type}");
+    }
+
+    let package_name = maybe_escape_identifier_string(package);
+    Library {
+        source: source(&package_name, &error_type, &functions, go_module_path),
+        readme: readme(
+            package,
+            &package_name,
+            &error_type,
+            &functions,
+            &omitted,
+            go_module_path,
+        ),
+    }
+}
+
+/// The names and Go types of a function's parameters, or `None` if one of them
+/// has no Go equivalent. Parameters are named after their label if they have
+/// one, as that is the name callers know them by.
+fn parameters(arguments: &[TypedArg]) -> Option<Vec<(EcoString, GoType)>> {
+    let mut names = HashSet::new();
+    arguments
+        .iter()
+        .enumerate()
+        .map(|(index, argument)| {
+            let name = match argument
+                .names
+                .get_label()
+                .or(argument.names.get_variable_name())
+            {
+                Some(name) => maybe_escape_identifier_string(&name.to_lower_camel_case()),
+                None => eco_format!("arg{index}"),
+            };
+            let name = if names.insert(name.clone()) {
+                name
+            } else {
+                eco_format!("{name}{index}")
+            };
+            Some((name, GoType::of(&argument.type_)?))
+        })
+        .collect()
+}
+
+#[derive(Debug)]
+struct Function {
+    /// The name the function is exported with from the facade.
+    go_name: EcoString,
+    /// The name of the function in the Go package of its module.
+    module_go_name: EcoString,
+    qualified_name: EcoString,
+    module: EcoString,
+    package: EcoString,
+    parameters: Vec<(EcoString, GoType)>,
+    returns: Returns,
+    documentation: Option<EcoString>,
+}
+
+impl Function {
+    fn signature(&self) -> String {
+        let parameters = self
+            .parameters
+            .iter()
+            .map(|(name, type_)| format!("{name} {}", type_.go()))
+            .join(", ");
+        let returns = match &self.returns {
+            Returns::Nothing => String::new(),
+            Returns::Value(type_) => format!(" {}", type_.go()),
+            Returns::ValueOrError(type_) => format!(" ({}, error)", type_.go()),
+            Returns::Error => " error".into(),
+        };
+        format!("func {}({parameters}){returns}", self.go_name)
+    }
+
+    fn body(&self) -> String {
+        let arguments = self
+            .parameters
+            .iter()
+            .map(|(name, type_)| type_.to_gleam(name))
+            .join(", ");
+        let call = format!(
+            "{}.{}({arguments})",
+            to_go_package_name(&self.module),
+            self.module_go_name
+        );
+        match &self.returns {
+            Returns::Nothing => call,
+            Returns::Value(type_) => format!("return {}", type_.to_go(&call)),
+            Returns::ValueOrError(type_) => format!(
+                "return gleam_P.ResultToGo({call}, {})",
+                type_.to_go_function()
+            ),
+            Returns::Error => format!("return gleam_P.ResultErrorToGo({call})"),
+        }
+    }
+}
+
+/// The Gleam types that have a Go equivalent a facade can convert them to.
+#[derive(Debug)]
+enum GoType {
+    Int,
+    Float,
+    String,
+    Bool,
+    BitArray,
+    List(Box<GoType>),
+}
+
+impl GoType {
+    fn of(type_: &Arc<Type>) -> Option<Self> {
+        let type_ = collapse_links(type_.clone());
+        if type_.is_int() {
+            Some(Self::Int)
+        } else if type_.is_float() {
+            Some(Self::Float)
+        } else if type_.is_string() {
+            Some(Self::String)
+        } else if type_.is_bool() {
+            Some(Self::Bool)
+        } else if type_.is_bit_array() {
+            Some(Self::BitArray)
+        } else {
+            let element = type_.list_type()?;
+            Some(Self::List(Box::new(Self::of(&element)?)))
+        }
+    }
+
+    fn go(&self) -> EcoString {
+        match self {
+            Self::Int => "int64".into(),
+            Self::Float => "float64".into(),
+            Self::String => "string".into(),
+            Self::Bool => "bool".into(),
+            Self::BitArray => "[]byte".into(),
+            Self::List(element) => eco_format!("[]{}", element.go()),
+        }
+    }
+
+    fn gleam(&self) -> EcoString {
+        match self {
+            Self::Int => "gleam_P.Int_t".into(),
+            Self::Float => "gleam_P.Float_t".into(),
+            Self::String => "gleam_P.String_t".into(),
+            Self::Bool => "gleam_P.Bool_t".into(),
+            Self::BitArray => "gleam_P.BitArray_t".into(),
+            Self::List(element) => eco_format!("gleam_P.List_t[{}]", element.gleam()),
+        }
+    }
+
+    fn to_gleam(&self, value: &str) -> String {
+        match self {
+            Self::List(element) => format!(
+                "gleam_P.SliceToList({value}, func(v {}) {} {{ return {} }})",
+                element.go(),
+                element.gleam(),
+                element.to_gleam("v")
+            ),
+            _ => format!("{}({value})", self.gleam()),
+        }
+    }
+
+    fn to_go(&self, value: &str) -> String {
+        match self {
+            Self::List(element) => {
+                format!("gleam_P.ListToSlice({value}, {})", element.to_go_function())
+            }
+            _ => format!("{}({value})", self.go()),
+        }
+    }
+
+    fn to_go_function(&self) -> String {
+        format!(
+            "func(v {}) {} {{ return {} }}",
+            self.gleam(),
+            self.go(),
+            self.to_go("v")
+        )
+    }
+}
+
+/// What a facade function returns for the return type of a Gleam function.
+#[derive(Debug)]
+enum Returns {
+    /// For `Nil`.
+    Nothing,
+    Value(GoType),
+    /// For `Result(a, e)`, where the error holds the Gleam error value.
+    ValueOrError(GoType),
+    /// For `Result(Nil, e)`.
+    Error,
+}
+
+impl Returns {
+    fn of(type_: &Arc<Type>) -> Option<Self> {
+        let type_ = collapse_links(type_.clone());
+        if type_.is_nil() {
+            return Some(Self::Nothing);
+        }
+        match type_.result_types() {
+            Some((ok, _)) if ok.is_nil() => Some(Self::Error),
+            Some((ok, _)) => Some(Self::ValueOrError(GoType::of(&ok)?)),
+            None => Some(Self::Value(GoType::of(&type_)?)),
+        }
+    }
+}
+
+fn source(
+    package_name: &str,
+    error_type: &str,
+    functions: &[Function],
+    go_module_path: &str,
+) -> String {
+    let mut imports = vec![format!(
+        "\tgleam_P \"{go_module_path}/{PRELUDE_MODULE_NAME}\""
+    )];
+    imports.extend(
+        functions
+            .iter()
+            .map(|function| {
+                format!(
+                    "\t{} \"{go_module_path}/{}/{}\"",
+                    to_go_package_name(&function.module),
+                    function.package,
+                    function.module
+                )
+            })
+            .unique(),
+    );
+
+    let mut out = format!(
+        "// Code generated by `gleam build --target go --library`. DO NOT EDIT.
+
+package {package_name}
+
+import (
+{}
+)
+
+// {error_type} is the error returned when a Gleam function returns an `Error`, which
+// it holds as its `Value`.
+type {error_type} = gleam_P.ResultError
+",
+        imports.join("\n")
+    );
+
+    for function in functions {
+        out.push('\n');
+        if let Some(documentation) = &function.documentation {
+            for line in documentation.trim_end().lines() {
+                let line = line.strip_prefix(' ').unwrap_or(line);
+                let _ = writeln!(out, "//{}{line}", if line.is_empty() { "" } else { " " });
+            }
+            out.push_str("//\n");
+        }
+        let _ = writeln!(out, "// It calls `{}`.", function.qualified_name);
+        let _ = writeln!(out, "{} {{", function.signature());
+        let _ = writeln!(out, "\t{}", function.body());
+        out.push_str("}\n");
+    }
+    out
+}
+
+fn readme(
+    package: &str,
+    package_name: &str,
+    error_type: &str,
+    functions: &[Function],
+    omitted: &[(EcoString, EcoString)],
+    go_module_path: &str,
+) -> String {
+    let mut out = format!(
+        "# {package}
+
+A Go package for calling the Gleam package `{package}`, generated by
+`gleam build --target go --library`. Do not edit it, it is regenerated on every
+build.
+
+```go
+import {package_name} \"{go_module_path}/{package}\"
+```
+
+When a Gleam function returns an `Error` the Go function returns a
+`{package_name}.{error_type}`, holding the Gleam error value.
+
+## Functions
+"
+    );
+
+    for function in functions {
+        let _ = write!(
+            out,
+            "\n### {}\n\n```go\n{}\n```\n\nCalls `{}`.\n",
+            function.go_name,
+            function.signature(),
+            function.qualified_name
+        );
+        if let Some(documentation) = &function.documentation {
+            let documentation = documentation
+                .lines()
+                .map(|line| line.strip_prefix(' ').unwrap_or(line))
+                .join("\n");
+            let _ = writeln!(out, "\n{}", documentation.trim());
+        }
+    }
+
+    if !omitted.is_empty() {
+        out.push_str("\n## Not exported\n\n");
+        for (name, reason) in omitted {
+            let _ = writeln!(out, "- `{name}`: {reason}.");
+        }
+    }
+    out
+}
@@ -139,12 +139,6 @@ impl<'module_ctx, 'expression_gen, 'a> Generator<'module_ctx, 'expression_gen, '
         self.path.push(Index::SliceAfter(i));
     }
 
-    fn push_string_times(&mut self, s: EcoString, times: usize) {
-        for _ in 0..times {
-            self.push_string(s.clone());
-        }
-    }
-
     fn pop(&mut self) {
         let _ = self.path.pop();
     }
@@ -228,6 +222,20 @@ impl<'module_ctx, 'expression_gen, 'a> Generator<'module_ctx, 'expression_gen, '
 
     fn push_guard_check(&mut self, guard: &'a TypedClauseGuard) -> Result<(), Error> {
         let expression = self.guard(guard)?;
+        // On Erlang a guard that raises an error doesn't match, so a guard
+        // whose float arithmetic overflows is false rather than a panic.
+        let expression = if has_float_arithmetic(guard) {
+            docvec![
+                to_go_package_name(PRELUDE_MODULE_NAME),
+                ".CheckGuard(func() ",
+                to_go_package_name(PRELUDE_MODULE_NAME),
+                ".Bool_t { return ",
+                expression,
+                " })"
+            ]
+        } else {
+            expression
+        };
         self.checks.push(Check::Guard { expression });
         Ok(())
     }
@@ -238,6 +246,12 @@ impl<'module_ctx, 'expression_gen, 'a> Generator<'module_ctx, 'expression_gen, '
             | ClauseGuard::TupleIndex { .. }
             | ClauseGuard::Constant(_)
             | ClauseGuard::Not { .. }
+            | ClauseGuard::DivInt { .. }
+            | ClauseGuard::DivFloat { .. }
+            | ClauseGuard::AddFloat { .. }
+            | ClauseGuard::SubFloat { .. }
+            | ClauseGuard::MultFloat { .. }
+            | ClauseGuard::RemainderInt { .. }
             | ClauseGuard::FieldAccess { .. } => self.guard(guard),
 
             ClauseGuard::Equals { .. }
@@ -251,20 +265,30 @@ impl<'module_ctx, 'expression_gen, 'a> Generator<'module_ctx, 'expression_gen, '
             | ClauseGuard::LtFloat { .. }
             | ClauseGuard::LtEqFloat { .. }
             | ClauseGuard::AddInt { .. }
-            | ClauseGuard::AddFloat { .. }
             | ClauseGuard::SubInt { .. }
-            | ClauseGuard::SubFloat { .. }
             | ClauseGuard::MultInt { .. }
-            | ClauseGuard::MultFloat { .. }
-            | ClauseGuard::DivInt { .. }
-            | ClauseGuard::DivFloat { .. }
-            | ClauseGuard::RemainderInt { .. }
             | ClauseGuard::Or { .. }
             | ClauseGuard::And { .. }
             | ClauseGuard::ModuleSelect { .. } => Ok(docvec!["(", self.guard(guard)?, ")"]),
         }
     }
 
+    /// Float arithmetic in a guard is checked for overflow the same way as
+    /// in any other expression.
+    fn float_guard(
+        &mut self,
+        left: &'a TypedClauseGuard,
+        right: &'a TypedClauseGuard,
+        operator: &'a str,
+        location: SrcSpan,
+    ) -> Output<'a> {
+        let left = self.wrapped_guard(left)?;
+        let right = self.wrapped_guard(right)?;
+        Ok(self
+            .expression_generator
+            .check_float(docvec![left, operator, right], location))
+    }
+
     fn guard(&mut self, guard: &'a TypedClauseGuard) -> Output<'a> {
         Ok(match guard {
             ClauseGuard::Equals { left, right, .. } if is_go_scalar(left.type_()) => {
@@ -274,9 +298,9 @@ impl<'module_ctx, 'expression_gen, 'a> Generator<'module_ctx, 'expression_gen, '
                     to_go_package_name(PRELUDE_MODULE_NAME),
                     ".Bool_t(",
                     left,
-                    ".Equal(",
+                    " == ",
                     right,
-                    "))"
+                    ")"
                 ]
             }
 
@@ -286,11 +310,10 @@ impl<'module_ctx, 'expression_gen, 'a> Generator<'module_ctx, 'expression_gen, '
                 docvec![
                     to_go_package_name(PRELUDE_MODULE_NAME),
                     ".Bool_t(",
-                    "!",
                     left,
-                    ".Equal(",
+                    " != ",
                     right,
-                    "))"
+                    ")"
                 ]
             }
 
@@ -334,35 +357,82 @@ impl<'module_ctx, 'expression_gen, 'a> Generator<'module_ctx, 'expression_gen, '
                 docvec![left, " <= ", right]
             }
 
-            ClauseGuard::AddFloat { left, right, .. } | ClauseGuard::AddInt { left, right, .. } => {
+            ClauseGuard::AddInt { left, right, .. } => {
                 let left = self.wrapped_guard(left)?;
                 let right = self.wrapped_guard(right)?;
                 docvec![left, " + ", right]
             }
 
-            ClauseGuard::SubFloat { left, right, .. } | ClauseGuard::SubInt { left, right, .. } => {
+            ClauseGuard::SubInt { left, right, .. } => {
                 let left = self.wrapped_guard(left)?;
                 let right = self.wrapped_guard(right)?;
                 docvec![left, " - ", right]
             }
 
-            ClauseGuard::MultFloat { left, right, .. }
-            | ClauseGuard::MultInt { left, right, .. } => {
+            ClauseGuard::MultInt { left, right, .. } => {
                 let left = self.wrapped_guard(left)?;
                 let right = self.wrapped_guard(right)?;
                 docvec![left, " * ", right]
             }
 
-            ClauseGuard::DivFloat { left, right, .. } | ClauseGuard::DivInt { left, right, .. } => {
+            ClauseGuard::AddFloat {
+                left,
+                right,
+                location,
+                ..
+            } => self.float_guard(left, right, " + ", *location)?,
+
+            ClauseGuard::SubFloat {
+                left,
+                right,
+                location,
+                ..
+            } => self.float_guard(left, right, " - ", *location)?,
+
+            ClauseGuard::MultFloat {
+                left,
+                right,
+                location,
+                ..
+            } => self.float_guard(left, right, " * ", *location)?,
+
+            ClauseGuard::DivFloat {
+                left,
+                right,
+                location,
+                ..
+            } => {
+                let left = self.wrapped_guard(left)?;
+                let right = self.wrapped_guard(right)?;
+                self.expression_generator.tracker.float_division_used = true;
+                let division = docvec![
+                    to_go_package_name(PRELUDE_MODULE_NAME),
+                    ".DivideFloat",
+                    wrap_args([left, right])
+                ];
+                self.expression_generator.check_float(division, *location)
+            }
+
+            ClauseGuard::DivInt { left, right, .. } => {
                 let left = self.wrapped_guard(left)?;
                 let right = self.wrapped_guard(right)?;
-                docvec![left, " / ", right]
+                self.expression_generator.tracker.int_division_used = true;
+                docvec![
+                    to_go_package_name(PRELUDE_MODULE_NAME),
+                    ".DivideInt",
+                    wrap_args([left, right])
+                ]
             }
 
             ClauseGuard::RemainderInt { left, right, .. } => {
                 let left = self.wrapped_guard(left)?;
                 let right = self.wrapped_guard(right)?;
-                docvec![left, " % ", right]
+                self.expression_generator.tracker.int_remainder_used = true;
+                docvec![
+                    to_go_package_name(PRELUDE_MODULE_NAME),
+                    ".RemainderInt",
+                    wrap_args([left, right])
+                ]
             }
 
             ClauseGuard::Or { left, right, .. } => {
@@ -392,25 +462,28 @@ impl<'module_ctx, 'expression_gen, 'a> Generator<'module_ctx, 'expression_gen, '
             ClauseGuard::FieldAccess {
                 label, container, ..
             } => {
-                let public = is_type_public_and_transparent(
+                let (type_module, type_name) =
+                    container.type_().named_type_name().expect("named type");
+                let public =
+                    is_type_public_and_transparent(self.expression_generator.module, &type_name);
+                let single_constructor = is_type_single_constructor(
+                    self.expression_generator.dep_modules,
                     &self.expression_generator.module,
-                    &container.type_().named_type_name().expect("named type").1,
+                    &type_module,
+                    &type_name,
                 );
 
                 docvec![
                     self.guard(container)?,
                     ".",
-                    to_go_field_name(label, public),
-                    "_f()"
+                    to_go_common_field_name(label, public, single_constructor, true)
                 ]
             }
 
             ClauseGuard::ModuleSelect {
-                module_alias,
-                label,
-                ..
+                module_name, label, ..
             } => docvec![
-                to_go_package_name(module_alias),
+                to_go_package_name(module_name),
                 ".",
                 to_go_name(label, true)
             ],
@@ -488,19 +561,20 @@ impl<'module_ctx, 'expression_gen, 'a> Generator<'module_ctx, 'expression_gen, '
             }
 
             Pattern::List { elements, tail, .. } => {
-                self.push_list_length_check(subject.clone(), elements.len(), tail.is_some());
+                // The list is checked one cell at a time, only as deep as the
+                // pattern needs, so matching never walks the whole list.
                 for pattern in elements {
+                    self.push_list_empty_check(subject.clone(), false);
                     self.push_string("Head()".into());
                     self.traverse_pattern(subject, pattern)?;
                     self.pop();
                     self.push_string("Tail()".into());
                 }
-                self.pop_times(elements.len());
-                if let Some(pattern) = tail {
-                    self.push_string_times("Tail()".into(), elements.len());
-                    self.traverse_pattern(subject, pattern)?;
-                    self.pop_times(elements.len());
+                match tail {
+                    Some(pattern) => self.traverse_pattern(subject, pattern)?,
+                    None => self.push_list_empty_check(subject.clone(), true),
                 }
+                self.pop_times(elements.len());
                 Ok(())
             }
 
@@ -896,15 +970,9 @@ impl<'module_ctx, 'expression_gen, 'a> Generator<'module_ctx, 'expression_gen, '
         })
     }
 
-    fn push_list_length_check(
-        &mut self,
-        subject: Document<'a>,
-        expected_length: usize,
-        has_tail_spread: bool,
-    ) {
-        self.checks.push(Check::ListLength {
-            expected_length,
-            has_tail_spread,
+    fn push_list_empty_check(&mut self, subject: Document<'a>, expected_to_be_empty: bool) {
+        self.checks.push(Check::ListEmpty {
+            expected_to_be_empty,
             subject,
             path: self.path_document(),
         })
@@ -976,11 +1044,10 @@ pub enum Check<'a> {
         path: Document<'a>,
         to: Document<'a>,
     },
-    ListLength {
+    ListEmpty {
         subject: Document<'a>,
         path: Document<'a>,
-        expected_length: usize,
-        has_tail_spread: bool,
+        expected_to_be_empty: bool,
     },
     BitArrayLength {
         subject: Document<'a>,
@@ -1062,21 +1129,15 @@ impl<'a> Check<'a> {
                 ]
             }
 
-            Check::ListLength {
+            Check::ListEmpty {
                 subject,
                 path,
-                expected_length,
-                has_tail_spread,
+                expected_to_be_empty,
             } => {
-                let length_check = if has_tail_spread {
-                    eco_format!(".AtLeastLength({expected_length})").to_doc()
+                if expected_to_be_empty == match_desired {
+                    docvec![subject, path, ".IsEmpty()"]
                 } else {
-                    eco_format!(".HasLength({expected_length})").to_doc()
-                };
-                if match_desired {
-                    docvec![subject, path, length_check,]
-                } else {
-                    docvec!["!", subject, path, length_check,]
+                    docvec!["!", subject, path, ".IsEmpty()"]
                 }
             }
             Check::BitArrayLength {
@@ -1131,7 +1192,7 @@ impl<'a> Check<'a> {
         match self {
             Check::Variant { .. }
             | Check::Equal { .. }
-            | Check::ListLength { .. }
+            | Check::ListEmpty { .. }
             | Check::BitArrayLength { .. }
             | Check::StringPrefix { .. }
             | Check::Bool { .. } => false,
@@ -1140,6 +1201,44 @@ impl<'a> Check<'a> {
     }
 }
 
+/// Whether the guard does any float arithmetic, which could overflow.
+fn has_float_arithmetic(guard: &TypedClauseGuard) -> bool {
+    match guard {
+        ClauseGuard::AddFloat { .. }
+        | ClauseGuard::SubFloat { .. }
+        | ClauseGuard::MultFloat { .. }
+        | ClauseGuard::DivFloat { .. } => true,
+
+        ClauseGuard::Equals { left, right, .. }
+        | ClauseGuard::NotEquals { left, right, .. }
+        | ClauseGuard::GtInt { left, right, .. }
+        | ClauseGuard::GtEqInt { left, right, .. }
+        | ClauseGuard::LtInt { left, right, .. }
+        | ClauseGuard::LtEqInt { left, right, .. }
+        | ClauseGuard::GtFloat { left, right, .. }
+        | ClauseGuard::GtEqFloat { left, right, .. }
+        | ClauseGuard::LtFloat { left, right, .. }
+        | ClauseGuard::LtEqFloat { left, right, .. }
+        | ClauseGuard::AddInt { left, right, .. }
+        | ClauseGuard::SubInt { left, right, .. }
+        | ClauseGuard::MultInt { left, right, .. }
+        | ClauseGuard::DivInt { left, right, .. }
+        | ClauseGuard::RemainderInt { left, right, .. }
+        | ClauseGuard::Or { left, right, .. }
+        | ClauseGuard::And { left, right, .. } => {
+            has_float_arithmetic(left) || has_float_arithmetic(right)
+        }
+
+        ClauseGuard::Not { expression, .. } => has_float_arithmetic(expression),
+        ClauseGuard::TupleIndex { tuple, .. } => has_float_arithmetic(tuple),
+        ClauseGuard::FieldAccess { container, .. } => has_float_arithmetic(container),
+
+        ClauseGuard::Var { .. } | ClauseGuard::ModuleSelect { .. } | ClauseGuard::Constant(_) => {
+            false
+        }
+    }
+}
+
 pub(crate) fn assign_subject<'a>(
     expression_generator: &mut expression::Generator<'_>,
     subject: &'a TypedExpr,
@@ -0,0 +1,284 @@
+//! Compiling the entries of an interactive `gleam shell --target go` session.
+//!
+//! Each entry is compiled into a throwaway `gleam_shell` module whose `main`
+//! function replays the imports and bindings of the earlier entries before
+//! evaluating the new one, along with a Go `main` package that prints the
+//! value `main` returns.
+//!
+//! Each entry runs as a new Go program, so nothing is kept between entries
+//! but their source. Replaying a binding runs its expression again, along
+//! with any side effects it has, such as printing or reading a file.
+
+use std::collections::HashSet;
+
+use camino::Utf8PathBuf;
+use ecow::{eco_format, EcoString};
+use itertools::Itertools;
+
+use crate::{
+    analyse::{ModuleAnalyzerConstructor, TargetSupport},
+    ast::{Definition, TypedModule},
+    build::{Origin, Outcome, Target},
+    config::PackageConfig,
+    line_numbers::LineNumbers,
+    parse::error::ParseErrorType,
+    type_::{self, ModuleInterface, Type, TypeVar, PRELUDE_MODULE_NAME},
+    uid::UniqueIdGenerator,
+    warning::{TypeWarningEmitter, WarningEmitter},
+    Error,
+};
+
+/// The name of the module each entry is compiled into. It is also the name of
+/// the directory of the Go build directory the entry's program is written to.
+pub const MODULE_NAME: &str = "gleam_shell";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntryKind {
+    /// `import wibble/wobble`, which is checked but has nothing to run.
+    Import,
+    /// `let x = ...`, which is replayed before every later entry.
+    Binding,
+    Expression,
+}
+
+impl EntryKind {
+    pub fn of(entry: &str) -> Self {
+        let entry = entry.trim_start();
+        if entry.starts_with("import ") {
+            EntryKind::Import
+        } else if entry.starts_with("let ") {
+            EntryKind::Binding
+        } else {
+            EntryKind::Expression
+        }
+    }
+}
+
+/// The Go source files of a compiled entry.
+#[derive(Debug)]
+pub struct CompiledEntry {
+    pub kind: EntryKind,
+    /// The generated `gleam_shell` module, to be written to
+    /// `gleam_shell/gleam_shell/gleam_generated.go`.
+    pub module: String,
+    /// The `main` package that prints the entry's value, to be written to
+    /// `gleam_shell/main.go`.
+    pub main: String,
+}
+
+/// The imports and bindings entered so far in a shell session.
+#[derive(Debug)]
+pub struct Session {
+    ids: UniqueIdGenerator,
+    imports: Vec<EcoString>,
+    bindings: Vec<EcoString>,
+}
+
+impl Session {
+    /// Starts a session whose entries are compiled with the id generator
+    /// that the importable modules were compiled with.
+    pub fn new(ids: UniqueIdGenerator) -> Self {
+        Self {
+            ids,
+            imports: vec![],
+            bindings: vec![],
+        }
+    }
+
+    /// Returns `true` if the entry is missing its end, such as a closing
+    /// bracket, and more lines should be read before compiling it.
+    pub fn is_incomplete(entry: &str) -> bool {
+        match EntryKind::of(entry) {
+            EntryKind::Import => false,
+            EntryKind::Binding | EntryKind::Expression => {
+                matches!(
+                    crate::parse::parse_statement_sequence(entry),
+                    Err(error) if error.error == ParseErrorType::UnexpectedEof
+                )
+            }
+        }
+    }
+
+    /// Type checks the entry against the given modules, replaying the earlier
+    /// entries, and generates the Go program that evaluates it.
+    pub fn compile(
+        &self,
+        entry: &str,
+        package_config: &PackageConfig,
+        importable_modules: &im::HashMap<EcoString, ModuleInterface>,
+        go_module_path: &str,
+    ) -> Result<CompiledEntry, Error> {
+        let kind = EntryKind::of(entry);
+        let code: EcoString = self.source(entry, kind).into();
+        let path = Utf8PathBuf::from(format!("{MODULE_NAME}.gleam"));
+
+        let mut parsed = crate::parse::parse_module(path.clone(), &code, &WarningEmitter::null())
+            .map_err(|error| Error::Parse {
+                path: path.clone(),
+                src: code.clone(),
+                error,
+            })?
+            .module;
+        parsed.name = MODULE_NAME.into();
+
+        let mut importable_modules = importable_modules.clone();
+        if !importable_modules.contains_key(PRELUDE_MODULE_NAME) {
+            let _ = importable_modules
+                .insert(PRELUDE_MODULE_NAME.into(), type_::build_prelude(&self.ids));
+        }
+
+        let module = match (ModuleAnalyzerConstructor {
+            target: Target::Go,
+            ids: &self.ids,
+            origin: Origin::Src,
+            importable_modules: &importable_modules,
+            warnings: &TypeWarningEmitter::null(),
+            direct_dependencies: &package_config.all_direct_dependencies().unwrap_or_default(),
+            target_support: TargetSupport::Enforced,
+            package_config,
+        })
+        .infer_module(parsed, LineNumbers::new(&code), path.clone())
+        {
+            Outcome::Ok(module) => module,
+            Outcome::PartialFailure(module, errors) => {
+                return Err(Error::Type {
+                    names: module.names.clone(),
+                    path,
+                    src: code,
+                    errors,
+                })
+            }
+            Outcome::TotalFailure(errors) => {
+                return Err(Error::Type {
+                    names: Default::default(),
+                    path,
+                    src: code,
+                    errors,
+                })
+            }
+        };
+
+        let line_numbers = LineNumbers::new(&code);
+        let generated = super::module(
+            &importable_modules,
+            &module,
+            &line_numbers,
+            &path,
+            &code,
+            super::ModuleOptions {
+                target_support: TargetSupport::Enforced,
+                go_module_path,
+                package_name: None,
+            },
+        )?;
+
+        Ok(CompiledEntry {
+            kind,
+            module: generated,
+            main: main_package(go_module_path, main_type_parameters(&module)),
+        })
+    }
+
+    /// Remembers an entry that was compiled and run successfully so that it is
+    /// replayed before the entries that follow it.
+    pub fn record(&mut self, entry: &str) {
+        match EntryKind::of(entry) {
+            EntryKind::Import => self.imports.push(entry.trim().into()),
+            EntryKind::Binding => self.bindings.push(entry.trim().into()),
+            EntryKind::Expression => (),
+        }
+    }
+
+    fn source(&self, entry: &str, kind: EntryKind) -> String {
+        let imports = self
+            .imports
+            .iter()
+            .map(EcoString::as_str)
+            .chain(match kind {
+                EntryKind::Import => Some(entry.trim()),
+                EntryKind::Binding | EntryKind::Expression => None,
+            })
+            .join("\n");
+        let body = self
+            .bindings
+            .iter()
+            .map(EcoString::as_str)
+            .chain(match kind {
+                EntryKind::Import => Some("Nil"),
+                EntryKind::Binding | EntryKind::Expression => Some(entry.trim()),
+            })
+            .join("\n");
+        format!("{imports}\n\npub fn main() {{\n{body}\n}}\n")
+    }
+}
+
+/// The number of type parameters of the generated `Main` function, one for
+/// each type variable in the type of the value it returns.
+fn main_type_parameters(module: &TypedModule) -> usize {
+    let Some(main) = module
+        .definitions
+        .iter()
+        .find_map(|definition| match definition {
+            Definition::Function(function) => Some(function),
+            _ => None,
+        })
+    else {
+        return 0;
+    };
+    let mut ids = HashSet::new();
+    collect_type_variables(&main.return_type, &mut ids);
+    ids.len()
+}
+
+fn collect_type_variables(type_: &Type, ids: &mut HashSet<u64>) {
+    match type_ {
+        Type::Named { args, .. } => {
+            for argument in args {
+                collect_type_variables(argument, ids);
+            }
+        }
+        Type::Fn { args, retrn } => {
+            for argument in args {
+                collect_type_variables(argument, ids);
+            }
+            collect_type_variables(retrn, ids);
+        }
+        Type::Tuple { elems } => {
+            for element in elems {
+                collect_type_variables(element, ids);
+            }
+        }
+        Type::Var { type_ } => match &*type_.borrow() {
+            TypeVar::Link { type_ } => collect_type_variables(type_, ids),
+            TypeVar::Unbound { id } | TypeVar::Generic { id } => {
+                let _ = ids.insert(*id);
+            }
+        },
+    }
+}
+
+/// A Go `main` package printing the value returned by the entry's `Main`
+/// function. Any type parameters are instantiated with `Nil`, as a value of a
+/// type that could be anything can't contain anything of that type.
+fn main_package(go_module_path: &str, type_parameters: usize) -> String {
+    let type_arguments = if type_parameters == 0 {
+        EcoString::new()
+    } else {
+        eco_format!("[{}]", vec!["gleam_P.Nil_t"; type_parameters].join(", "))
+    };
+    format!(
+        r#"package main
+
+import (
+	"fmt"
+
+	gleam_P "{go_module_path}/gleam"
+	entry_P "{go_module_path}/{MODULE_NAME}/{MODULE_NAME}"
+)
+
+func main() {{
+	fmt.Println(gleam_P.Inspect(entry_P.Main{type_arguments}()))
+}}
+"#
+    )
+}
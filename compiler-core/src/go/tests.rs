@@ -18,8 +18,10 @@ mod case_clause_guards;
 mod consts;
 mod custom_types;
 mod externals;
+mod ffi;
 mod functions;
 mod generics;
+mod library;
 mod lists;
 mod modules;
 mod numbers;
@@ -28,6 +30,7 @@ mod prelude;
 mod records;
 mod recursion;
 mod results;
+mod shell;
 mod strings;
 mod todo;
 mod tuples;
@@ -190,8 +193,11 @@ pub fn compile_go(src: &str, deps: Vec<(&str, &str, &str)>) -> Result<String, cr
         &line_numbers,
         Utf8Path::new(""),
         &"".into(),
-        TargetSupport::Enforced,
-        "example.com/todo", // TODO
+        ModuleOptions {
+            target_support: TargetSupport::Enforced,
+            go_module_path: "example.com/todo", // TODO
+            package_name: None,
+        },
     )
 }
 
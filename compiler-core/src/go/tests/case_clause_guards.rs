@@ -117,6 +117,34 @@ fn not_eq_scalar() {
     );
 }
 
+#[test]
+fn eq_scalar_constants() {
+    assert_go!(
+        r#"pub fn main() {
+  case Nil {
+    _ if 1 + 1 == 2 -> 1
+    _ -> 0
+  }
+}
+"#,
+    );
+}
+
+#[test]
+fn division_and_remainder() {
+    assert_go!(
+        r#"pub fn main(x: Int, y: Float) {
+  case Nil {
+    _ if x / 0 == 0 -> 1
+    _ if x % 0 == 0 -> 2
+    _ if y /. 0.0 == 0.0 -> 3
+    _ -> 0
+  }
+}
+"#,
+    );
+}
+
 #[test]
 fn tuple_index() {
     assert_go!(
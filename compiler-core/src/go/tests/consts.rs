@@ -126,3 +126,41 @@ fn literal_nil_does_not_get_constant_annotation() {
 fn constructor_function_in_constant() {
     assert_go!("pub const a = Ok");
 }
+
+#[test]
+fn generic_constant() {
+    assert_go!(
+        r#"
+const empty = []
+
+pub fn main() -> #(List(Int), List(String)) {
+  #(empty, empty)
+}
+"#
+    );
+}
+
+#[test]
+fn generic_constant_in_constant() {
+    assert_go!(
+        r#"
+const empty = []
+
+const empties = #(empty, 1)
+"#
+    );
+}
+
+#[test]
+fn imported_generic_constant() {
+    assert_go!(
+        ("package", "other_module", "pub const empty = []"),
+        r#"
+import other_module
+
+pub fn main() -> List(Int) {
+  other_module.empty
+}
+"#
+    );
+}
@@ -1,3 +1,4 @@
+use crate::go::tests::CURRENT_PACKAGE;
 use crate::{assert_go, assert_go_error, assert_module_error};
 
 #[test]
@@ -65,6 +66,118 @@ pub type Thing(a, b)
     );
 }
 
+#[test]
+fn generic_external_type_without_type_arguments() {
+    assert_go!(
+        r#"
+@external(go, "example.com/concurrent", "wrap *Map[]")
+pub type Map(k, v)
+"#
+    );
+}
+
+#[test]
+fn generic_external_type_implementing_type() {
+    assert_go!(
+        r#"
+@external(go, "example.com/cache", "*Cache[v, k]")
+pub type Cache(k, v)
+"#
+    );
+}
+
+#[test]
+fn wrapped_external_type_in_external_function() {
+    assert_go!(
+        r#"
+@external(go, "sync", "wrap *Map[]")
+pub type Map(k, v)
+
+@external(go, "", "newMap")
+pub fn new() -> Map(k, v)
+
+@external(go, "", "mapLen")
+pub fn size(map: Map(k, v), extra: Int) -> Int
+
+@external(go, "", "mapStore")
+pub fn insert(map: Map(k, v), key: k, value: v) -> Map(k, v)
+"#
+    );
+}
+
+#[test]
+fn imported_wrapped_external_type_in_external_function() {
+    assert_go!(
+        (
+            CURRENT_PACKAGE,
+            "sync_map",
+            r#"
+@external(go, "sync", "wrap *Map[]")
+pub type Map(k, v)
+"#
+        ),
+        r#"
+import sync_map.{type Map}
+
+@external(go, "", "mapLen")
+pub fn size(map: Map(k, v)) -> Int
+
+@external(go, "", "mapClone")
+pub fn clone(map: Map(k, v)) -> Map(k, v)
+"#
+    );
+}
+
+#[test]
+fn wrapped_external_type_nested_in_external_function_argument() {
+    assert_go_error!(
+        r#"
+@external(go, "sync", "wrap *Map[]")
+pub type Map(k, v)
+
+@external(go, "", "mapsLen")
+pub fn size(maps: List(Map(k, v))) -> Int
+"#
+    );
+}
+
+#[test]
+fn wrapped_external_type_nested_in_external_function_return_type() {
+    assert_go_error!(
+        r#"
+@external(go, "", "map[string]v")
+pub type Counts(v)
+
+@external(go, "", "newCounts")
+pub fn new() -> #(Counts(Int), Int)
+"#
+    );
+}
+
+#[test]
+fn generic_external_type_with_reordered_type_arguments() {
+    assert_go!(
+        r#"
+@external(go, "example.com/cache", "wrap Cache[v, string, k]")
+pub type Cache(k, v)
+
+pub fn lookup(cache: Cache(Int, String)) -> Cache(Int, String) {
+  cache
+}
+"#
+    );
+}
+
+#[test]
+fn generic_external_type_in_current_go_package() {
+    assert_go!(
+        r#"
+@external(go, "", "map[string][]v")
+pub type Counts(v)
+"#
+    );
+}
+
 #[test]
 fn module_fn() {
     assert_go!(
@@ -0,0 +1,134 @@
+use crate::go::ffi::{scan_functions, GoFunction};
+
+fn function(name: &str, type_parameters: &[&str], parameters: &[&str]) -> GoFunction {
+    GoFunction {
+        name: name.into(),
+        type_parameters: type_parameters.iter().map(|&p| p.into()).collect(),
+        parameters: parameters.iter().map(|&p| p.into()).collect(),
+        variadic: false,
+    }
+}
+
+#[test]
+fn scan_simple_function() {
+    assert_eq!(
+        scan_functions(
+            r#"package ffi
+
+import gleam "example.com/todo/gleam"
+
+func Add(a gleam.Int_t, b gleam.Int_t) gleam.Int_t {
+	return a + b
+}
+"#
+        ),
+        vec![function("Add", &[], &["gleam.Int_t", "gleam.Int_t"])]
+    );
+}
+
+#[test]
+fn scan_grouped_parameters() {
+    assert_eq!(
+        scan_functions("func Add(a, b gleam.Int_t, c gleam.Float_t) {}"),
+        vec![function(
+            "Add",
+            &[],
+            &["gleam.Int_t", "gleam.Int_t", "gleam.Float_t"]
+        )]
+    );
+}
+
+#[test]
+fn scan_unnamed_parameters() {
+    assert_eq!(
+        scan_functions("func Add(gleam.Int_t, map[string]int) {}"),
+        vec![function("Add", &[], &["gleam.Int_t", "map[string]int"])]
+    );
+}
+
+#[test]
+fn scan_generic_function() {
+    assert_eq!(
+        scan_functions(
+            "func Inspect[K, V gleam.Type[V], E any](x gleam.List_t[K], f func(K) V) E {}"
+        ),
+        vec![function(
+            "Inspect",
+            &["K", "V", "E"],
+            &["gleam.List_t[K]", "func(K) V"]
+        )]
+    );
+}
+
+#[test]
+fn scan_variadic_function() {
+    assert_eq!(
+        scan_functions("func Join(separator string, parts ...string) string {}"),
+        vec![GoFunction {
+            name: "Join".into(),
+            type_parameters: vec![],
+            parameters: vec!["string".into(), "string".into()],
+            variadic: true,
+        }]
+    );
+}
+
+#[test]
+fn scan_skips_methods_and_nested_functions() {
+    assert_eq!(
+        scan_functions(
+            r#"
+func (t Thing) Method(x gleam.Int_t) {}
+
+var f = func(x int) {}
+
+func Outer() {
+	inner := func(y int) {}
+	inner(1)
+}
+"#
+        ),
+        vec![function("Outer", &[], &[])]
+    );
+}
+
+#[test]
+fn scan_skips_comments_and_strings() {
+    assert_eq!(
+        scan_functions(
+            r#"
+// func Commented(x int) {}
+/* func AlsoCommented(
+   x int) {} */
+func Real() string {
+	return "func NotAFunction() { ( ["
+}
+var s = `func Raw() {`
+func AfterRaw(r rune) { _ = '{' }
+"#
+        ),
+        vec![
+            function("Real", &[], &[]),
+            function("AfterRaw", &[], &["rune"])
+        ]
+    );
+}
+
+#[test]
+fn scan_multiline_signature() {
+    assert_eq!(
+        scan_functions(
+            "func Long(
+	first gleam.String_t,
+	second gleam.List_t[
+		gleam.Int_t,
+	],
+) gleam.Nil_t {}"
+        ),
+        vec![function(
+            "Long",
+            &[],
+            &["gleam.String_t", "gleam.List_t[ gleam.Int_t, ]"]
+        )]
+    );
+}
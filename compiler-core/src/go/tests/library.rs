@@ -0,0 +1,107 @@
+use crate::go::library::library;
+
+fn compile_library(src: &str) -> String {
+    let (_, module) = super::compile(src, vec![]);
+    let library = library("thepackage", &[&module], "example.com/todo");
+    format!(
+        "----- SOURCE CODE\n{src}\n----- GO\n{}\n----- README\n{}",
+        library.source, library.readme
+    )
+}
+
+#[test]
+fn native_types() {
+    insta::assert_snapshot!(compile_library(
+        r#"
+/// Adds two numbers.
+pub fn add(a: Int, to b: Int) -> Int { a + b }
+
+pub fn scale(x: Float) -> Float { x *. 2.0 }
+
+pub fn greet(name name: String, loud loud: Bool) -> String {
+  case loud {
+    True -> "HELLO " <> name
+    False -> "hello " <> name
+  }
+}
+
+pub fn bytes(b: BitArray) -> BitArray { b }
+
+pub fn log(message: String) -> Nil { Nil }
+"#
+    ));
+}
+
+#[test]
+fn lists() {
+    insta::assert_snapshot!(compile_library(
+        r#"
+pub fn pairs(xs: List(List(Int))) -> List(String) { [] }
+"#
+    ));
+}
+
+#[test]
+fn results() {
+    insta::assert_snapshot!(compile_library(
+        r#"
+pub type ParseError { Empty }
+
+pub fn parse(input: String) -> Result(Int, ParseError) { Error(Empty) }
+
+pub fn check(input: String) -> Result(Nil, String) { Ok(Nil) }
+"#
+    ));
+}
+
+#[test]
+fn unsupported_functions_are_listed() {
+    insta::assert_snapshot!(compile_library(
+        r#"
+pub type Cat { Cat(name: String) }
+
+pub fn identity(x: a) -> a { x }
+
+pub fn new(name: String) -> Cat { Cat(name) }
+
+pub fn name(cat: Cat) -> String { cat.name }
+
+@external(erlang, "wibble", "wobble")
+pub fn erlang_only() -> Int
+
+fn private() -> Int { 1 }
+
+pub fn supported(x: Int) -> Int { x + private() }
+"#
+    ));
+}
+
+#[test]
+fn error_type_is_renamed_if_a_function_takes_its_name() {
+    insta::assert_snapshot!(compile_library(
+        r#"
+pub fn error(message: String) -> Result(Nil, String) { Error(message) }
+"#
+    ));
+}
+
+#[test]
+fn clashing_names_are_qualified_with_their_module() {
+    let (_, mut wibble) = super::compile(
+        r#"
+pub fn parse(input: String) -> Int { 1 }
+
+pub fn only_in_wibble() -> Int { 1 }
+"#,
+        vec![],
+    );
+    wibble.name = "wibble".into();
+    let (_, mut wobble) = super::compile("pub fn parse(input: String) -> Int { 2 }", vec![]);
+    wobble.name = "wibble/wobble".into();
+
+    let library = library("thepackage", &[&wibble, &wobble], "example.com/todo");
+    insta::assert_snapshot!(format!(
+        "----- GO\n{}\n----- README\n{}",
+        library.source, library.readme
+    ));
+}
@@ -1,3 +1,5 @@
+use camino::Utf8Path;
+
 use crate::assert_go;
 
 #[test]
@@ -101,3 +103,33 @@ fn go(func) {
 "#,
     );
 }
+
+/// The Gleam source of the function the Go prelude tests and the list pattern
+/// benchmark in `test/go_prelude` run.
+const SUM_PAIRS: &str = r#"
+pub fn sum_pairs(xs, acc) {
+  case xs {
+    [a, b, ..rest] -> sum_pairs(rest, acc + a + b)
+    [a] -> acc + a
+    [] -> acc
+  }
+}
+"#;
+
+// The Go tests in `test/go_prelude` run the code generated for `SUM_PAIRS`,
+// so it's kept in a file there that must match the compiler's output.
+// Run with `UPDATE_GO_FIXTURES=1` to regenerate it.
+#[test]
+fn sum_pairs_go_fixture_is_up_to_date() {
+    let compiled = crate::go::tests::compile_go(SUM_PAIRS, vec![]).expect("compilation failed");
+    let fixture =
+        Utf8Path::new(env!("CARGO_MANIFEST_DIR")).join("../test/go_prelude/sum_pairs/sum_pairs.go");
+    if std::env::var("UPDATE_GO_FIXTURES").is_ok() {
+        std::fs::write(&fixture, &compiled).expect("writing fixture");
+    }
+    let expected = std::fs::read_to_string(&fixture).expect("reading fixture");
+    assert_eq!(
+        expected, compiled,
+        "{fixture} is out of date, run the tests with UPDATE_GO_FIXTURES=1 to regenerate it"
+    );
+}
@@ -1,3 +1,7 @@
+use std::collections::HashMap;
+
+use ecow::EcoString;
+
 use crate::go::tests::CURRENT_PACKAGE;
 use crate::{assert_go, assert_go_with_multiple_imports};
 
@@ -209,3 +213,45 @@ pub const variable = rocket_ship.func
 "#,
     );
 }
+
+#[test]
+fn types_from_modules_with_the_same_last_segment() {
+    assert_go_with_multiple_imports!(
+        ("esa/rocket_ship", r#"pub type Rocket { Rocket(name: String) }"#),
+        ("nasa/rocket_ship", r#"pub type Rocket { Rocket(name: String) }"#);
+        r#"
+import esa/rocket_ship
+import nasa/rocket_ship as nasa
+
+pub fn swap(rocket: rocket_ship.Rocket) -> nasa.Rocket {
+  nasa.Rocket(name: rocket.name)
+}
+"#
+    );
+}
+
+#[test]
+fn clashing_go_package_names() {
+    let modules: Vec<EcoString> = vec!["a_b/c".into(), "app".into(), "a/b_c".into()];
+    assert_eq!(
+        crate::go::check_package_names(&modules, &HashMap::new()),
+        Err(crate::Error::GoPackageNameClash {
+            name: "a_b_c_P".into(),
+            first: "a/b_c".into(),
+            second: "a_b/c".into(),
+        })
+    );
+}
+
+#[test]
+fn invalid_go_package_name() {
+    let modules: Vec<EcoString> = vec!["app/router".into()];
+    let package_names = HashMap::from([("app/router".into(), "http-router".into())]);
+    assert_eq!(
+        crate::go::check_package_names(&modules, &package_names),
+        Err(crate::Error::InvalidGoPackageName {
+            module: "app/router".into(),
+            name: "http-router".into(),
+        })
+    );
+}
@@ -311,3 +311,72 @@ fn main(x) {
 "#
     );
 }
+
+// On Erlang `1.0 /. 0.0` is 0.0, so Go returns zero rather than infinity.
+#[test]
+fn float_division_by_zero() {
+    assert_go!(
+        r#"
+pub fn main() {
+  1.0 /. 0.0
+}
+"#
+    );
+}
+
+// On Erlang an overflowing float operation raises a badarith error, so on Go
+// every float operation is checked for overflow to infinity.
+#[test]
+fn float_overflow_is_checked() {
+    assert_go!(
+        r#"
+pub fn main() {
+  let big = 1.0e308
+  let sum = big +. big
+  let difference = 0.0 -. big -. big
+  let product = big *. 10.0
+  let quotient = big /. 0.1
+  #(sum, difference, product, quotient)
+}
+"#
+    );
+}
+
+#[test]
+fn float_operation_in_tail_position() {
+    assert_go!(
+        r#"
+pub fn add(a, b) {
+  a +. b
+}
+"#
+    );
+}
+
+#[test]
+fn nested_float_operations() {
+    assert_go!(
+        r#"
+pub fn main(a, b) {
+  { a +. b } *. { a -. b } /. 2.0
+}
+"#
+    );
+}
+
+// On Erlang a guard raising an error doesn't match, so on Go a guard whose
+// float arithmetic overflows is false rather than a panic.
+#[test]
+fn float_overflow_in_guard_is_checked() {
+    assert_go!(
+        r#"
+pub fn main(a, b) {
+  case a {
+    _ if a +. b >. 1.0 || a *. b <. 0.0 -> 1
+    _ if a <. b -> 2
+    _ -> 3
+  }
+}
+"#
+    );
+}
@@ -68,3 +68,85 @@ pub fn get_name(person: Person) { person.name }
 pub fn get_age(person: Person) { person.age }"
     );
 }
+
+#[test]
+fn record_access_on_inferred_variant() {
+    // Fields only present in one variant are read from the struct of that
+    // variant once it is known.
+    assert_go!(
+        "
+pub type Pet {
+  Cat(name: String, lives: Int)
+  Dog(name: String, good: Bool)
+}
+
+pub fn lives(pet: Pet) {
+  case pet {
+    Cat(..) -> pet.lives
+    Dog(..) -> 0
+  }
+}
+"
+    );
+}
+
+#[test]
+fn record_update_on_multiple_variants() {
+    assert_go!(
+        "
+pub type Pet {
+  Cat(name: String, lives: Int)
+  Dog(name: String, good: Bool)
+}
+
+pub fn rename(pet: Pet, name: String) {
+  case pet {
+    Cat(..) -> Cat(..pet, name: name, lives: pet.lives - 1)
+    Dog(..) -> Dog(..pet, name: name)
+  }
+}
+"
+    );
+}
+
+#[test]
+fn record_update_changing_type_parameter_on_multiple_variants() {
+    // A record update changing the type of the record builds a new struct, as
+    // the `with` helpers return a value of the same type.
+    assert_go!(
+        "
+pub type Box(a) {
+  Full(label: String, value: a)
+  Empty(label: String)
+}
+
+pub fn replace(box: Box(Int), value: String) -> Box(String) {
+  case box {
+    Full(..) -> Full(..box, value: value)
+    Empty(..) -> Empty(label: box.label)
+  }
+}
+"
+    );
+}
+
+#[test]
+fn record_update_with_label_ending_in_with() {
+    // The update method of `name` must not clash with anything generated for
+    // the `name_with` label.
+    assert_go!(
+        "
+pub type Thing {
+  A(name: Int, name_with: Int)
+  B(name: Int, name_with: Int)
+}
+
+pub fn go(thing: Thing) {
+  case thing {
+    A(..) -> A(..thing, name: 1, name_with: 2)
+    B(..) -> thing
+  }
+}
+"
+    );
+}
@@ -0,0 +1,93 @@
+use crate::{
+    config::PackageConfig,
+    go::shell::{EntryKind, Session},
+    uid::UniqueIdGenerator,
+};
+
+fn compile_entries(entries: &[&str], entry: &str) -> Result<String, crate::Error> {
+    let (modules, _) = super::compile(
+        "",
+        vec![(
+            super::CURRENT_PACKAGE,
+            "wibble",
+            "pub fn add(a, b) { a + b }",
+        )],
+    );
+    let mut config = PackageConfig::default();
+    config.name = super::CURRENT_PACKAGE.into();
+
+    let mut session = Session::new(UniqueIdGenerator::new());
+    for earlier in entries {
+        let _ = session
+            .compile(earlier, &config, &modules, "example.com/todo")
+            .expect("earlier entry should compile");
+        session.record(earlier);
+    }
+    let compiled = session.compile(entry, &config, &modules, "example.com/todo")?;
+    Ok(format!(
+        "----- MODULE\n{}\n----- MAIN\n{}",
+        compiled.module, compiled.main
+    ))
+}
+
+#[test]
+fn entry_kinds() {
+    assert_eq!(EntryKind::of("import gleam/io"), EntryKind::Import);
+    assert_eq!(EntryKind::of("  let x = 1"), EntryKind::Binding);
+    assert_eq!(EntryKind::of("letter"), EntryKind::Expression);
+    assert_eq!(EntryKind::of("1 + 2"), EntryKind::Expression);
+}
+
+#[test]
+fn incomplete_entries() {
+    assert!(Session::is_incomplete("[1, 2,"));
+    assert!(Session::is_incomplete("let x = {\n  1"));
+    assert!(!Session::is_incomplete("[1, 2]"));
+    assert!(!Session::is_incomplete("import gleam/io"));
+}
+
+#[test]
+fn expression() {
+    insta::assert_snapshot!(compile_entries(&[], "1 + 2").unwrap());
+}
+
+#[test]
+fn bindings_and_imports_are_replayed() {
+    insta::assert_snapshot!(compile_entries(
+        &["import wibble", "let x = wibble.add(1, 2)"],
+        "x * 2"
+    )
+    .unwrap());
+}
+
+#[test]
+fn generic_values_are_printed_as_nil() {
+    insta::assert_snapshot!(compile_entries(&[], "#([], Error)").unwrap());
+}
+
+#[test]
+fn type_errors_are_reported() {
+    let error = compile_entries(&["let x = 1"], "x <> \"a\"").unwrap_err();
+    assert!(matches!(error, crate::Error::Type { .. }));
+}
+
+#[test]
+fn expressions_are_not_replayed() {
+    insta::assert_snapshot!(compile_entries(&["1 + 2"], "3").unwrap());
+}
+
+#[test]
+fn entries_use_the_session_ids() {
+    let (modules, _) = super::compile("", vec![]);
+    let ids = UniqueIdGenerator::new();
+    let session = Session::new(ids.clone());
+    let _ = session
+        .compile(
+            "[]",
+            &PackageConfig::default(),
+            &modules,
+            "example.com/todo",
+        )
+        .expect("entry should compile");
+    assert!(ids.next() > 0);
+}
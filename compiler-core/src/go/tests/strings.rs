@@ -44,6 +44,18 @@ pub fn unicode_escape_sequence_6_digits() -> String {
     );
 }
 
+#[test]
+fn multi_line_string_literal() {
+    assert_go!(
+        r#"
+pub fn go() {
+  "Hello,
+Gleam!"
+}
+"#,
+    );
+}
+
 #[test]
 fn string_literals() {
     assert_go!(
@@ -1,14 +1,20 @@
+mod call_hierarchy;
 mod code_action;
+mod code_lens;
 mod compiler;
 mod completer;
 mod edits;
 mod engine;
 mod feedback;
 mod files;
+mod implementation;
+mod inlay_hints;
 mod messages;
 mod progress;
+mod references;
 mod rename;
 mod router;
+mod semantic_tokens;
 mod server;
 mod signature_help;
 
@@ -40,6 +46,25 @@ pub trait DownloadDependencies {
     fn download_dependencies(&self, paths: &ProjectPaths) -> Result<Manifest>;
 }
 
+/// Something printed by a `gleam` command run from the language server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommandOutput {
+    Line(String),
+    Exited(i32),
+}
+
+pub trait RunGleamCommand {
+    /// Starts running the `gleam` executable with the given arguments in the
+    /// project's root directory, without waiting for it to finish. Each line it
+    /// prints is passed to `output` as it's printed, followed by its exit code.
+    fn run_gleam_command(
+        &self,
+        paths: &ProjectPaths,
+        arguments: Vec<String>,
+        output: Box<dyn FnMut(CommandOutput) + Send>,
+    ) -> Result<()>;
+}
+
 pub fn src_span_to_lsp_range(location: SrcSpan, line_numbers: &LineNumbers) -> Range {
     let start = line_numbers.line_and_column_number(location.start);
     let end = line_numbers.line_and_column_number(location.end);
@@ -102,3 +127,9 @@ fn path(uri: &Url) -> Utf8PathBuf {
     #[cfg(not(any(unix, windows, target_os = "redox", target_os = "wasi")))]
     return Utf8PathBuf::from_path_buf(uri.path().into()).expect("Non Utf8 Path");
 }
+
+fn path_to_uri(path: Utf8PathBuf) -> Url {
+    let mut file: String = "file://".into();
+    file.push_str(&path.as_os_str().to_string_lossy());
+    Url::parse(&file).expect("path_to_uri URL parse")
+}
@@ -0,0 +1,59 @@
+use camino::Utf8PathBuf;
+use ecow::EcoString;
+use lsp_types::CallHierarchyItem;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ast::{SrcSpan, TypedFunction},
+    call_graph::reachable,
+};
+
+use super::references::Referenced;
+
+/// The data attached to each call hierarchy item, so that the function it
+/// stands for can be found again when the editor asks for its calls.
+///
+/// The editor sends the item back as it received it, with no document to tell
+/// which project it belongs to, and the item may be a function from one of
+/// the project's dependencies. So the project's root directory is recorded
+/// too, to route the request to the project the hierarchy was started from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ItemData {
+    pub root: Utf8PathBuf,
+    pub module: EcoString,
+    pub location: SrcSpan,
+}
+
+impl ItemData {
+    pub fn from_item(item: &CallHierarchyItem) -> Option<Self> {
+        serde_json::from_value(item.data.clone()?).ok()
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).expect("call hierarchy item data serialisation")
+    }
+
+    /// The function this item stands for, in the same form used to find
+    /// references to it.
+    pub fn function(&self) -> Referenced {
+        Referenced::Value {
+            module: self.module.clone(),
+            location: self.location,
+        }
+    }
+}
+
+/// Finds all the calls to module functions made in the body of the given
+/// function, along with the location of the called function's name.
+pub fn calls(function: &TypedFunction) -> Vec<(Referenced, SrcSpan)> {
+    reachable::calls(function)
+        .into_iter()
+        .map(|call| {
+            let called = Referenced::Value {
+                module: call.module,
+                location: call.definition,
+            };
+            (called, call.location)
+        })
+        .collect()
+}
@@ -3913,6 +3913,1366 @@ impl<'ast> ast::visit::Visit<'ast> for GenerateFunction<'ast> {
     }
 }
 
+/// Builder for code action to extract the selected code into a new function.
+///
+/// ```gleam
+/// pub fn main() {
+///   let wibble = 1
+///   let wobble = wibble + 2
+///   //           ^^^^^^^^^^ selected
+///   wobble
+/// }
+/// ```
+///
+/// Will turn the selected code into a new private function, taking the
+/// variables it uses as its arguments:
+///
+/// ```gleam
+/// pub fn main() {
+///   let wibble = 1
+///   let wobble = function(wibble)
+///   wobble
+/// }
+///
+/// fn function(wibble: Int) -> Int {
+///   wibble + 2
+/// }
+/// ```
+///
+/// When the selected statements define variables that are used after them,
+/// the new function returns those variables.
+///
+pub struct ExtractFunction<'a> {
+    module: &'a Module,
+    params: &'a CodeActionParams,
+    edits: TextEdits<'a>,
+    selection: Option<SrcSpan>,
+    current_function: Option<&'a ast::TypedFunction>,
+    extracted: Option<ExtractedCode<'a>>,
+}
+
+struct ExtractedCode<'a> {
+    function: &'a ast::TypedFunction,
+    kind: ExtractedCodeKind<'a>,
+}
+
+enum ExtractedCodeKind<'a> {
+    Expression(&'a TypedExpr),
+    Statements(&'a [TypedStatement]),
+}
+
+impl ExtractedCode<'_> {
+    fn location(&self) -> SrcSpan {
+        match &self.kind {
+            ExtractedCodeKind::Expression(expression) => expression.location(),
+            ExtractedCodeKind::Statements(statements) => {
+                let first = statements.first().expect("non empty statements");
+                let last = statements.last().expect("non empty statements");
+                SrcSpan::new(
+                    statement_location(first).start,
+                    statement_location(last).end,
+                )
+            }
+        }
+    }
+}
+
+impl<'a> ExtractFunction<'a> {
+    pub fn new(
+        module: &'a Module,
+        line_numbers: &'a LineNumbers,
+        params: &'a CodeActionParams,
+    ) -> Self {
+        let start = line_numbers.byte_index(params.range.start.line, params.range.start.character);
+        let end = line_numbers.byte_index(params.range.end.line, params.range.end.character);
+        Self {
+            module,
+            params,
+            edits: TextEdits::new(line_numbers),
+            selection: trim_selection(&module.code, start, end),
+            current_function: None,
+            extracted: None,
+        }
+    }
+
+    pub fn code_actions(mut self) -> Vec<CodeAction> {
+        if self.selection.is_none() {
+            return vec![];
+        }
+        self.visit_typed_module(&self.module.ast);
+
+        let Some(extracted) = self.extracted.take() else {
+            return vec![];
+        };
+        let location = extracted.location();
+
+        // The variables used in the extracted code that are defined outside
+        // of it become the arguments of the new function.
+        let mut extracted_variables = LocalVariables::default();
+        match extracted.kind {
+            ExtractedCodeKind::Expression(expression) => {
+                extracted_variables.visit_typed_expr(expression)
+            }
+            ExtractedCodeKind::Statements(statements) => statements
+                .iter()
+                .for_each(|statement| extracted_variables.visit_typed_statement(statement)),
+        }
+        if extracted_variables.invalid {
+            return vec![];
+        }
+        let arguments = extracted_variables.defined_outside(location);
+
+        // The variables defined in the extracted code that are used after it
+        // have to be returned by the new function.
+        let mut function_variables = LocalVariables::default();
+        function_variables.visit_typed_function(extracted.function);
+        let returned = function_variables.used_after(location);
+
+        let mut name_generator = NameGenerator::new();
+        for definition in &self.module.ast.definitions {
+            match definition {
+                ast::Definition::Function(function) => {
+                    if let Some((_, name)) = &function.name {
+                        name_generator.add_used_name(name.clone());
+                    }
+                }
+                ast::Definition::ModuleConstant(constant) => {
+                    name_generator.add_used_name(constant.name.clone())
+                }
+                ast::Definition::Import(import) => import
+                    .unqualified_values
+                    .iter()
+                    .for_each(|value| name_generator.add_used_name(value.used_name().clone())),
+                ast::Definition::TypeAlias(_) | ast::Definition::CustomType(_) => (),
+            }
+        }
+        // A local variable with the same name would shadow the new function
+        // where it's called.
+        for argument in &extracted.function.arguments {
+            if let Some(name) = argument.get_variable_name() {
+                name_generator.add_used_name(name.clone());
+            }
+        }
+        name_generator
+            .reserve_variable_names(VariablesNames::from_statements(&extracted.function.body));
+        let name = name_generator.rename_to_avoid_shadowing("function".into());
+
+        let mut printer = Printer::new(&self.module.ast.names);
+        let parameters = arguments
+            .iter()
+            .map(|(name, type_)| format!("{name}: {}", printer.print_type(type_)))
+            .join(", ");
+        let call = format!(
+            "{name}({})",
+            arguments.iter().map(|(name, _)| name).join(", ")
+        );
+
+        let mut body = self.indented_code(location);
+        let (return_type, call) = match (&extracted.kind, returned.as_slice()) {
+            (ExtractedCodeKind::Expression(expression), _) => {
+                (printer.print_type(&expression.type_()), call)
+            }
+            (ExtractedCodeKind::Statements(statements), []) => {
+                let last = statements.last().expect("non empty statements");
+                (printer.print_type(&last.type_()), call)
+            }
+            (ExtractedCodeKind::Statements(_), [(variable, type_)]) => {
+                body.push_str(&format!("\n  {variable}"));
+                (
+                    printer.print_type(type_),
+                    format!("let {variable} = {call}"),
+                )
+            }
+            (ExtractedCodeKind::Statements(_), returned) => {
+                let variables = returned.iter().map(|(name, _)| name).join(", ");
+                let types = returned
+                    .iter()
+                    .map(|(_, type_)| printer.print_type(type_))
+                    .join(", ");
+                body.push_str(&format!("\n  #({variables})"));
+                (
+                    eco_format!("#({types})"),
+                    format!("let #({variables}) = {call}"),
+                )
+            }
+        };
+
+        self.edits.replace(location, call);
+        self.edits.insert(
+            extracted.function.end_position,
+            format!("\n\nfn {name}({parameters}) -> {return_type} {{\n{body}\n}}"),
+        );
+
+        let mut action = Vec::with_capacity(1);
+        CodeActionBuilder::new("Extract function")
+            .kind(CodeActionKind::REFACTOR_EXTRACT)
+            .changes(self.params.text_document.uri.clone(), self.edits.edits)
+            .preferred(false)
+            .push_to(&mut action);
+        action
+    }
+
+    /// The extracted code indented to be the body of a top level function.
+    fn indented_code(&self, location: SrcSpan) -> String {
+        let code = &self.module.code;
+        let indentation = line_indentation(code, location.start);
+        let extracted = code
+            .get(location.start as usize..location.end as usize)
+            .expect("extracted code");
+        format!("  {}", reindent(extracted, indentation, "  "))
+    }
+
+    /// Checks if the selection covers some consecutive statements of a block,
+    /// starting and ending exactly where they do.
+    fn check_statements(&mut self, statements: &'a [TypedStatement]) {
+        let (Some(selection), Some(function), None) =
+            (self.selection, self.current_function, &self.extracted)
+        else {
+            return;
+        };
+        let Some(first) = statements
+            .iter()
+            .position(|statement| statement_location(statement).start == selection.start)
+        else {
+            return;
+        };
+        let Some(last) = statements
+            .iter()
+            .skip(first)
+            .position(|statement| statement_location(statement).end == selection.end)
+        else {
+            return;
+        };
+        let Some(selected) = statements.get(first..=first + last) else {
+            return;
+        };
+        self.extracted = Some(ExtractedCode {
+            function,
+            kind: ExtractedCodeKind::Statements(selected),
+        });
+    }
+}
+
+impl<'ast> ast::visit::Visit<'ast> for ExtractFunction<'ast> {
+    fn visit_typed_function(&mut self, fun: &'ast ast::TypedFunction) {
+        self.current_function = Some(fun);
+        self.check_statements(&fun.body);
+        ast::visit::visit_typed_function(self, fun);
+        self.current_function = None;
+    }
+
+    fn visit_typed_expr(&mut self, expr: &'ast TypedExpr) {
+        if let (Some(selection), Some(function), None) =
+            (self.selection, self.current_function, &self.extracted)
+        {
+            match expr {
+                // A variable is already as simple as it gets, and module
+                // selects are always considered as part of a function call.
+                TypedExpr::Var { .. } | TypedExpr::ModuleSelect { .. } => (),
+                // The callback of a `use` can't be extracted on its own.
+                TypedExpr::Fn {
+                    kind: FunctionLiteralKind::Use { .. },
+                    ..
+                } => (),
+                _ if expr.location() == selection => {
+                    self.extracted = Some(ExtractedCode {
+                        function,
+                        kind: ExtractedCodeKind::Expression(expr),
+                    });
+                }
+                _ => (),
+            }
+        }
+
+        ast::visit::visit_typed_expr(self, expr);
+    }
+
+    fn visit_typed_expr_block(
+        &mut self,
+        location: &'ast SrcSpan,
+        statements: &'ast [TypedStatement],
+    ) {
+        self.check_statements(statements);
+        ast::visit::visit_typed_expr_block(self, location, statements);
+    }
+
+    fn visit_typed_expr_fn(
+        &mut self,
+        location: &'ast SrcSpan,
+        type_: &'ast Arc<Type>,
+        kind: &'ast FunctionLiteralKind,
+        args: &'ast [TypedArg],
+        body: &'ast Vec1<TypedStatement>,
+        return_annotation: &'ast Option<ast::TypeAst>,
+    ) {
+        // The body of a capture is a single call inserted by the compiler,
+        // the capture itself is extracted instead.
+        if !kind.is_capture() {
+            self.check_statements(body);
+        }
+        ast::visit::visit_typed_expr_fn(self, location, type_, kind, args, body, return_annotation);
+    }
+
+    fn visit_typed_expr_pipeline(
+        &mut self,
+        _location: &'ast SrcSpan,
+        assignments: &'ast [ast::TypedPipelineAssignment],
+        finally: &'ast TypedExpr,
+    ) {
+        // The steps of a pipeline can't be extracted on their own as they are
+        // missing their first argument, but what's inside them can.
+        for assignment in assignments {
+            ast::visit::visit_typed_expr(self, &assignment.value);
+        }
+        ast::visit::visit_typed_expr(self, finally);
+    }
+
+    fn visit_typed_use(&mut self, use_: &'ast TypedUse) {
+        // The call a `use` desugars to can't be extracted on its own.
+        ast::visit::visit_typed_expr(self, &use_.call);
+    }
+}
+
+/// The location of a statement. For a `use` this includes all the statements
+/// following it, as they are part of its callback.
+fn statement_location(statement: &TypedStatement) -> SrcSpan {
+    match statement {
+        ast::Statement::Use(use_) => {
+            SrcSpan::new(use_.location.start, statement.last_location().end)
+        }
+        ast::Statement::Expression(_) | ast::Statement::Assignment(_) => statement.location(),
+    }
+}
+
+/// Removes any whitespace from the start and end of the selection, returning
+/// nothing if it's empty.
+fn trim_selection(code: &str, start: u32, end: u32) -> Option<SrcSpan> {
+    let selected = code.get(start as usize..end as usize)?;
+    let trimmed = selected.trim();
+    if trimmed.is_empty() {
+        return None;
+    }
+    let start = start + (selected.len() - selected.trim_start().len()) as u32;
+    Some(SrcSpan::new(start, start + trimmed.len() as u32))
+}
+
+/// All the references to local variables, along with where they are defined.
+#[derive(Default)]
+struct LocalVariables {
+    references: Vec<LocalVariableReference>,
+    /// The names of the module values referenced without being qualified.
+    module_values: HashSet<EcoString>,
+    invalid: bool,
+}
+
+struct LocalVariableReference {
+    name: EcoString,
+    type_: Arc<Type>,
+    location: SrcSpan,
+    definition: SrcSpan,
+    /// If the variable is used in a clause guard, where only variables and
+    /// constants are allowed.
+    in_guard: bool,
+    /// If the variable is used as the size of a bit array segment in a
+    /// pattern, where only variables and constants are allowed.
+    in_pattern: bool,
+    /// If the variable is passed to a function using the label shorthand
+    /// syntax `wibble(label:)`.
+    label_shorthand: bool,
+}
+
+impl LocalVariableReference {
+    /// Whether the variable is used where it can't be replaced by any other
+    /// expression.
+    fn requires_variable(&self) -> bool {
+        self.in_guard || self.in_pattern
+    }
+}
+
+impl LocalVariables {
+    /// The variables referenced in the given location that are defined
+    /// outside of it, in the order they are first used.
+    fn defined_outside(&self, location: SrcSpan) -> Vec<(EcoString, Arc<Type>)> {
+        self.references
+            .iter()
+            .filter(|reference| !contains(location, reference.definition))
+            .map(|reference| (reference.name.clone(), reference.type_.clone()))
+            .unique_by(|(name, _)| name.clone())
+            .collect()
+    }
+
+    /// The variables defined in the given location that are referenced after
+    /// it, in the order they are defined.
+    fn used_after(&self, location: SrcSpan) -> Vec<(EcoString, Arc<Type>)> {
+        self.references
+            .iter()
+            .filter(|reference| {
+                reference.location.start >= location.end && contains(location, reference.definition)
+            })
+            .sorted_by_key(|reference| reference.definition.start)
+            .map(|reference| (reference.name.clone(), reference.type_.clone()))
+            .unique_by(|(name, _)| name.clone())
+            .collect()
+    }
+}
+
+fn contains(outer: SrcSpan, inner: SrcSpan) -> bool {
+    outer.start <= inner.start && inner.end <= outer.end
+}
+
+impl<'ast> ast::visit::Visit<'ast> for LocalVariables {
+    fn visit_typed_expr_var(
+        &mut self,
+        location: &'ast SrcSpan,
+        constructor: &'ast ValueConstructor,
+        name: &'ast EcoString,
+    ) {
+        if let type_::ValueConstructorVariant::LocalVariable {
+            location: definition,
+            ..
+        } = &constructor.variant
+        {
+            self.references.push(LocalVariableReference {
+                name: name.clone(),
+                type_: constructor.type_.clone(),
+                location: *location,
+                definition: *definition,
+                in_guard: false,
+                in_pattern: false,
+                label_shorthand: false,
+            });
+        } else {
+            let _ = self.module_values.insert(name.clone());
+        }
+    }
+
+    fn visit_typed_call_arg(&mut self, arg: &'ast TypedCallArg) {
+        let first_reference = self.references.len();
+        visit_typed_call_arg(self, arg);
+        if arg.uses_label_shorthand() {
+            if let Some(reference) = self.references.get_mut(first_reference) {
+                reference.label_shorthand = true;
+            }
+        }
+    }
+
+    fn visit_typed_clause_guard_var(
+        &mut self,
+        location: &'ast SrcSpan,
+        name: &'ast EcoString,
+        type_: &'ast Arc<Type>,
+        definition_location: &'ast SrcSpan,
+    ) {
+        self.references.push(LocalVariableReference {
+            name: name.clone(),
+            type_: type_.clone(),
+            location: *location,
+            definition: *definition_location,
+            in_guard: true,
+            in_pattern: false,
+            label_shorthand: false,
+        });
+    }
+
+    fn visit_typed_pattern_var_usage(
+        &mut self,
+        location: &'ast SrcSpan,
+        name: &'ast EcoString,
+        constructor: &'ast Option<ValueConstructor>,
+        type_: &'ast Arc<Type>,
+    ) {
+        let Some(ValueConstructor {
+            variant:
+                type_::ValueConstructorVariant::LocalVariable {
+                    location: definition,
+                    ..
+                },
+            ..
+        }) = constructor
+        else {
+            return;
+        };
+        self.references.push(LocalVariableReference {
+            name: name.clone(),
+            type_: type_.clone(),
+            location: *location,
+            definition: *definition,
+            in_guard: false,
+            in_pattern: true,
+            label_shorthand: false,
+        });
+    }
+
+    fn visit_typed_expr_invalid(&mut self, _location: &'ast SrcSpan, _type_: &'ast Arc<Type>) {
+        self.invalid = true;
+    }
+}
+
+/// The number of spaces the line containing the given byte index is indented
+/// by.
+fn line_indentation(code: &str, byte_index: u32) -> usize {
+    let line_start = code
+        .get(..byte_index as usize)
+        .and_then(|before| before.rfind('\n'))
+        .map_or(0, |newline| newline + 1);
+    code.get(line_start..)
+        .unwrap_or_default()
+        .chars()
+        .take_while(|char| *char == ' ')
+        .count()
+}
+
+/// Moves some code to a different indentation: all lines but the first one
+/// have `from` spaces of indentation replaced with the given indentation.
+fn reindent(code: &str, from: usize, to: &str) -> String {
+    code.lines()
+        .enumerate()
+        .map(|(index, line)| {
+            if index == 0 {
+                return line.to_string();
+            }
+            let leading_spaces = line.chars().take_while(|char| *char == ' ').count();
+            let line = line.get(leading_spaces.min(from)..).unwrap_or(line);
+            if line.is_empty() {
+                String::new()
+            } else {
+                format!("{to}{line}")
+            }
+        })
+        .join("\n")
+}
+
+/// The precedence of an expression when used as an operand, the same one the
+/// formatter uses to decide if it needs to be wrapped in a block.
+fn operator_precedence(expression: &TypedExpr) -> u8 {
+    match expression {
+        TypedExpr::BinOp { name, .. } => name.precedence(),
+        TypedExpr::Pipeline { .. } => 5,
+        _ => u8::MAX,
+    }
+}
+
+/// The locations of all the expressions used as operands: the arguments of
+/// binary operators, the first steps of pipelines, the records fields are
+/// accessed on and the functions being called. Each comes with the lowest
+/// precedence an expression can have to be put there without being wrapped
+/// in a block.
+#[derive(Default)]
+struct OperandLocations {
+    operands: Vec<(SrcSpan, u8)>,
+}
+
+impl OperandLocations {
+    /// Whether the code replacing the expression at the given location needs
+    /// to be wrapped in a block to keep the same meaning.
+    fn needs_grouping(&self, location: SrcSpan, replacement: &TypedExpr) -> bool {
+        self.operands.iter().any(|(operand, precedence)| {
+            *operand == location && operator_precedence(replacement) < *precedence
+        })
+    }
+}
+
+impl<'ast> ast::visit::Visit<'ast> for OperandLocations {
+    fn visit_typed_expr_bin_op(
+        &mut self,
+        location: &'ast SrcSpan,
+        type_: &'ast Arc<Type>,
+        name: &'ast ast::BinOp,
+        left: &'ast TypedExpr,
+        right: &'ast TypedExpr,
+    ) {
+        // Operators are left associative, so an operator with the same
+        // precedence needs to be wrapped only on the right hand side.
+        self.operands.push((left.location(), name.precedence()));
+        self.operands
+            .push((right.location(), name.precedence() + 1));
+        ast::visit::visit_typed_expr_bin_op(self, location, type_, name, left, right);
+    }
+
+    fn visit_typed_pipeline_assignment(&mut self, assignment: &'ast ast::TypedPipelineAssignment) {
+        self.operands.push((assignment.value.location(), 5));
+        ast::visit::visit_typed_pipeline_assignment(self, assignment);
+    }
+
+    fn visit_typed_expr_record_access(
+        &mut self,
+        location: &'ast SrcSpan,
+        type_: &'ast Arc<Type>,
+        label: &'ast EcoString,
+        index: &'ast u64,
+        record: &'ast TypedExpr,
+    ) {
+        self.operands.push((record.location(), u8::MAX));
+        ast::visit::visit_typed_expr_record_access(self, location, type_, label, index, record);
+    }
+
+    fn visit_typed_expr_tuple_index(
+        &mut self,
+        location: &'ast SrcSpan,
+        type_: &'ast Arc<Type>,
+        index: &'ast u64,
+        tuple: &'ast TypedExpr,
+    ) {
+        self.operands.push((tuple.location(), u8::MAX));
+        ast::visit::visit_typed_expr_tuple_index(self, location, type_, index, tuple);
+    }
+
+    fn visit_typed_expr_call(
+        &mut self,
+        location: &'ast SrcSpan,
+        type_: &'ast Arc<Type>,
+        fun: &'ast TypedExpr,
+        args: &'ast [TypedCallArg],
+    ) {
+        self.operands.push((fun.location(), u8::MAX));
+        ast::visit::visit_typed_expr_call(self, location, type_, fun, args);
+    }
+}
+
+/// The locations of the code that might not be evaluated, or might be
+/// evaluated more than once, when the expression containing it is: the
+/// clauses of case expressions, the bodies of anonymous functions and the
+/// right hand side of boolean operators.
+#[derive(Default)]
+struct ConditionalLocations {
+    locations: Vec<SrcSpan>,
+}
+
+impl ConditionalLocations {
+    fn contains(&self, location: SrcSpan) -> bool {
+        self.locations
+            .iter()
+            .any(|conditional| contains(*conditional, location))
+    }
+}
+
+impl<'ast> ast::visit::Visit<'ast> for ConditionalLocations {
+    fn visit_typed_clause(&mut self, clause: &'ast ast::TypedClause) {
+        self.locations.push(clause.location);
+        ast::visit::visit_typed_clause(self, clause);
+    }
+
+    fn visit_typed_expr_fn(
+        &mut self,
+        location: &'ast SrcSpan,
+        type_: &'ast Arc<Type>,
+        kind: &'ast FunctionLiteralKind,
+        args: &'ast [TypedArg],
+        body: &'ast Vec1<TypedStatement>,
+        return_annotation: &'ast Option<ast::TypeAst>,
+    ) {
+        self.locations.push(*location);
+        ast::visit::visit_typed_expr_fn(self, location, type_, kind, args, body, return_annotation);
+    }
+
+    fn visit_typed_expr_bin_op(
+        &mut self,
+        location: &'ast SrcSpan,
+        type_: &'ast Arc<Type>,
+        name: &'ast ast::BinOp,
+        left: &'ast TypedExpr,
+        right: &'ast TypedExpr,
+    ) {
+        if let ast::BinOp::And | ast::BinOp::Or = name {
+            self.locations.push(right.location());
+        }
+        ast::visit::visit_typed_expr_bin_op(self, location, type_, name, left, right);
+    }
+}
+
+/// All the variables bound by patterns and function arguments, along with
+/// where they are defined.
+#[derive(Default)]
+struct BoundVariables {
+    variables: Vec<(EcoString, SrcSpan)>,
+}
+
+impl BoundVariables {
+    fn names(&self) -> HashSet<EcoString> {
+        self.variables
+            .iter()
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+}
+
+impl<'ast> ast::visit::Visit<'ast> for BoundVariables {
+    fn visit_typed_function(&mut self, fun: &'ast ast::TypedFunction) {
+        for argument in &fun.arguments {
+            if let Some(name) = argument.get_variable_name() {
+                self.variables.push((name.clone(), argument.location));
+            }
+        }
+        ast::visit::visit_typed_function(self, fun);
+    }
+
+    fn visit_typed_expr_fn(
+        &mut self,
+        location: &'ast SrcSpan,
+        type_: &'ast Arc<Type>,
+        kind: &'ast FunctionLiteralKind,
+        args: &'ast [TypedArg],
+        body: &'ast Vec1<TypedStatement>,
+        return_annotation: &'ast Option<ast::TypeAst>,
+    ) {
+        for argument in args {
+            if let Some(name) = argument.get_variable_name() {
+                self.variables.push((name.clone(), argument.location));
+            }
+        }
+        ast::visit::visit_typed_expr_fn(self, location, type_, kind, args, body, return_annotation);
+    }
+
+    fn visit_typed_pattern_variable(
+        &mut self,
+        location: &'ast SrcSpan,
+        name: &'ast EcoString,
+        _type_: &'ast Arc<Type>,
+        _origin: &'ast VariableOrigin,
+    ) {
+        self.variables.push((name.clone(), *location));
+    }
+
+    fn visit_typed_pattern_assign(
+        &mut self,
+        location: &'ast SrcSpan,
+        name: &'ast EcoString,
+        pattern: &'ast TypedPattern,
+    ) {
+        self.variables.push((name.clone(), *location));
+        ast::visit::visit_typed_pattern_assign(self, location, name, pattern);
+    }
+
+    fn visit_typed_pattern_string_prefix(
+        &mut self,
+        _location: &'ast SrcSpan,
+        left_location: &'ast SrcSpan,
+        left_side_assignment: &'ast Option<(EcoString, SrcSpan)>,
+        right_location: &'ast SrcSpan,
+        _left_side_string: &'ast EcoString,
+        right_side_assignment: &'ast AssignName,
+    ) {
+        if let Some((name, _)) = left_side_assignment {
+            self.variables.push((name.clone(), *left_location));
+        }
+        if let AssignName::Variable(name) = right_side_assignment {
+            self.variables.push((name.clone(), *right_location));
+        }
+    }
+}
+
+/// Builder for code action to inline a variable, replacing all its uses with
+/// its value and removing its definition.
+///
+/// ```gleam
+/// pub fn main() {
+///   let wibble = [1, 2]
+///   //  ^^^^^^ [inline variable]
+///   list.length(wibble)
+/// }
+/// ```
+///
+/// Will become:
+///
+/// ```gleam
+/// pub fn main() {
+///   list.length([1, 2])
+/// }
+/// ```
+///
+/// As the value may end up being evaluated a different number of times, or
+/// at a different time, this is only offered for values that have no side
+/// effects.
+///
+pub struct InlineVariable<'a> {
+    module: &'a Module,
+    params: &'a CodeActionParams,
+    edits: TextEdits<'a>,
+    current_function: Option<&'a ast::TypedFunction>,
+    bindings: Vec<InlinableBinding<'a>>,
+    selected_variable: Option<SrcSpan>,
+}
+
+struct InlinableBinding<'a> {
+    function: &'a ast::TypedFunction,
+    assignment: &'a TypedAssignment,
+    /// The location of the variable's name in the `let` pattern.
+    definition: SrcSpan,
+    /// Where the following statement starts, the binding is removed up to
+    /// there.
+    next_statement_start: u32,
+}
+
+impl<'a> InlineVariable<'a> {
+    pub fn new(
+        module: &'a Module,
+        line_numbers: &'a LineNumbers,
+        params: &'a CodeActionParams,
+    ) -> Self {
+        Self {
+            module,
+            params,
+            edits: TextEdits::new(line_numbers),
+            current_function: None,
+            bindings: vec![],
+            selected_variable: None,
+        }
+    }
+
+    pub fn code_actions(mut self) -> Vec<CodeAction> {
+        self.visit_typed_module(&self.module.ast);
+
+        let Some(definition) = self.selected_variable else {
+            return vec![];
+        };
+        let Some(binding) = self
+            .bindings
+            .iter()
+            .find(|binding| binding.definition == definition)
+        else {
+            return vec![];
+        };
+
+        let mut function_variables = LocalVariables::default();
+        function_variables.visit_typed_function(binding.function);
+        let references = function_variables
+            .references
+            .iter()
+            .filter(|reference| reference.definition == definition)
+            .collect_vec();
+        // Only variables and constants can be used in a guard or as the size
+        // of a bit array segment in a pattern, so we can't replace the variable
+        // with its value there.
+        if references
+            .iter()
+            .any(|reference| reference.requires_variable())
+        {
+            return vec![];
+        }
+
+        // The value can only be moved where all the variables it uses still
+        // refer to the same thing, so none of those can be shadowed between
+        // the binding and its last use.
+        let mut value_variables = LocalVariables::default();
+        value_variables.visit_typed_expr(&binding.assignment.value);
+        let used_names = value_variables
+            .references
+            .iter()
+            .map(|reference| &reference.name)
+            .collect::<HashSet<_>>();
+        let mut bound_variables = BoundVariables::default();
+        bound_variables.visit_typed_function(binding.function);
+        let last_use = references
+            .iter()
+            .map(|reference| reference.location.start)
+            .max()
+            .unwrap_or(binding.assignment.location.end);
+        let value_is_shadowed = bound_variables.variables.iter().any(|(name, location)| {
+            used_names.contains(name)
+                && location.start > binding.assignment.location.end
+                && location.start < last_use
+        });
+        if value_is_shadowed {
+            return vec![];
+        }
+
+        let mut operands = OperandLocations::default();
+        operands.visit_typed_function(binding.function);
+
+        let value = &binding.assignment.value;
+        let value_location = value.location();
+        let value_code = self
+            .module
+            .code
+            .get(value_location.start as usize..value_location.end as usize)
+            .expect("variable value");
+        for reference in references {
+            let code = if operands.needs_grouping(reference.location, value) {
+                format!("{{ {value_code} }}")
+            } else {
+                value_code.to_string()
+            };
+            let code = if reference.label_shorthand {
+                format!("{}: {code}", reference.name)
+            } else {
+                code
+            };
+            self.edits.replace(reference.location, code);
+        }
+        self.edits.delete(SrcSpan::new(
+            binding.assignment.location.start,
+            binding.next_statement_start,
+        ));
+
+        let mut action = Vec::with_capacity(1);
+        CodeActionBuilder::new("Inline variable")
+            .kind(CodeActionKind::REFACTOR_INLINE)
+            .changes(self.params.text_document.uri.clone(), self.edits.edits)
+            .preferred(false)
+            .push_to(&mut action);
+        action
+    }
+
+    /// Records all the `let` bindings in a block that can be inlined. A
+    /// binding that's the last statement of a block is the block's value so
+    /// it can't be removed.
+    fn check_statements(&mut self, statements: &'a [TypedStatement]) {
+        let Some(function) = self.current_function else {
+            return;
+        };
+        for (statement, next) in statements.iter().tuple_windows() {
+            let ast::Statement::Assignment(assignment) = statement else {
+                continue;
+            };
+            let Pattern::Variable { location, .. } = &assignment.pattern else {
+                continue;
+            };
+            if assignment.kind != AssignmentKind::Let
+                || !assignment.value.is_pure_value_constructor()
+            {
+                continue;
+            }
+
+            let variable_range = self.edits.src_span_to_lsp_range(*location);
+            if within(self.params.range, variable_range) {
+                self.selected_variable = Some(*location);
+            }
+            self.bindings.push(InlinableBinding {
+                function,
+                assignment,
+                definition: *location,
+                next_statement_start: statement_location(next).start,
+            });
+        }
+    }
+}
+
+impl<'ast> ast::visit::Visit<'ast> for InlineVariable<'ast> {
+    fn visit_typed_function(&mut self, fun: &'ast ast::TypedFunction) {
+        self.current_function = Some(fun);
+        self.check_statements(&fun.body);
+        ast::visit::visit_typed_function(self, fun);
+        self.current_function = None;
+    }
+
+    fn visit_typed_expr_block(
+        &mut self,
+        location: &'ast SrcSpan,
+        statements: &'ast [TypedStatement],
+    ) {
+        self.check_statements(statements);
+        ast::visit::visit_typed_expr_block(self, location, statements);
+    }
+
+    fn visit_typed_expr_fn(
+        &mut self,
+        location: &'ast SrcSpan,
+        type_: &'ast Arc<Type>,
+        kind: &'ast FunctionLiteralKind,
+        args: &'ast [TypedArg],
+        body: &'ast Vec1<TypedStatement>,
+        return_annotation: &'ast Option<ast::TypeAst>,
+    ) {
+        self.check_statements(body);
+        ast::visit::visit_typed_expr_fn(self, location, type_, kind, args, body, return_annotation);
+    }
+
+    fn visit_typed_expr_var(
+        &mut self,
+        location: &'ast SrcSpan,
+        constructor: &'ast ValueConstructor,
+        name: &'ast EcoString,
+    ) {
+        let variable_range = self.edits.src_span_to_lsp_range(*location);
+        if let type_::ValueConstructorVariant::LocalVariable {
+            location: definition,
+            ..
+        } = &constructor.variant
+        {
+            if within(self.params.range, variable_range) {
+                self.selected_variable = Some(*definition);
+            }
+        }
+        ast::visit::visit_typed_expr_var(self, location, constructor, name);
+    }
+}
+
+/// Builder for code action to inline a call to a small private function,
+/// replacing it with the function's body.
+///
+/// ```gleam
+/// pub fn main() {
+///   double(of: 2)
+///   // ^ [inline function call]
+/// }
+///
+/// fn double(of value: Int) -> Int {
+///   value * 2
+/// }
+/// ```
+///
+/// Will become:
+///
+/// ```gleam
+/// pub fn main() {
+///   2 * 2
+/// }
+/// ```
+///
+/// Only functions whose body is a single expression can be inlined. Arguments
+/// with side effects are bound to variables before the body, unless each of
+/// them is used exactly once, unconditionally and in the same order as the
+/// parameters, so that they're still evaluated once and in the same order.
+///
+pub struct InlineFunctionCall<'a> {
+    module: &'a Module,
+    params: &'a CodeActionParams,
+    edits: TextEdits<'a>,
+    current_function: Option<&'a ast::TypedFunction>,
+    inside_capture: bool,
+    selected_call: Option<InlinedCall<'a>>,
+}
+
+struct InlinedCall<'a> {
+    location: SrcSpan,
+    caller: &'a ast::TypedFunction,
+    called: &'a ast::TypedFunction,
+    field_map: Option<&'a FieldMap>,
+    args: &'a [TypedCallArg],
+}
+
+impl<'a> InlineFunctionCall<'a> {
+    pub fn new(
+        module: &'a Module,
+        line_numbers: &'a LineNumbers,
+        params: &'a CodeActionParams,
+    ) -> Self {
+        Self {
+            module,
+            params,
+            edits: TextEdits::new(line_numbers),
+            current_function: None,
+            inside_capture: false,
+            selected_call: None,
+        }
+    }
+
+    pub fn code_actions(mut self) -> Vec<CodeAction> {
+        self.visit_typed_module(&self.module.ast);
+
+        let Some(call) = self.selected_call.take() else {
+            return vec![];
+        };
+        let Some(code) = self.inlined_code(&call) else {
+            return vec![];
+        };
+        self.edits.replace(call.location, code);
+
+        let mut action = Vec::with_capacity(1);
+        CodeActionBuilder::new("Inline function call")
+            .kind(CodeActionKind::REFACTOR_INLINE)
+            .changes(self.params.text_document.uri.clone(), self.edits.edits)
+            .preferred(false)
+            .push_to(&mut action);
+        action
+    }
+
+    /// The body of the called function, with its arguments replaced by the
+    /// ones it's called with.
+    fn inlined_code(&self, call: &InlinedCall<'_>) -> Option<String> {
+        let code = &self.module.code;
+        let [ast::Statement::Expression(body)] = call.called.body.as_slice() else {
+            return None;
+        };
+        let body_location = body.location();
+
+        // A function call that's part of a pipeline or of a `use` is missing
+        // some of its arguments.
+        if call.args.iter().any(|arg| arg.is_implicit()) {
+            return None;
+        }
+
+        // Each argument is matched to its parameter using the function's
+        // field map, the same way missing labels are found, so labelled
+        // arguments can be given in any order. They are kept in the order
+        // they're evaluated in. Every parameter needs exactly one argument.
+        let positions = match call.field_map {
+            Some(field_map) => field_map.argument_positions(call.args),
+            None => (0..call.args.len() as u32).map(Some).collect_vec(),
+        };
+        if call.args.len() != call.called.arguments.len()
+            || !positions.iter().flatten().all_unique()
+        {
+            return None;
+        }
+        let parameters = positions
+            .into_iter()
+            .zip(call.args)
+            .map(|(position, arg)| {
+                let parameter = call.called.arguments.get(position? as usize)?;
+                Some((parameter.get_variable_name(), arg))
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        let mut body_variables = LocalVariables::default();
+        body_variables.visit_typed_expr(body);
+        let uses = |name: Option<&EcoString>| {
+            body_variables
+                .references
+                .iter()
+                .filter(|reference| {
+                    !contains(body_location, reference.definition) && Some(&reference.name) == name
+                })
+                .collect_vec()
+        };
+
+        // An argument with side effects can only replace its parameter if it
+        // still ends up being evaluated exactly once, and in the same order as
+        // the other arguments with side effects. Otherwise all of those are
+        // bound to variables before the body instead.
+        let mut conditional = ConditionalLocations::default();
+        conditional.visit_typed_expr(body);
+        let mut last_use_end = body_location.start;
+        let inline_side_effects = parameters
+            .iter()
+            .filter(|(_, arg)| !arg.value.is_pure_value_constructor())
+            .all(|(name, _)| match uses(*name).as_slice() {
+                [reference]
+                    if !reference.requires_variable()
+                        && !conditional.contains(reference.location)
+                        && reference.location.start >= last_use_end =>
+                {
+                    last_use_end = reference.location.end;
+                    true
+                }
+                _ => false,
+            });
+        let bound = parameters
+            .iter()
+            .filter(|(name, arg)| {
+                if !arg.value.is_pure_value_constructor() {
+                    !inline_side_effects
+                } else {
+                    !matches!(arg.value, TypedExpr::Var { .. })
+                        && uses(*name)
+                            .iter()
+                            .any(|reference| reference.requires_variable())
+                }
+            })
+            .collect_vec();
+        let bound_names = bound
+            .iter()
+            .filter_map(|(name, _)| *name)
+            .collect::<HashSet<_>>();
+
+        let arguments = parameters
+            .iter()
+            .filter_map(|(name, arg)| {
+                Some((name.filter(|name| !bound_names.contains(name))?, *arg))
+            })
+            .collect::<HashMap<_, _>>();
+        let parameter_references = body_variables
+            .references
+            .iter()
+            .filter(|reference| {
+                !contains(body_location, reference.definition)
+                    && arguments.contains_key(&reference.name)
+            })
+            .collect_vec();
+
+        // The arguments must not refer to variables bound in the body, and the
+        // module values used in the body must not be shadowed by variables of
+        // the calling function.
+        let mut arguments_variables = LocalVariables::default();
+        call.args
+            .iter()
+            .for_each(|arg| arguments_variables.visit_typed_expr(&arg.value));
+        let mut body_bound_variables = BoundVariables::default();
+        body_bound_variables.visit_typed_expr(body);
+        let body_bound_names = body_bound_variables.names();
+        if arguments_variables.references.iter().any(|reference| {
+            body_bound_names.contains(&reference.name) || bound_names.contains(&reference.name)
+        }) {
+            return None;
+        }
+        let mut caller_bound_variables = BoundVariables::default();
+        caller_bound_variables.visit_typed_function(call.caller);
+        if !caller_bound_variables
+            .names()
+            .is_disjoint(&body_variables.module_values)
+        {
+            return None;
+        }
+
+        let mut body_operands = OperandLocations::default();
+        body_operands.visit_typed_expr(body);
+        let mut inlined = String::new();
+        let mut last_end = body_location.start;
+        for reference in parameter_references
+            .iter()
+            .sorted_by_key(|reference| reference.location.start)
+        {
+            let arg = arguments.get(&reference.name)?;
+            let arg_code = self.argument_code(arg)?;
+            let arg_code = if body_operands.needs_grouping(reference.location, &arg.value) {
+                format!("{{ {arg_code} }}")
+            } else {
+                arg_code
+            };
+            let arg_code = if reference.label_shorthand {
+                format!("{}: {arg_code}", reference.name)
+            } else {
+                arg_code
+            };
+
+            inlined.push_str(code.get(last_end as usize..reference.location.start as usize)?);
+            inlined.push_str(&arg_code);
+            last_end = reference.location.end;
+        }
+        inlined.push_str(code.get(last_end as usize..body_location.end as usize)?);
+
+        let indentation = " ".repeat(line_indentation(code, call.location.start));
+        if !bound.is_empty() {
+            let inner_indentation = format!("{indentation}  ");
+            let mut block = String::from("{\n");
+            for (name, arg) in bound {
+                let value = reindent(
+                    &self.argument_code(arg)?,
+                    line_indentation(code, arg.value.location().start),
+                    &inner_indentation,
+                );
+                let name = name.map_or("_", |name| name.as_str());
+                block.push_str(&format!("{inner_indentation}let {name} = {value}\n"));
+            }
+            let inlined = reindent(
+                &inlined,
+                line_indentation(code, body_location.start),
+                &inner_indentation,
+            );
+            block.push_str(&format!("{inner_indentation}{inlined}\n{indentation}}}"));
+            return Some(block);
+        }
+
+        let inlined = reindent(
+            &inlined,
+            line_indentation(code, body_location.start),
+            &indentation,
+        );
+
+        let mut caller_operands = OperandLocations::default();
+        caller_operands.visit_typed_function(call.caller);
+        if caller_operands.needs_grouping(call.location, body) {
+            Some(format!("{{ {inlined} }}"))
+        } else {
+            Some(inlined)
+        }
+    }
+
+    /// The code of an argument, as it's written where the function is called.
+    fn argument_code(&self, arg: &TypedCallArg) -> Option<String> {
+        if arg.uses_label_shorthand() {
+            return Some(arg.label.clone()?.to_string());
+        }
+        let location = arg.value.location();
+        self.module
+            .code
+            .get(location.start as usize..location.end as usize)
+            .map(String::from)
+    }
+}
+
+impl<'ast> ast::visit::Visit<'ast> for InlineFunctionCall<'ast> {
+    fn visit_typed_function(&mut self, fun: &'ast ast::TypedFunction) {
+        self.current_function = Some(fun);
+        ast::visit::visit_typed_function(self, fun);
+        self.current_function = None;
+    }
+
+    fn visit_typed_expr_fn(
+        &mut self,
+        location: &'ast SrcSpan,
+        type_: &'ast Arc<Type>,
+        kind: &'ast FunctionLiteralKind,
+        args: &'ast [TypedArg],
+        body: &'ast Vec1<TypedStatement>,
+        return_annotation: &'ast Option<ast::TypeAst>,
+    ) {
+        // The body of a capture is a call with one of its arguments missing,
+        // so it can't be inlined.
+        self.inside_capture = kind.is_capture();
+        ast::visit::visit_typed_expr_fn(self, location, type_, kind, args, body, return_annotation);
+    }
+
+    fn visit_typed_expr_call(
+        &mut self,
+        location: &'ast SrcSpan,
+        type_: &'ast Arc<Type>,
+        fun: &'ast TypedExpr,
+        args: &'ast [TypedCallArg],
+    ) {
+        let is_capture_call = std::mem::take(&mut self.inside_capture);
+        let call_range = self.edits.src_span_to_lsp_range(*location);
+        if !within(self.params.range, call_range) {
+            return;
+        }
+
+        let called = match fun {
+            TypedExpr::Var {
+                constructor:
+                    ValueConstructor {
+                        variant:
+                            type_::ValueConstructorVariant::ModuleFn {
+                                module,
+                                location: definition,
+                                ..
+                            },
+                        ..
+                    },
+                ..
+            } if *module == self.module.name => self.private_function(*definition),
+            _ => None,
+        };
+        if let (Some(called), Some(caller), false) =
+            (called, self.current_function, is_capture_call)
+        {
+            self.selected_call = Some(InlinedCall {
+                location: *location,
+                caller,
+                called,
+                field_map: fun.field_map(),
+                args,
+            });
+        }
+
+        // We only want to inline the innermost call containing the selection
+        // so we keep looking inside its arguments.
+        ast::visit::visit_typed_expr_call(self, location, type_, fun, args);
+    }
+}
+
+impl<'a> InlineFunctionCall<'a> {
+    /// The module's private function defined at the given location, if it
+    /// doesn't use any external implementation.
+    fn private_function(&self, location: SrcSpan) -> Option<&'a ast::TypedFunction> {
+        self.module
+            .ast
+            .definitions
+            .iter()
+            .find_map(|definition| match definition {
+                ast::Definition::Function(function) if function.location == location => {
+                    Some(function)
+                }
+                _ => None,
+            })
+            .filter(|function| {
+                function.publicity.is_private()
+                    && function.external_erlang.is_none()
+                    && function.external_javascript.is_none()
+                    && function.external_go.is_none()
+            })
+    }
+}
+
 struct NameGenerator {
     used_names: HashSet<EcoString>,
 }
@@ -0,0 +1,181 @@
+use camino::{Utf8Path, Utf8PathBuf};
+use ecow::EcoString;
+use itertools::Itertools;
+use lsp_types::{CodeLens, Command};
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+
+use crate::{
+    ast::{Definition, TypedFunction},
+    build::{Module, Origin, Target},
+    line_numbers::LineNumbers,
+};
+
+use super::src_span_to_lsp_range;
+
+/// The command the run code lenses are backed by, handled by the server when
+/// the editor sends a `workspace/executeCommand` request.
+pub const RUN_COMMAND: &str = "gleam.run";
+
+/// What a run code lens runs, sent by the editor as the only argument of the
+/// run command.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunArguments {
+    /// The root directory of the project, as the command isn't sent along with
+    /// any document to tell which project it's for.
+    pub root: Utf8PathBuf,
+    #[serde(flatten)]
+    pub kind: RunKind,
+    pub target: Target,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", tag = "kind")]
+pub enum RunKind {
+    /// The `main` function of a module, run with `gleam run`.
+    Main { module: EcoString },
+    /// All the project's tests, run with `gleam test`. The test runner always
+    /// runs all the tests so there's no way to run a single one.
+    Tests,
+}
+
+impl RunArguments {
+    pub fn from_arguments(arguments: &[serde_json::Value]) -> Option<Self> {
+        match arguments {
+            [argument] => serde_json::from_value(argument.clone()).ok(),
+            _ => None,
+        }
+    }
+
+    /// The arguments to pass to the `gleam` executable.
+    pub fn command_line(&self) -> Vec<String> {
+        let target = self.target.to_string();
+        match &self.kind {
+            RunKind::Main { module } => vec![
+                "run".into(),
+                "--target".into(),
+                target,
+                "--module".into(),
+                module.to_string(),
+            ],
+            RunKind::Tests => vec!["test".into(), "--target".into(), target],
+        }
+    }
+}
+
+/// Returns the code lenses to run the module's `main` function and, for a test
+/// module, the project's tests.
+///
+/// The first lens runs on the target the project is compiled for, which is
+/// the only one the module has been type checked for. The others run on any
+/// other target the function supports, taking into account its externals and
+/// its `@target` attribute.
+///
+/// Each public test function gets its own lenses too. The test runner always
+/// runs all the tests though, so these run the whole suite, and only offer the
+/// targets all of the module's tests can run on.
+pub fn run_lenses(module: &Module, root: &Utf8Path, target: Target) -> Vec<CodeLens> {
+    let line_numbers = LineNumbers::new(&module.code);
+    let arguments = |kind: RunKind, target| RunArguments {
+        root: root.to_path_buf(),
+        kind,
+        target,
+    };
+
+    let mut lenses = vec![];
+    let mut tests = vec![];
+    for definition in &module.ast.definitions {
+        let Definition::Function(function) = definition else {
+            continue;
+        };
+        match run_kind(module, function) {
+            Some(RunKind::Tests) => tests.push(function),
+            Some(kind @ RunKind::Main { .. }) => {
+                let range = src_span_to_lsp_range(function.location, &line_numbers);
+                lenses.push(run_lens(range, "Run", arguments(kind.clone(), target)));
+                for other in other_targets(module, &[function], target) {
+                    let title = format!("Run on {}", target_name(other));
+                    lenses.push(run_lens(range, &title, arguments(kind.clone(), other)));
+                }
+            }
+            None => {}
+        }
+    }
+
+    let test_targets = other_targets(module, &tests, target).collect_vec();
+    for test in &tests {
+        let range = src_span_to_lsp_range(test.location, &line_numbers);
+        lenses.push(run_lens(
+            range,
+            "Run all tests",
+            arguments(RunKind::Tests, target),
+        ));
+        for other in &test_targets {
+            let title = format!("Run all tests on {}", target_name(*other));
+            lenses.push(run_lens(range, &title, arguments(RunKind::Tests, *other)));
+        }
+    }
+    lenses
+}
+
+/// The targets other than the project's one that all the given functions can
+/// run on. A function with a `@target` attribute only exists for the project's
+/// target.
+fn other_targets<'a>(
+    module: &'a Module,
+    functions: &'a [&TypedFunction],
+    target: Target,
+) -> impl Iterator<Item = Target> + 'a {
+    let any_targeted = functions.iter().any(|function| {
+        module
+            .extra
+            .targeted_functions
+            .iter()
+            .any(|(start, _)| *start == function.location.start)
+    });
+    Target::iter().filter(move |other| {
+        *other != target
+            && !any_targeted
+            && functions
+                .iter()
+                .all(|function| function.implementations.supports(*other))
+    })
+}
+
+fn run_kind(module: &Module, function: &TypedFunction) -> Option<RunKind> {
+    let (_, name) = function.name.as_ref()?;
+    if !function.publicity.is_public() || !function.arguments.is_empty() {
+        return None;
+    }
+    if name == "main" {
+        Some(RunKind::Main {
+            module: module.name.clone(),
+        })
+    } else if module.origin == Origin::Test && name.ends_with("_test") {
+        Some(RunKind::Tests)
+    } else {
+        None
+    }
+}
+
+fn run_lens(range: lsp_types::Range, title: &str, arguments: RunArguments) -> CodeLens {
+    let argument = serde_json::to_value(arguments).expect("run arguments serialisation");
+    CodeLens {
+        range,
+        command: Some(Command {
+            title: title.into(),
+            command: RUN_COMMAND.into(),
+            arguments: Some(vec![argument]),
+        }),
+        data: None,
+    }
+}
+
+fn target_name(target: Target) -> &'static str {
+    match target {
+        Target::Erlang => "Erlang",
+        Target::JavaScript => "JavaScript",
+        Target::Go => "Go",
+    }
+}
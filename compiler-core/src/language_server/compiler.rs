@@ -72,6 +72,7 @@ where
             compile: build::Compile::All,
             root_target_support: TargetSupport::Enforced,
             no_print_progress: false,
+            entrypoint: None,
         };
         let mut project_compiler = ProjectCompiler::new(
             config,
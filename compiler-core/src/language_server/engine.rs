@@ -27,19 +27,27 @@ use lsp_types::{
     PrepareRenameResponse, Range, SignatureHelp, SymbolKind, SymbolTag, TextEdit, Url,
     WorkspaceEdit,
 };
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use super::{
+    call_hierarchy::{calls, ItemData},
     code_action::{
         code_action_add_missing_patterns, code_action_convert_qualified_constructor_to_unqualified,
         code_action_convert_unqualified_constructor_to_qualified, code_action_import_module,
         code_action_inexhaustive_let_to_case, AddAnnotations, CodeActionBuilder, DesugarUse,
-        ExpandFunctionCapture, ExtractVariable, FillInMissingLabelledArgs, GenerateDynamicDecoder,
-        GenerateFunction, LetAssertToCase, PatternMatchOnValue, RedundantTupleInCaseSubject,
-        TurnIntoUse, UseLabelShorthandSyntax,
+        ExpandFunctionCapture, ExtractFunction, ExtractVariable, FillInMissingLabelledArgs,
+        GenerateDynamicDecoder, GenerateFunction, InlineFunctionCall, InlineVariable,
+        LetAssertToCase, PatternMatchOnValue, RedundantTupleInCaseSubject, TurnIntoUse,
+        UseLabelShorthandSyntax,
     },
+    code_lens::run_lenses,
     completer::Completer,
-    rename::{rename_local_variable, VariableRenameKind},
+    implementation::{externals_at, find_implementations},
+    inlay_hints::get_inlay_hints,
+    path_to_uri,
+    references::{find_references, referenced, Referenced},
+    rename::{rename_local_variable, rename_module_definition, VariableRenameKind},
+    semantic_tokens::semantic_tokens,
     signature_help, src_span_to_lsp_range, DownloadDependencies, MakeLocker,
 };
 
@@ -206,6 +214,91 @@ where
         })
     }
 
+    pub fn goto_type_definition(
+        &mut self,
+        params: lsp::request::GotoTypeDefinitionParams,
+    ) -> Response<Option<lsp::GotoDefinitionResponse>> {
+        self.respond(|this| {
+            let params = params.text_document_position_params;
+            let Some((_, node)) = this.node_at_position(&params) else {
+                return Ok(None);
+            };
+            let Some(type_) = node.type_() else {
+                return Ok(None);
+            };
+
+            // The type itself comes first, followed by the types it's made of
+            // so that it's possible to jump to `Wibble` from a `List(Wibble)`.
+            let mut types = vec![];
+            named_types(type_, &mut types);
+
+            let importable_modules = this.compiler.project_compiler.get_importable_modules();
+            let mut locations = vec![];
+            for type_ in types {
+                let Some(constructor) = type_constructor_from_modules(importable_modules, type_)
+                else {
+                    continue;
+                };
+                // Prelude types have no source to jump to.
+                let Some(module) = this.compiler.get_source(&constructor.module) else {
+                    continue;
+                };
+                let uri = Url::parse(&format!("file:///{}", &module.path))
+                    .expect("goto type definition URL parse");
+                let range = src_span_to_lsp_range(constructor.origin, &module.line_numbers);
+                locations.push(lsp::Location { uri, range });
+            }
+
+            Ok(match locations.len() {
+                0 => None,
+                1 => locations.pop().map(lsp::GotoDefinitionResponse::Scalar),
+                _ => Some(lsp::GotoDefinitionResponse::Array(locations)),
+            })
+        })
+    }
+
+    pub fn goto_implementation(
+        &mut self,
+        params: lsp::request::GotoImplementationParams,
+    ) -> Response<Option<lsp::GotoDefinitionResponse>> {
+        self.respond(|this| {
+            let params = params.text_document_position_params;
+            let Some((line_numbers, node)) = this.node_at_position(&params) else {
+                return Ok(None);
+            };
+            let Some(module) = this.module_for_uri(&params.text_document.uri) else {
+                return Ok(None);
+            };
+            let byte_index =
+                line_numbers.byte_index(params.position.line, params.position.character);
+            let importable_modules = this.compiler.project_compiler.get_importable_modules();
+            let Some((defining_module, externals)) =
+                externals_at(&node, byte_index, module, importable_modules)
+            else {
+                return Ok(None);
+            };
+
+            // Only the native files of this package can be searched, those of
+            // dependencies are not available to the language server.
+            let Some(defining_module) = this.compiler.modules.get(&defining_module) else {
+                return Ok(None);
+            };
+            let locations = find_implementations(
+                &this.compiler.project_compiler.io,
+                &this.paths,
+                &this.compiler.project_compiler.config.name,
+                defining_module,
+                &externals,
+            );
+
+            if locations.is_empty() {
+                Ok(None)
+            } else {
+                Ok(Some(lsp::GotoDefinitionResponse::Array(locations)))
+            }
+        })
+    }
+
     pub fn completion(
         &mut self,
         params: lsp::TextDocumentPositionParams,
@@ -337,7 +430,10 @@ where
             actions.extend(TurnIntoUse::new(module, &lines, &params).code_actions());
             actions.extend(ExpandFunctionCapture::new(module, &lines, &params).code_actions());
             actions.extend(ExtractVariable::new(module, &lines, &params).code_actions());
+            actions.extend(ExtractFunction::new(module, &lines, &params).code_actions());
             actions.extend(GenerateFunction::new(module, &lines, &params).code_actions());
+            actions.extend(InlineVariable::new(module, &lines, &params).code_actions());
+            actions.extend(InlineFunctionCall::new(module, &lines, &params).code_actions());
             actions.extend(
                 PatternMatchOnValue::new(module, &lines, &params, &this.compiler).code_actions(),
             );
@@ -356,143 +452,134 @@ where
         params: lsp::DocumentSymbolParams,
     ) -> Response<Vec<DocumentSymbol>> {
         self.respond(|this| {
-            let mut symbols = vec![];
             let Some(module) = this.module_for_uri(&params.text_document.uri) else {
-                return Ok(symbols);
+                return Ok(vec![]);
+            };
+            Ok(document_symbols(module))
+        })
+    }
+
+    pub fn inlay_hints(&mut self, params: lsp::InlayHintParams) -> Response<Vec<lsp::InlayHint>> {
+        self.respond(|this| {
+            let Some(module) = this.module_for_uri(&params.text_document.uri) else {
+                return Ok(vec![]);
             };
             let line_numbers = LineNumbers::new(&module.code);
+            Ok(get_inlay_hints(module, &line_numbers, params.range))
+        })
+    }
 
-            for definition in &module.ast.definitions {
-                match definition {
-                    // Typically, imports aren't considered document symbols.
-                    Definition::Import(_) => {}
-
-                    Definition::Function(function) => {
-                        // By default, the function's location ends right after the return type.
-                        // For the full symbol range, have it end at the end of the body.
-                        // Also include the documentation, if available.
-                        //
-                        // By convention, the symbol span starts from the leading slash in the
-                        // documentation comment's marker ('///'), not from its content (of which
-                        // we have the position), so we must convert the content start position
-                        // to the leading slash's position using 'get_doc_marker_pos'.
-                        let full_function_span = SrcSpan {
-                            start: function
-                                .documentation
-                                .as_ref()
-                                .map(|(doc_start, _)| get_doc_marker_pos(*doc_start))
-                                .unwrap_or(function.location.start),
-
-                            end: function.end_position,
-                        };
-
-                        let (name_location, name) = function
-                            .name
-                            .as_ref()
-                            .expect("Function in a definition must be named");
-
-                        // The 'deprecated' field is deprecated, but we have to specify it anyway
-                        // to be able to construct the 'DocumentSymbol' type, so
-                        // we suppress the warning. We specify 'None' as specifying 'Some'
-                        // is what is actually deprecated.
-                        #[allow(deprecated)]
-                        symbols.push(DocumentSymbol {
-                            name: name.to_string(),
-                            detail: Some(
-                                Printer::new(&module.ast.names)
-                                    .print_type(&get_function_type(function))
-                                    .to_string(),
-                            ),
-                            kind: SymbolKind::FUNCTION,
-                            tags: make_deprecated_symbol_tag(&function.deprecation),
-                            deprecated: None,
-                            range: src_span_to_lsp_range(full_function_span, &line_numbers),
-                            selection_range: src_span_to_lsp_range(*name_location, &line_numbers),
-                            children: None,
-                        });
-                    }
+    pub fn code_lens(&mut self, params: lsp::CodeLensParams) -> Response<Vec<lsp::CodeLens>> {
+        self.respond(|this| {
+            let Some(module) = this.module_for_uri(&params.text_document.uri) else {
+                return Ok(vec![]);
+            };
+            let target = this.compiler.project_compiler.config.target;
+            Ok(run_lenses(module, this.paths.root(), target))
+        })
+    }
 
-                    Definition::TypeAlias(alias) => {
-                        let full_alias_span = match alias.documentation {
-                            Some((doc_position, _)) => {
-                                SrcSpan::new(get_doc_marker_pos(doc_position), alias.location.end)
-                            }
-                            None => alias.location,
-                        };
-
-                        // The 'deprecated' field is deprecated, but we have to specify it anyway
-                        // to be able to construct the 'DocumentSymbol' type, so
-                        // we suppress the warning. We specify 'None' as specifying 'Some'
-                        // is what is actually deprecated.
-                        #[allow(deprecated)]
-                        symbols.push(DocumentSymbol {
-                            name: alias.alias.to_string(),
-                            detail: Some(
-                                Printer::new(&module.ast.names)
-                                    // If we print with aliases, we end up printing the alias which the user
-                                    // is currently hovering, which is not helpful. Instead, we print the
-                                    // raw type, so the user can see which type the alias represents
-                                    .print_type_without_aliases(&alias.type_)
-                                    .to_string(),
-                            ),
-                            kind: SymbolKind::CLASS,
-                            tags: make_deprecated_symbol_tag(&alias.deprecation),
-                            deprecated: None,
-                            range: src_span_to_lsp_range(full_alias_span, &line_numbers),
-                            selection_range: src_span_to_lsp_range(
-                                alias.name_location,
-                                &line_numbers,
-                            ),
-                            children: None,
-                        });
-                    }
+    pub fn semantic_tokens_full(
+        &mut self,
+        params: lsp::SemanticTokensParams,
+    ) -> Response<Option<lsp::SemanticTokens>> {
+        self.semantic_tokens(&params.text_document.uri, None)
+    }
 
-                    Definition::CustomType(type_) => {
-                        symbols.push(custom_type_symbol(type_, &line_numbers, module));
-                    }
+    pub fn semantic_tokens_range(
+        &mut self,
+        params: lsp::SemanticTokensRangeParams,
+    ) -> Response<Option<lsp::SemanticTokens>> {
+        self.semantic_tokens(&params.text_document.uri, Some(params.range))
+    }
 
-                    Definition::ModuleConstant(constant) => {
-                        // `ModuleConstant.location` ends at the constant's name or type.
-                        // For the full symbol span, necessary for `range`, we need to
-                        // include the constant value as well.
-                        // Also include the documentation at the start, if available.
-                        let full_constant_span = SrcSpan {
-                            start: constant
-                                .documentation
-                                .as_ref()
-                                .map(|(doc_start, _)| get_doc_marker_pos(*doc_start))
-                                .unwrap_or(constant.location.start),
-
-                            end: constant.value.location().end,
-                        };
-
-                        // The 'deprecated' field is deprecated, but we have to specify it anyway
-                        // to be able to construct the 'DocumentSymbol' type, so
-                        // we suppress the warning. We specify 'None' as specifying 'Some'
-                        // is what is actually deprecated.
-                        #[allow(deprecated)]
-                        symbols.push(DocumentSymbol {
-                            name: constant.name.to_string(),
-                            detail: Some(
-                                Printer::new(&module.ast.names)
-                                    .print_type(&constant.type_)
-                                    .to_string(),
-                            ),
-                            kind: SymbolKind::CONSTANT,
-                            tags: make_deprecated_symbol_tag(&constant.deprecation),
-                            deprecated: None,
-                            range: src_span_to_lsp_range(full_constant_span, &line_numbers),
-                            selection_range: src_span_to_lsp_range(
-                                constant.name_location,
-                                &line_numbers,
-                            ),
-                            children: None,
-                        });
+    fn semantic_tokens(
+        &mut self,
+        uri: &Url,
+        range: Option<Range>,
+    ) -> Response<Option<lsp::SemanticTokens>> {
+        self.respond(|this| {
+            let Some(module) = this.module_for_uri(uri) else {
+                return Ok(None);
+            };
+            let line_numbers = LineNumbers::new(&module.code);
+            let importable_modules = this.compiler.project_compiler.get_importable_modules();
+            Ok(Some(semantic_tokens(
+                module,
+                &line_numbers,
+                importable_modules,
+                range,
+            )))
+        })
+    }
+
+    pub fn workspace_symbol(
+        &mut self,
+        params: lsp::WorkspaceSymbolParams,
+    ) -> Response<Vec<lsp::SymbolInformation>> {
+        self.respond(|this| {
+            let mut modules = this
+                .compiler
+                .modules
+                .values()
+                .map(|module| {
+                    let uri = path_to_uri(module.input_path.clone());
+                    (module.name.clone(), uri, document_symbols(module))
+                })
+                .collect_vec();
+
+            // Modules of dependencies are only known through their interfaces.
+            for (name, interface) in this.compiler.project_compiler.get_importable_modules() {
+                if this.compiler.modules.contains_key(name) {
+                    continue;
+                }
+                let Some(source) = this.compiler.get_source(name) else {
+                    continue;
+                };
+                let uri = Url::parse(&format!("file:///{}", &source.path))
+                    .expect("workspace symbol URL parse");
+                let symbols = interface_symbols(interface, &source.line_numbers);
+                modules.push((name.clone(), uri, symbols));
+            }
+
+            let mut matches = vec![];
+            for (module_name, uri, symbols) in modules {
+                let location = |range| lsp::Location {
+                    uri: uri.clone(),
+                    range,
+                };
+
+                for symbol in symbols {
+                    // The constructors of a custom type are its children. Their
+                    // own children, the labelled fields, aren't searched.
+                    for constructor in symbol.children.iter().flatten() {
+                        if let Some(score) = fuzzy_match(&params.query, &constructor.name) {
+                            let symbol = symbol_information(
+                                constructor,
+                                location(constructor.range),
+                                &symbol.name,
+                            );
+                            matches.push((score, module_name.clone(), symbol));
+                        }
+                    }
+                    if let Some(score) = fuzzy_match(&params.query, &symbol.name) {
+                        let symbol =
+                            symbol_information(&symbol, location(symbol.range), &module_name);
+                        matches.push((score, module_name.clone(), symbol));
                     }
                 }
             }
 
-            Ok(symbols)
+            // The best matches come first, then shorter names.
+            matches.sort_by(|(score_a, module_a, a), (score_b, module_b, b)| {
+                score_b
+                    .cmp(score_a)
+                    .then_with(|| a.name.len().cmp(&b.name.len()))
+                    .then_with(|| a.name.cmp(&b.name))
+                    .then_with(|| module_a.cmp(module_b))
+                    .then_with(|| a.location.range.start.cmp(&b.location.range.start))
+            });
+            Ok(matches.into_iter().map(|(_, _, symbol)| symbol).collect())
         })
     }
 
@@ -501,7 +588,7 @@ where
         params: lsp::TextDocumentPositionParams,
     ) -> Response<Option<PrepareRenameResponse>> {
         self.respond(|this| {
-            let (_, found) = match this.node_at_position(&params) {
+            let (lines, found) = match this.node_at_position(&params) {
                 Some(value) => value,
                 None => return Ok(None),
             };
@@ -541,7 +628,24 @@ where
                     ArgNames::Named { .. } | ArgNames::NamedLabelled { .. } => success_response,
                     ArgNames::Discard { .. } | ArgNames::LabelledDiscard { .. } => None,
                 },
-                _ => None,
+                found => {
+                    let Some(module) = this.module_for_uri(&params.text_document.uri) else {
+                        return Ok(None);
+                    };
+                    let byte_index =
+                        lines.byte_index(params.position.line, params.position.character);
+                    let importable_modules =
+                        this.compiler.project_compiler.get_importable_modules();
+                    // Only definitions in the root package can be renamed.
+                    match referenced(&found, byte_index, module, importable_modules) {
+                        Some(referenced)
+                            if this.compiler.modules.contains_key(referenced.module()) =>
+                        {
+                            success_response
+                        }
+                        Some(_) | None => None,
+                    }
+                }
             })
         })
     }
@@ -635,11 +739,256 @@ where
                     ),
                     ArgNames::Discard { .. } | ArgNames::LabelledDiscard { .. } => None,
                 },
-                _ => None,
+                found => {
+                    let byte_index =
+                        lines.byte_index(position.position.line, position.position.character);
+                    let importable_modules =
+                        this.compiler.project_compiler.get_importable_modules();
+                    referenced(&found, byte_index, module, importable_modules).and_then(
+                        |referenced| {
+                            rename_module_definition(
+                                &this.compiler.modules,
+                                importable_modules,
+                                &params,
+                                &referenced,
+                            )
+                        },
+                    )
+                }
             })
         })
     }
 
+    pub fn find_references(
+        &mut self,
+        params: lsp::ReferenceParams,
+    ) -> Response<Option<Vec<lsp::Location>>> {
+        self.respond(|this| {
+            let position = &params.text_document_position;
+
+            let (lines, found) = match this.node_at_position(position) {
+                Some(value) => value,
+                None => return Ok(None),
+            };
+
+            let Some(module) = this.module_for_uri(&position.text_document.uri) else {
+                return Ok(None);
+            };
+
+            let importable_modules = this.compiler.project_compiler.get_importable_modules();
+            let byte_index = lines.byte_index(position.position.line, position.position.character);
+            let Some(referenced) = referenced(&found, byte_index, module, importable_modules)
+            else {
+                return Ok(None);
+            };
+
+            // Every module of the root package is searched, including the
+            // test modules.
+            let mut locations = vec![];
+            for module in this
+                .compiler
+                .modules
+                .values()
+                .sorted_by_key(|module| &module.name)
+            {
+                let references = find_references(
+                    &referenced,
+                    module,
+                    importable_modules,
+                    params.context.include_declaration,
+                );
+                if references.is_empty() {
+                    continue;
+                }
+                let uri = path_to_uri(module.input_path.clone());
+                let line_numbers = LineNumbers::new(&module.code);
+                locations.extend(references.into_iter().map(|location| lsp::Location {
+                    uri: uri.clone(),
+                    range: src_span_to_lsp_range(location, &line_numbers),
+                }));
+            }
+
+            Ok(Some(locations))
+        })
+    }
+
+    pub fn prepare_call_hierarchy(
+        &mut self,
+        params: lsp::CallHierarchyPrepareParams,
+    ) -> Response<Option<Vec<lsp::CallHierarchyItem>>> {
+        self.respond(|this| {
+            let position = &params.text_document_position_params;
+            let Some((lines, found)) = this.node_at_position(position) else {
+                return Ok(None);
+            };
+            let Some(module) = this.module_for_uri(&position.text_document.uri) else {
+                return Ok(None);
+            };
+
+            let importable_modules = this.compiler.project_compiler.get_importable_modules();
+            let byte_index = lines.byte_index(position.position.line, position.position.character);
+            let Some(Referenced::Value { module, location }) =
+                referenced(&found, byte_index, module, importable_modules)
+            else {
+                return Ok(None);
+            };
+
+            Ok(this
+                .call_hierarchy_item(&module, location)
+                .map(|item| vec![item]))
+        })
+    }
+
+    pub fn incoming_calls(
+        &mut self,
+        params: lsp::CallHierarchyIncomingCallsParams,
+    ) -> Response<Option<Vec<lsp::CallHierarchyIncomingCall>>> {
+        self.respond(|this| {
+            let Some(data) = ItemData::from_item(&params.item) else {
+                return Ok(None);
+            };
+            let called = data.function();
+
+            // Every module of the root package is searched, including the
+            // test modules.
+            let mut incoming = vec![];
+            for module in this
+                .compiler
+                .modules
+                .values()
+                .sorted_by_key(|module| &module.name)
+            {
+                let line_numbers = LineNumbers::new(&module.code);
+                for definition in &module.ast.definitions {
+                    let Definition::Function(function) = definition else {
+                        continue;
+                    };
+                    let from_ranges = calls(function)
+                        .into_iter()
+                        .filter(|(function, _)| *function == called)
+                        .map(|(_, location)| src_span_to_lsp_range(location, &line_numbers))
+                        .collect_vec();
+                    if from_ranges.is_empty() {
+                        continue;
+                    }
+                    let Some(from) = this.call_hierarchy_item(&module.name, function.location)
+                    else {
+                        continue;
+                    };
+                    incoming.push(lsp::CallHierarchyIncomingCall { from, from_ranges });
+                }
+            }
+
+            Ok(Some(incoming))
+        })
+    }
+
+    pub fn outgoing_calls(
+        &mut self,
+        params: lsp::CallHierarchyOutgoingCallsParams,
+    ) -> Response<Option<Vec<lsp::CallHierarchyOutgoingCall>>> {
+        self.respond(|this| {
+            let Some(data) = ItemData::from_item(&params.item) else {
+                return Ok(None);
+            };
+
+            // Only the functions of the root package have a body that can be
+            // searched for calls.
+            let Some(module) = this.compiler.modules.get(&data.module) else {
+                return Ok(None);
+            };
+            let Some(function) = module_function(module, data.location) else {
+                return Ok(None);
+            };
+
+            // A function called multiple times is shown once, with all the
+            // places it's called from.
+            let line_numbers = LineNumbers::new(&module.code);
+            let mut called_functions: Vec<(Referenced, Vec<Range>)> = vec![];
+            for (called, location) in calls(function) {
+                let range = src_span_to_lsp_range(location, &line_numbers);
+                match called_functions
+                    .iter_mut()
+                    .find(|(other, _)| *other == called)
+                {
+                    Some((_, ranges)) => ranges.push(range),
+                    None => called_functions.push((called, vec![range])),
+                }
+            }
+
+            let outgoing = called_functions
+                .into_iter()
+                .filter_map(|(called, from_ranges)| {
+                    let Referenced::Value { module, location } = called else {
+                        return None;
+                    };
+                    let to = this.call_hierarchy_item(&module, location)?;
+                    Some(lsp::CallHierarchyOutgoingCall { to, from_ranges })
+                })
+                .collect();
+
+            Ok(Some(outgoing))
+        })
+    }
+
+    /// The call hierarchy item for the module function defined at the given
+    /// location, if there is one.
+    fn call_hierarchy_item(
+        &self,
+        module_name: &EcoString,
+        location: SrcSpan,
+    ) -> Option<lsp::CallHierarchyItem> {
+        let data = ItemData {
+            root: self.paths.root().to_path_buf(),
+            module: module_name.clone(),
+            location,
+        };
+
+        // Functions of the root package have their full definition available,
+        // for those from dependencies there's only the location of their head.
+        if let Some(module) = self.compiler.modules.get(module_name) {
+            let function = module_function(module, location)?;
+            let (name_location, name) = function.name.as_ref()?;
+            let line_numbers = LineNumbers::new(&module.code);
+            return Some(lsp::CallHierarchyItem {
+                name: name.to_string(),
+                kind: SymbolKind::FUNCTION,
+                tags: make_deprecated_symbol_tag(&function.deprecation),
+                detail: Some(module_name.to_string()),
+                uri: path_to_uri(module.input_path.clone()),
+                range: src_span_to_lsp_range(function.full_location(), &line_numbers),
+                selection_range: src_span_to_lsp_range(*name_location, &line_numbers),
+                data: Some(data.to_json()),
+            });
+        }
+
+        let module = self
+            .compiler
+            .project_compiler
+            .get_importable_modules()
+            .get(module_name)?;
+        let (name, value) = module.values.iter().find(|(_, value)| {
+            matches!(
+                value.variant,
+                ValueConstructorVariant::ModuleFn { location: definition, .. } if definition == location
+            )
+        })?;
+        let source = self.compiler.get_source(module_name)?;
+        let uri =
+            Url::parse(&format!("file:///{}", &source.path)).expect("call hierarchy URL parse");
+        let range = src_span_to_lsp_range(location, &source.line_numbers);
+        Some(lsp::CallHierarchyItem {
+            name: name.to_string(),
+            kind: SymbolKind::FUNCTION,
+            tags: make_deprecated_symbol_tag(&value.deprecation),
+            detail: Some(module_name.to_string()),
+            uri,
+            range,
+            selection_range: range,
+            data: Some(data.to_json()),
+        })
+    }
+
     fn respond<T>(&mut self, handler: impl FnOnce(&mut Self) -> Result<T>) -> Response<T> {
         let result = handler(self);
         let warnings = self.take_warnings();
@@ -840,6 +1189,288 @@ Unused labelled fields:
     }
 }
 
+/// The symbols for the definitions of a module, as shown in the outline of
+/// its document.
+fn document_symbols(module: &Module) -> Vec<DocumentSymbol> {
+    let mut symbols = vec![];
+    let line_numbers = LineNumbers::new(&module.code);
+
+    for definition in &module.ast.definitions {
+        match definition {
+            // Typically, imports aren't considered document symbols.
+            Definition::Import(_) => {}
+
+            Definition::Function(function) => {
+                // By default, the function's location ends right after the return type.
+                // For the full symbol range, have it end at the end of the body.
+                // Also include the documentation, if available.
+                //
+                // By convention, the symbol span starts from the leading slash in the
+                // documentation comment's marker ('///'), not from its content (of which
+                // we have the position), so we must convert the content start position
+                // to the leading slash's position using 'get_doc_marker_pos'.
+                let full_function_span = SrcSpan {
+                    start: function
+                        .documentation
+                        .as_ref()
+                        .map(|(doc_start, _)| get_doc_marker_pos(*doc_start))
+                        .unwrap_or(function.location.start),
+
+                    end: function.end_position,
+                };
+
+                let (name_location, name) = function
+                    .name
+                    .as_ref()
+                    .expect("Function in a definition must be named");
+
+                // The 'deprecated' field is deprecated, but we have to specify it anyway
+                // to be able to construct the 'DocumentSymbol' type, so
+                // we suppress the warning. We specify 'None' as specifying 'Some'
+                // is what is actually deprecated.
+                #[allow(deprecated)]
+                symbols.push(DocumentSymbol {
+                    name: name.to_string(),
+                    detail: Some(
+                        Printer::new(&module.ast.names)
+                            .print_type(&get_function_type(function))
+                            .to_string(),
+                    ),
+                    kind: SymbolKind::FUNCTION,
+                    tags: make_deprecated_symbol_tag(&function.deprecation),
+                    deprecated: None,
+                    range: src_span_to_lsp_range(full_function_span, &line_numbers),
+                    selection_range: src_span_to_lsp_range(*name_location, &line_numbers),
+                    children: None,
+                });
+            }
+
+            Definition::TypeAlias(alias) => {
+                let full_alias_span = match alias.documentation {
+                    Some((doc_position, _)) => {
+                        SrcSpan::new(get_doc_marker_pos(doc_position), alias.location.end)
+                    }
+                    None => alias.location,
+                };
+
+                // The 'deprecated' field is deprecated, but we have to specify it anyway
+                // to be able to construct the 'DocumentSymbol' type, so
+                // we suppress the warning. We specify 'None' as specifying 'Some'
+                // is what is actually deprecated.
+                #[allow(deprecated)]
+                symbols.push(DocumentSymbol {
+                    name: alias.alias.to_string(),
+                    detail: Some(
+                        Printer::new(&module.ast.names)
+                            // If we print with aliases, we end up printing the alias which the user
+                            // is currently hovering, which is not helpful. Instead, we print the
+                            // raw type, so the user can see which type the alias represents
+                            .print_type_without_aliases(&alias.type_)
+                            .to_string(),
+                    ),
+                    kind: SymbolKind::CLASS,
+                    tags: make_deprecated_symbol_tag(&alias.deprecation),
+                    deprecated: None,
+                    range: src_span_to_lsp_range(full_alias_span, &line_numbers),
+                    selection_range: src_span_to_lsp_range(alias.name_location, &line_numbers),
+                    children: None,
+                });
+            }
+
+            Definition::CustomType(type_) => {
+                symbols.push(custom_type_symbol(type_, &line_numbers, module));
+            }
+
+            Definition::ModuleConstant(constant) => {
+                // `ModuleConstant.location` ends at the constant's name or type.
+                // For the full symbol span, necessary for `range`, we need to
+                // include the constant value as well.
+                // Also include the documentation at the start, if available.
+                let full_constant_span = SrcSpan {
+                    start: constant
+                        .documentation
+                        .as_ref()
+                        .map(|(doc_start, _)| get_doc_marker_pos(*doc_start))
+                        .unwrap_or(constant.location.start),
+
+                    end: constant.value.location().end,
+                };
+
+                // The 'deprecated' field is deprecated, but we have to specify it anyway
+                // to be able to construct the 'DocumentSymbol' type, so
+                // we suppress the warning. We specify 'None' as specifying 'Some'
+                // is what is actually deprecated.
+                #[allow(deprecated)]
+                symbols.push(DocumentSymbol {
+                    name: constant.name.to_string(),
+                    detail: Some(
+                        Printer::new(&module.ast.names)
+                            .print_type(&constant.type_)
+                            .to_string(),
+                    ),
+                    kind: SymbolKind::CONSTANT,
+                    tags: make_deprecated_symbol_tag(&constant.deprecation),
+                    deprecated: None,
+                    range: src_span_to_lsp_range(full_constant_span, &line_numbers),
+                    selection_range: src_span_to_lsp_range(constant.name_location, &line_numbers),
+                    children: None,
+                });
+            }
+        }
+    }
+
+    symbols
+}
+
+/// The public types, constructors, functions and constants of a module that is
+/// only known through its interface, such as a module of a dependency. Unlike
+/// `document_symbols` the interface doesn't know where definitions end, so
+/// each symbol only spans its head.
+fn interface_symbols(
+    interface: &ModuleInterface,
+    line_numbers: &LineNumbers,
+) -> Vec<DocumentSymbol> {
+    // The 'deprecated' field is deprecated, but we have to specify it anyway
+    // to be able to construct the 'DocumentSymbol' type, so we suppress the
+    // warning. We specify 'None' as specifying 'Some' is what is actually
+    // deprecated.
+    #[allow(deprecated)]
+    let symbol = |name: &EcoString, kind, deprecation, location| {
+        let range = src_span_to_lsp_range(location, line_numbers);
+        DocumentSymbol {
+            name: name.to_string(),
+            detail: None,
+            kind,
+            tags: make_deprecated_symbol_tag(deprecation),
+            deprecated: None,
+            range,
+            selection_range: range,
+            children: None,
+        }
+    };
+
+    let mut types: HashMap<&EcoString, DocumentSymbol> = interface
+        .types
+        .iter()
+        .filter(|(_, type_)| type_.publicity.is_public() && type_.module == interface.name)
+        .map(|(name, type_)| {
+            let symbol = symbol(name, SymbolKind::CLASS, &type_.deprecation, type_.origin);
+            (name, symbol)
+        })
+        .collect();
+
+    let mut symbols = vec![];
+    for (name, value) in &interface.values {
+        if !value.publicity.is_public() {
+            continue;
+        }
+        match &value.variant {
+            ValueConstructorVariant::ModuleFn {
+                module, location, ..
+            } if *module == interface.name => symbols.push(symbol(
+                name,
+                SymbolKind::FUNCTION,
+                &value.deprecation,
+                *location,
+            )),
+
+            ValueConstructorVariant::ModuleConstant {
+                module, location, ..
+            } if *module == interface.name => symbols.push(symbol(
+                name,
+                SymbolKind::CONSTANT,
+                &value.deprecation,
+                *location,
+            )),
+
+            // Constructors are the children of their type, like they are for
+            // the modules of the project.
+            ValueConstructorVariant::Record {
+                module,
+                location,
+                arity,
+                ..
+            } if *module == interface.name => {
+                let type_name = value
+                    .type_
+                    .return_type()
+                    .unwrap_or_else(|| value.type_.clone())
+                    .named_type_name();
+                let Some(type_) = type_name.and_then(|(_, type_name)| types.get_mut(&type_name))
+                else {
+                    continue;
+                };
+                let kind = if *arity == 0 {
+                    SymbolKind::ENUM_MEMBER
+                } else {
+                    SymbolKind::CONSTRUCTOR
+                };
+                let constructor = symbol(name, kind, &value.deprecation, *location);
+                type_
+                    .children
+                    .get_or_insert_with(Vec::new)
+                    .push(constructor);
+            }
+
+            ValueConstructorVariant::ModuleFn { .. }
+            | ValueConstructorVariant::ModuleConstant { .. }
+            | ValueConstructorVariant::Record { .. }
+            | ValueConstructorVariant::LocalVariable { .. }
+            | ValueConstructorVariant::LocalConstant { .. } => {}
+        }
+    }
+
+    symbols.extend(types.into_values());
+    symbols
+}
+
+fn symbol_information(
+    symbol: &DocumentSymbol,
+    location: lsp::Location,
+    container_name: &str,
+) -> lsp::SymbolInformation {
+    // The 'deprecated' field is deprecated, but we have to specify it anyway
+    // to be able to construct the 'SymbolInformation' type, so we suppress the
+    // warning. We specify 'None' as specifying 'Some' is what is actually
+    // deprecated.
+    #[allow(deprecated)]
+    lsp::SymbolInformation {
+        name: symbol.name.clone(),
+        kind: symbol.kind,
+        tags: symbol.tags.clone(),
+        deprecated: None,
+        location,
+        container_name: Some(container_name.into()),
+    }
+}
+
+/// How well a workspace symbol search query matches a name, or `None` if it
+/// doesn't match at all. A name matches if it contains all the characters of
+/// the query in order, ignoring case. An exact match scores highest, followed
+/// by a name starting with the query, then a name containing it.
+fn fuzzy_match(query: &str, name: &str) -> Option<u8> {
+    let query = query.to_lowercase();
+    let name = name.to_lowercase();
+
+    let mut name_chars = name.chars();
+    if !query
+        .chars()
+        .all(|query_char| name_chars.any(|name_char| name_char == query_char))
+    {
+        return None;
+    }
+
+    Some(if name == query {
+        3
+    } else if name.starts_with(&query) {
+        2
+    } else if name.contains(&query) {
+        1
+    } else {
+        0
+    })
+}
+
 fn custom_type_symbol(
     type_: &CustomType<Arc<Type>>,
     line_numbers: &LineNumbers,
@@ -974,6 +1605,39 @@ fn hover_for_pattern(pattern: &TypedPattern, line_numbers: LineNumbers, module:
     }
 }
 
+/// Collects the named types making up the given type, starting with the
+/// outermost one. Each type appears only once.
+fn named_types(type_: Arc<Type>, types: &mut Vec<Arc<Type>>) {
+    let type_ = type_::collapse_links(type_);
+    match type_.as_ref() {
+        Type::Named {
+            module, name, args, ..
+        } => {
+            let seen = types.iter().any(|seen| {
+                matches!(seen.as_ref(), Type::Named { module: m, name: n, .. } if m == module && n == name)
+            });
+            if !seen {
+                types.push(type_.clone());
+            }
+            for arg in args {
+                named_types(arg.clone(), types);
+            }
+        }
+        Type::Fn { args, retrn } => {
+            for arg in args {
+                named_types(arg.clone(), types);
+            }
+            named_types(retrn.clone(), types);
+        }
+        Type::Tuple { elems } => {
+            for elem in elems {
+                named_types(elem.clone(), types);
+            }
+        }
+        Type::Var { .. } => (),
+    }
+}
+
 fn get_function_type(fun: &TypedFunction) -> Type {
     Type::Fn {
         args: fun.arguments.iter().map(|arg| arg.type_.clone()).collect(),
@@ -1394,6 +2058,18 @@ fn get_doc_marker_pos(content_pos: u32) -> u32 {
     content_pos.saturating_sub(3)
 }
 
+/// The function of the module whose head is at the given location.
+fn module_function(module: &Module, location: SrcSpan) -> Option<&TypedFunction> {
+    module
+        .ast
+        .definitions
+        .iter()
+        .find_map(|definition| match definition {
+            Definition::Function(function) if function.location == location => Some(function),
+            _ => None,
+        })
+}
+
 fn make_deprecated_symbol_tag(deprecation: &Deprecation) -> Option<Vec<SymbolTag>> {
     deprecation
         .is_deprecated()
@@ -0,0 +1,273 @@
+use camino::{Utf8Component, Utf8Path, Utf8PathBuf};
+use ecow::EcoString;
+use lsp_types::Location;
+
+use crate::{
+    ast::{Definition, SrcSpan, TypedExpr},
+    build::{Located, Module, Target},
+    io::{DirWalker, FileSystemReader},
+    line_numbers::LineNumbers,
+    paths::ProjectPaths,
+    type_::{ModuleInterface, ValueConstructorVariant},
+};
+
+use super::{path_to_uri, src_span_to_lsp_range};
+
+/// A function implemented in another language with the `@external`
+/// attribute.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct External {
+    pub target: Target,
+    pub module: EcoString,
+    pub function: EcoString,
+}
+
+/// The externals of the function under the cursor, along with the name of the
+/// Gleam module defining it.
+pub fn externals_at(
+    found: &Located<'_>,
+    byte_index: u32,
+    module: &Module,
+    importable_modules: &im::HashMap<EcoString, ModuleInterface>,
+) -> Option<(EcoString, Vec<External>)> {
+    let externals = |erlang, javascript, go| {
+        [
+            (Target::Erlang, erlang),
+            (Target::JavaScript, javascript),
+            (Target::Go, go),
+        ]
+        .into_iter()
+        .filter_map(
+            |(target, external): (_, Option<(&EcoString, &EcoString)>)| {
+                let (module, function) = external?;
+                Some(External {
+                    target,
+                    module: module.clone(),
+                    function: function.clone(),
+                })
+            },
+        )
+        .collect::<Vec<_>>()
+    };
+
+    // A function with no body is given one that spans its head, so the head
+    // has to be looked for directly rather than relying on the located node.
+    let function = module
+        .ast
+        .definitions
+        .iter()
+        .find_map(|definition| match definition {
+            Definition::Function(function) if function.location.contains(byte_index) => {
+                Some(function)
+            }
+            _ => None,
+        });
+    if let Some(function) = function {
+        let externals = externals(
+            function.external_erlang.as_ref().map(|(m, f, _)| (m, f)),
+            function
+                .external_javascript
+                .as_ref()
+                .map(|(m, f, _)| (m, f)),
+            function.external_go.as_ref().map(|(m, f, _)| (m, f)),
+        );
+        return Some((module.name.clone(), externals));
+    }
+
+    let variant = match found {
+        Located::Expression(TypedExpr::Var { constructor, .. }) => &constructor.variant,
+        Located::Expression(TypedExpr::ModuleSelect {
+            module_name, label, ..
+        }) => {
+            &importable_modules
+                .get(module_name)?
+                .values
+                .get(label)?
+                .variant
+        }
+        _ => return None,
+    };
+
+    let ValueConstructorVariant::ModuleFn {
+        module,
+        external_erlang,
+        external_javascript,
+        external_go,
+        ..
+    } = variant
+    else {
+        return None;
+    };
+    let externals = externals(
+        external_erlang.as_ref().map(|(m, f)| (m, f)),
+        external_javascript.as_ref().map(|(m, f)| (m, f)),
+        external_go.as_ref().map(|(m, f)| (m, f)),
+    );
+    Some((module.clone(), externals))
+}
+
+/// Finds the native files implementing the given externals of a function
+/// defined in `module`. Only the package's own `src` and `test` directories
+/// are searched. If a file is found but the function can't be found in it
+/// then the start of the file is used.
+pub fn find_implementations(
+    io: &impl FileSystemReader,
+    paths: &ProjectPaths,
+    package_name: &str,
+    module: &Module,
+    externals: &[External],
+) -> Vec<Location> {
+    externals
+        .iter()
+        .filter_map(|external| {
+            let (path, code, location) = match external.target {
+                Target::Erlang => find_erlang(io, paths, external)?,
+                Target::JavaScript => find_javascript(io, module, external)?,
+                Target::Go => find_go(io, paths, package_name, module, external)?,
+            };
+            let line_numbers = LineNumbers::new(&code);
+            Some(Location {
+                uri: path_to_uri(path),
+                range: src_span_to_lsp_range(location, &line_numbers),
+            })
+        })
+        .collect()
+}
+
+/// An Erlang external refers to a module that can be anywhere in the source
+/// directories, as Erlang modules share a single namespace.
+fn find_erlang(
+    io: &impl FileSystemReader,
+    paths: &ProjectPaths,
+    external: &External,
+) -> Option<(Utf8PathBuf, String, SrcSpan)> {
+    let file_name = format!("{}.erl", external.module);
+    let path = source_files(io, paths).find(|path| path.file_name() == Some(&file_name))?;
+    let code = io.read(&path).ok()?;
+    let location = find_definition(&code, &external.function, &[""], &["("]);
+    Some((path, code, location.unwrap_or_default()))
+}
+
+/// A JavaScript external refers to a file relative to the Gleam module.
+fn find_javascript(
+    io: &impl FileSystemReader,
+    module: &Module,
+    external: &External,
+) -> Option<(Utf8PathBuf, String, SrcSpan)> {
+    let directory = module.input_path.parent()?;
+    let path = normalise(&directory.join(external.module.as_str()));
+    let code = io.read(&path).ok()?;
+    let location = find_definition(
+        &code,
+        &external.function,
+        &[
+            "export function ",
+            "export async function ",
+            "export const ",
+            "export let ",
+        ],
+        &["(", " ", "="],
+    );
+    Some((path, code, location.unwrap_or_default()))
+}
+
+/// A Go external refers to a Go package, which is a directory of the Gleam
+/// package when it's one of its own, or the package's source root when it's
+/// the package's own import path. The empty package is the one the Gleam
+/// module itself is compiled into.
+fn find_go(
+    io: &impl FileSystemReader,
+    paths: &ProjectPaths,
+    package_name: &str,
+    module: &Module,
+    external: &External,
+) -> Option<(Utf8PathBuf, String, SrcSpan)> {
+    let directory = if external.module.is_empty() {
+        Utf8PathBuf::from(module.name.as_str())
+    } else {
+        // The package may be given an alias: `alias path/to/package`
+        let package = match external.module.split_once(' ') {
+            Some((_alias, package)) => package,
+            None => external.module.as_str(),
+        };
+        // The package's own import path is its Go source root, the `src`
+        // and `test` directories themselves.
+        if package.ends_with(&format!("/{package_name}")) {
+            Utf8PathBuf::new()
+        } else {
+            let (_, directory) = package.split_once(&format!("/{package_name}/"))?;
+            Utf8PathBuf::from(directory)
+        }
+    };
+
+    source_files(io, paths)
+        .filter(|path| {
+            path.extension() == Some("go")
+                && !path.as_str().ends_with("_test.go")
+                && [paths.src_directory(), paths.test_directory()]
+                    .iter()
+                    .any(|root| path.parent() == Some(&root.join(&directory)))
+        })
+        .find_map(|path| {
+            // A Go package is made of many files, only the one defining the
+            // function is of interest.
+            let code = io.read(&path).ok()?;
+            let location = find_definition(&code, &external.function, &["func "], &["(", "["])?;
+            Some((path, code, location))
+        })
+}
+
+/// All the files in the package's `src` and `test` directories.
+fn source_files<'a>(
+    io: &'a impl FileSystemReader,
+    paths: &ProjectPaths,
+) -> impl Iterator<Item = Utf8PathBuf> + 'a {
+    [paths.src_directory(), paths.test_directory()]
+        .into_iter()
+        .filter(|directory| io.is_directory(directory))
+        .flat_map(|directory| DirWalker::new(directory).into_file_iter(io))
+        .filter_map(Result::ok)
+}
+
+/// The location of the name of the function defined on a line starting with
+/// one of the prefixes and with the name followed by one of the suffixes.
+fn find_definition(
+    code: &str,
+    name: &str,
+    prefixes: &[&str],
+    suffixes: &[&str],
+) -> Option<SrcSpan> {
+    let mut line_start = 0;
+    for line in code.split_inclusive('\n') {
+        for prefix in prefixes {
+            let Some(rest) = line.strip_prefix(prefix) else {
+                continue;
+            };
+            let is_definition = rest
+                .strip_prefix(name)
+                .is_some_and(|rest| suffixes.iter().any(|suffix| rest.starts_with(suffix)));
+            if is_definition {
+                let start = (line_start + prefix.len()) as u32;
+                return Some(SrcSpan::new(start, start + name.len() as u32));
+            }
+        }
+        line_start += line.len();
+    }
+    None
+}
+
+/// Resolves the `.` and `..` segments of a path, without touching the file
+/// system.
+fn normalise(path: &Utf8Path) -> Utf8PathBuf {
+    let mut normalised = Utf8PathBuf::new();
+    for component in path.components() {
+        match component {
+            Utf8Component::CurDir => (),
+            Utf8Component::ParentDir => {
+                let _ = normalised.pop();
+            }
+            component => normalised.push(component),
+        }
+    }
+    normalised
+}
@@ -0,0 +1,156 @@
+use std::sync::Arc;
+
+use lsp_types::{InlayHint, InlayHintKind, InlayHintLabel, Range};
+use vec1::Vec1;
+
+use crate::{
+    ast::{
+        visit::{self, Visit},
+        FunctionLiteralKind, SrcSpan, TypeAst, TypedArg, TypedAssignment, TypedExpr,
+        TypedPipelineAssignment, TypedStatement,
+    },
+    build::Module,
+    line_numbers::LineNumbers,
+    type_::{printer::Printer, Type},
+};
+
+use super::src_span_to_lsp_range;
+
+/// Returns the inlay hints for the inferred types of the given module that
+/// fall within the given range:
+///
+/// - After the pattern of a `let` assignment with no annotation.
+/// - After each parameter with no annotation of an anonymous function or of
+///   the callback of a `use` expression.
+/// - After each step of a pipeline spanning multiple lines, showing the type
+///   of the value that's passed on to the next step.
+///
+pub fn get_inlay_hints(
+    module: &Module,
+    line_numbers: &LineNumbers,
+    range: Range,
+) -> Vec<InlayHint> {
+    let mut finder = InlayHints {
+        line_numbers,
+        // We need to use the same printer for all the hints because otherwise
+        // we could get duplicate type variable names.
+        printer: Printer::new(&module.ast.names),
+        hints: vec![],
+    };
+    finder.visit_typed_module(&module.ast);
+
+    let mut hints = finder
+        .hints
+        .into_iter()
+        .filter(|hint| range.start <= hint.position && hint.position <= range.end)
+        .collect::<Vec<_>>();
+    // Nodes are visited after their children, so hints are sorted to be in the
+    // order they appear in the source code.
+    hints.sort_by_key(|hint| hint.position);
+    hints
+}
+
+struct InlayHints<'a> {
+    line_numbers: &'a LineNumbers,
+    printer: Printer<'a>,
+    hints: Vec<InlayHint>,
+}
+
+impl InlayHints<'_> {
+    fn push_hint(&mut self, at: u32, label: String, padding_left: bool) {
+        let position = src_span_to_lsp_range(SrcSpan::new(at, at), self.line_numbers).start;
+        self.hints.push(InlayHint {
+            position,
+            label: InlayHintLabel::String(label),
+            kind: Some(InlayHintKind::TYPE),
+            text_edits: None,
+            tooltip: None,
+            padding_left: Some(padding_left),
+            padding_right: None,
+            data: None,
+        });
+    }
+
+    /// A hint that goes right after a pattern or a parameter, like an
+    /// annotation would.
+    fn push_annotation_hint(&mut self, at: u32, type_: &Type) {
+        let label = format!(": {}", self.printer.print_type(type_));
+        self.push_hint(at, label, false);
+    }
+}
+
+impl<'ast> Visit<'ast> for InlayHints<'_> {
+    fn visit_typed_assignment(&mut self, assignment: &'ast TypedAssignment) {
+        visit::visit_typed_assignment(self, assignment);
+
+        // Pipelines and `use` expressions generate assignments internally,
+        // these can't be annotated so there's no hint to show.
+        if assignment.annotation.is_some() || assignment.kind.is_generated() {
+            return;
+        }
+
+        let type_ = assignment.type_();
+        self.push_annotation_hint(assignment.pattern.location().end, &type_);
+    }
+
+    fn visit_typed_expr_fn(
+        &mut self,
+        location: &'ast SrcSpan,
+        type_: &'ast Arc<Type>,
+        kind: &'ast FunctionLiteralKind,
+        args: &'ast [TypedArg],
+        body: &'ast Vec1<TypedStatement>,
+        return_annotation: &'ast Option<TypeAst>,
+    ) {
+        // Function captures have a single parameter that doesn't appear in the
+        // source code.
+        if !matches!(kind, FunctionLiteralKind::Capture) {
+            for argument in args.iter() {
+                if argument.annotation.is_none() {
+                    self.push_annotation_hint(argument.location.end, &argument.type_);
+                }
+            }
+        }
+
+        visit::visit_typed_expr_fn(self, location, type_, kind, args, body, return_annotation);
+    }
+
+    fn visit_typed_expr_pipeline(
+        &mut self,
+        location: &'ast SrcSpan,
+        assignments: &'ast [TypedPipelineAssignment],
+        finally: &'ast TypedExpr,
+    ) {
+        visit::visit_typed_expr_pipeline(self, location, assignments, finally);
+
+        // A pipeline that fits on a single line is short enough that the hints
+        // would only get in the way.
+        let start_line = self.line_numbers.line_number(location.start);
+        let end_line = self.line_numbers.line_number(location.end);
+        if start_line == end_line {
+            return;
+        }
+
+        let steps = assignments
+            .iter()
+            .map(|assignment| assignment.value.as_ref())
+            .chain(std::iter::once(finally))
+            .collect::<Vec<_>>();
+
+        for (index, step) in steps.iter().enumerate() {
+            // We only show a hint at the end of a line, a step followed by
+            // another one on the same line doesn't get one.
+            let end = step.location().end;
+            let next_step_on_same_line = steps.get(index + 1).is_some_and(|next| {
+                self.line_numbers.line_number(next.location().start)
+                    == self.line_numbers.line_number(end)
+            });
+            if next_step_on_same_line {
+                continue;
+            }
+
+            let label = self.printer.print_type(&step.type_()).to_string();
+            self.push_hint(end, label, true);
+        }
+    }
+}
@@ -7,8 +7,11 @@ use lsp_types::{
     self as lsp,
     notification::{DidChangeTextDocument, DidCloseTextDocument, DidSaveTextDocument},
     request::{
-        CodeActionRequest, Completion, DocumentSymbolRequest, Formatting, HoverRequest,
-        PrepareRenameRequest, Rename, SignatureHelpRequest,
+        CallHierarchyIncomingCalls, CallHierarchyOutgoingCalls, CallHierarchyPrepare,
+        CodeActionRequest, CodeLensRequest, Completion, DocumentSymbolRequest, ExecuteCommand,
+        Formatting, GotoImplementation, GotoTypeDefinition, HoverRequest, InlayHintRequest,
+        PrepareRenameRequest, References, Rename, SemanticTokensFullRequest,
+        SemanticTokensRangeRequest, SignatureHelpRequest, WorkspaceSymbolRequest,
     },
 };
 use std::time::Duration;
@@ -24,12 +27,26 @@ pub enum Request {
     Format(lsp::DocumentFormattingParams),
     Hover(lsp::HoverParams),
     GoToDefinition(lsp::GotoDefinitionParams),
+    GoToTypeDefinition(lsp::request::GotoTypeDefinitionParams),
+    GoToImplementation(lsp::request::GotoImplementationParams),
     Completion(lsp::CompletionParams),
     CodeAction(lsp::CodeActionParams),
     SignatureHelp(lsp::SignatureHelpParams),
     DocumentSymbol(lsp::DocumentSymbolParams),
     PrepareRename(lsp::TextDocumentPositionParams),
     Rename(lsp::RenameParams),
+    References(lsp::ReferenceParams),
+    WorkspaceSymbol(lsp::WorkspaceSymbolParams),
+    InlayHint(lsp::InlayHintParams),
+    SemanticTokensFull(lsp::SemanticTokensParams),
+    SemanticTokensRange(lsp::SemanticTokensRangeParams),
+    PrepareCallHierarchy(lsp::CallHierarchyPrepareParams),
+    // These carry a whole call hierarchy item, so they are boxed to keep the
+    // size of the other messages down.
+    IncomingCalls(Box<lsp::CallHierarchyIncomingCallsParams>),
+    OutgoingCalls(Box<lsp::CallHierarchyOutgoingCallsParams>),
+    CodeLens(lsp::CodeLensParams),
+    ExecuteCommand(lsp::ExecuteCommandParams),
 }
 
 impl Request {
@@ -48,6 +65,14 @@ impl Request {
                 let params = cast_request::<GotoDefinition>(request);
                 Some(Message::Request(id, Request::GoToDefinition(params)))
             }
+            "textDocument/typeDefinition" => {
+                let params = cast_request::<GotoTypeDefinition>(request);
+                Some(Message::Request(id, Request::GoToTypeDefinition(params)))
+            }
+            "textDocument/implementation" => {
+                let params = cast_request::<GotoImplementation>(request);
+                Some(Message::Request(id, Request::GoToImplementation(params)))
+            }
             "textDocument/completion" => {
                 let params = cast_request::<Completion>(request);
                 Some(Message::Request(id, Request::Completion(params)))
@@ -72,6 +97,52 @@ impl Request {
                 let params = cast_request::<PrepareRenameRequest>(request);
                 Some(Message::Request(id, Request::PrepareRename(params)))
             }
+            "textDocument/references" => {
+                let params = cast_request::<References>(request);
+                Some(Message::Request(id, Request::References(params)))
+            }
+            "workspace/symbol" => {
+                let params = cast_request::<WorkspaceSymbolRequest>(request);
+                Some(Message::Request(id, Request::WorkspaceSymbol(params)))
+            }
+            "textDocument/inlayHint" => {
+                let params = cast_request::<InlayHintRequest>(request);
+                Some(Message::Request(id, Request::InlayHint(params)))
+            }
+            "textDocument/semanticTokens/full" => {
+                let params = cast_request::<SemanticTokensFullRequest>(request);
+                Some(Message::Request(id, Request::SemanticTokensFull(params)))
+            }
+            "textDocument/semanticTokens/range" => {
+                let params = cast_request::<SemanticTokensRangeRequest>(request);
+                Some(Message::Request(id, Request::SemanticTokensRange(params)))
+            }
+            "textDocument/prepareCallHierarchy" => {
+                let params = cast_request::<CallHierarchyPrepare>(request);
+                Some(Message::Request(id, Request::PrepareCallHierarchy(params)))
+            }
+            "callHierarchy/incomingCalls" => {
+                let params = cast_request::<CallHierarchyIncomingCalls>(request);
+                Some(Message::Request(
+                    id,
+                    Request::IncomingCalls(Box::new(params)),
+                ))
+            }
+            "callHierarchy/outgoingCalls" => {
+                let params = cast_request::<CallHierarchyOutgoingCalls>(request);
+                Some(Message::Request(
+                    id,
+                    Request::OutgoingCalls(Box::new(params)),
+                ))
+            }
+            "textDocument/codeLens" => {
+                let params = cast_request::<CodeLensRequest>(request);
+                Some(Message::Request(id, Request::CodeLens(params)))
+            }
+            "workspace/executeCommand" => {
+                let params = cast_request::<ExecuteCommand>(request);
+                Some(Message::Request(id, Request::ExecuteCommand(params)))
+            }
             _ => None,
         }
     }
@@ -0,0 +1,617 @@
+use std::sync::Arc;
+
+use ecow::EcoString;
+use vec1::Vec1;
+
+use crate::{
+    analyse::Inferred,
+    ast::{
+        self, visit::Visit, CallArg, Constant, CustomType, Definition, FunctionLiteralKind, Import,
+        Pattern, SrcSpan, TypeAst, TypedArg, TypedConstant, TypedExpr, TypedModule,
+        TypedModuleConstant, TypedPattern, TypedStatement,
+    },
+    build::{Located, Module, UnqualifiedImport},
+    type_::{
+        collapse_links, ModuleInterface, ModuleValueConstructor, PatternConstructor, Type,
+        ValueConstructor, ValueConstructorVariant, PRELUDE_MODULE_NAME,
+    },
+};
+
+/// A module level value or type whose references can be searched for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Referenced {
+    /// A function, constant or record constructor, identified by the module it
+    /// is defined in and the location of its definition there.
+    Value {
+        module: EcoString,
+        location: SrcSpan,
+    },
+    Type {
+        module: EcoString,
+        name: EcoString,
+    },
+}
+
+impl Referenced {
+    /// The module the value or type is defined in.
+    pub fn module(&self) -> &EcoString {
+        match self {
+            Referenced::Value { module, .. } | Referenced::Type { module, .. } => module,
+        }
+    }
+}
+
+/// Works out which module level value or type, if any, the node under the
+/// cursor refers to.
+pub fn referenced(
+    found: &Located<'_>,
+    byte_index: u32,
+    module: &Module,
+    importable_modules: &im::HashMap<EcoString, ModuleInterface>,
+) -> Option<Referenced> {
+    let this_module = || module.name.clone();
+    match found {
+        Located::Expression(TypedExpr::Var { constructor, .. }) => {
+            module_value(&constructor.variant)
+        }
+        Located::Expression(TypedExpr::ModuleSelect {
+            module_name,
+            constructor,
+            ..
+        }) => Some(Referenced::Value {
+            module: module_name.clone(),
+            location: constructor.location(),
+        }),
+        Located::Pattern(Pattern::Constructor {
+            constructor:
+                Inferred::Known(PatternConstructor {
+                    module, location, ..
+                }),
+            ..
+        }) => Some(Referenced::Value {
+            module: module.clone(),
+            location: *location,
+        }),
+
+        // A definition is only referenced when the cursor is on its name, not
+        // anywhere else in its head.
+        Located::ModuleStatement(Definition::Function(function)) => {
+            let (name_location, _) = function.name.as_ref()?;
+            name_location
+                .contains(byte_index)
+                .then(|| Referenced::Value {
+                    module: this_module(),
+                    location: function.location,
+                })
+        }
+        Located::ModuleStatement(Definition::ModuleConstant(constant)) => constant
+            .name_location
+            .contains(byte_index)
+            .then(|| Referenced::Value {
+                module: this_module(),
+                location: constant.location,
+            }),
+        Located::ModuleStatement(Definition::CustomType(custom_type)) => {
+            if custom_type.name_location.contains(byte_index) {
+                return Some(Referenced::Type {
+                    module: this_module(),
+                    name: custom_type.name.clone(),
+                });
+            }
+            custom_type
+                .constructors
+                .iter()
+                .find(|constructor| constructor.name_location.contains(byte_index))
+                .map(|constructor| Referenced::Value {
+                    module: this_module(),
+                    location: constructor.location,
+                })
+        }
+        Located::ModuleStatement(Definition::TypeAlias(alias)) => alias
+            .name_location
+            .contains(byte_index)
+            .then(|| Referenced::Type {
+                module: this_module(),
+                name: alias.alias.clone(),
+            }),
+
+        Located::UnqualifiedImport(UnqualifiedImport {
+            name,
+            module,
+            is_type: true,
+            ..
+        }) => Some(Referenced::Type {
+            module: (*module).clone(),
+            name: (*name).clone(),
+        }),
+        Located::UnqualifiedImport(UnqualifiedImport {
+            name,
+            module,
+            is_type: false,
+            ..
+        }) => Some(Referenced::Value {
+            module: (*module).clone(),
+            location: value_location(importable_modules, module, name)?,
+        }),
+
+        Located::Annotation(location, type_) => {
+            // A type alias is replaced by the type it aliases when type
+            // checking, so the type alone can't tell which named type was
+            // written. Instead the name is read from the annotation itself.
+            if !matches!(collapse_links(type_.clone()).as_ref(), Type::Named { .. }) {
+                return None;
+            }
+            let annotation = module
+                .code
+                .get(location.start as usize..location.end as usize)?;
+            let head = annotation.split('(').next()?.trim();
+            let (qualifier, name) = match head.split_once('.') {
+                Some((qualifier, name)) => (Some(qualifier.trim()), name.trim()),
+                None => (None, head),
+            };
+            let (module, name) = Names::new(&module.ast).type_(qualifier, name)?;
+            Some(Referenced::Type { module, name })
+        }
+
+        Located::Expression(_)
+        | Located::Pattern(_)
+        | Located::PatternSpread { .. }
+        | Located::Statement(_)
+        | Located::ModuleStatement(Definition::Import(_))
+        | Located::FunctionBody(_)
+        | Located::Arg(_)
+        | Located::Label(_, _) => None,
+    }
+}
+
+/// Finds all the places in a module where the given value or type is used,
+/// both qualified and unqualified. If `include_declaration` is true and the
+/// value or type is defined in this module the name in its definition is
+/// included too.
+pub fn find_references(
+    referenced: &Referenced,
+    module: &Module,
+    importable_modules: &im::HashMap<EcoString, ModuleInterface>,
+    include_declaration: bool,
+) -> Vec<SrcSpan> {
+    let mut finder = FindReferences {
+        referenced,
+        module: &module.ast,
+        names: Names::new(&module.ast),
+        importable_modules,
+        include_declaration,
+        references: vec![],
+    };
+    finder.visit_typed_module(&module.ast);
+
+    let mut references = finder.references;
+    references.sort_by_key(|location| location.start);
+    references.dedup();
+    references
+}
+
+fn module_value(variant: &ValueConstructorVariant) -> Option<Referenced> {
+    match variant {
+        ValueConstructorVariant::ModuleConstant {
+            location, module, ..
+        }
+        | ValueConstructorVariant::ModuleFn {
+            location, module, ..
+        }
+        | ValueConstructorVariant::Record {
+            location, module, ..
+        } => Some(Referenced::Value {
+            module: module.clone(),
+            location: *location,
+        }),
+        ValueConstructorVariant::LocalVariable { .. }
+        | ValueConstructorVariant::LocalConstant { .. } => None,
+    }
+}
+
+fn value_location(
+    importable_modules: &im::HashMap<EcoString, ModuleInterface>,
+    module: &str,
+    name: &str,
+) -> Option<SrcSpan> {
+    let value = importable_modules.get(module)?.values.get(name)?;
+    Some(value.variant.definition_location())
+}
+
+/// The span of a name that may be preceded by a module qualifier, as in
+/// `option.Some`.
+pub fn name_location(
+    location: SrcSpan,
+    qualifier: &Option<(EcoString, SrcSpan)>,
+    name: &str,
+) -> SrcSpan {
+    let start = match qualifier {
+        Some((_, qualifier)) => qualifier.end + 1,
+        None => location.start,
+    };
+    SrcSpan::new(start, start + name.len() as u32)
+}
+
+/// Resolves the names written in a module to the module their value or type
+/// is defined in.
+pub struct Names<'a> {
+    module: &'a TypedModule,
+}
+
+impl<'a> Names<'a> {
+    pub fn new(module: &'a TypedModule) -> Self {
+        Self { module }
+    }
+
+    fn imports(&self) -> impl Iterator<Item = &'a Import<EcoString>> {
+        self.module
+            .definitions
+            .iter()
+            .filter_map(|definition| match definition {
+                Definition::Import(import) => Some(import),
+                _ => None,
+            })
+    }
+
+    fn imported_module(&self, qualifier: &str) -> Option<EcoString> {
+        self.imports()
+            .find(|import| import.used_name().as_deref() == Some(qualifier))
+            .map(|import| import.module.clone())
+    }
+
+    pub fn type_(&self, qualifier: Option<&str>, name: &str) -> Option<(EcoString, EcoString)> {
+        if let Some(qualifier) = qualifier {
+            return Some((self.imported_module(qualifier)?, name.into()));
+        }
+
+        let imported = self.imports().find_map(|import| {
+            import
+                .unqualified_types
+                .iter()
+                .find(|unqualified| unqualified.used_name() == name)
+                .map(|unqualified| (import.module.clone(), unqualified.name.clone()))
+        });
+        if imported.is_some() {
+            return imported;
+        }
+
+        let defined_here = self.module.definitions.iter().any(|definition| {
+            matches!(definition, Definition::CustomType(CustomType { name: type_name, .. }) if type_name == name)
+                || matches!(definition, Definition::TypeAlias(alias) if alias.alias == name)
+        });
+        let module = if defined_here {
+            self.module.name.clone()
+        } else {
+            PRELUDE_MODULE_NAME.into()
+        };
+        Some((module, name.into()))
+    }
+
+    pub fn value(&self, qualifier: Option<&str>, name: &str) -> Option<(EcoString, EcoString)> {
+        if let Some(qualifier) = qualifier {
+            return Some((self.imported_module(qualifier)?, name.into()));
+        }
+
+        let imported = self.imports().find_map(|import| {
+            import
+                .unqualified_values
+                .iter()
+                .find(|unqualified| unqualified.used_name() == name)
+                .map(|unqualified| (import.module.clone(), unqualified.name.clone()))
+        });
+        Some(imported.unwrap_or_else(|| (self.module.name.clone(), name.into())))
+    }
+}
+
+struct FindReferences<'a> {
+    referenced: &'a Referenced,
+    module: &'a TypedModule,
+    names: Names<'a>,
+    importable_modules: &'a im::HashMap<EcoString, ModuleInterface>,
+    include_declaration: bool,
+    references: Vec<SrcSpan>,
+}
+
+impl FindReferences<'_> {
+    fn is_referenced_value(&self, module: &str, location: SrcSpan) -> bool {
+        matches!(
+            self.referenced,
+            Referenced::Value { module: m, location: l } if m == module && *l == location
+        )
+    }
+
+    fn is_referenced_type(&self, module: &str, name: &str) -> bool {
+        matches!(
+            self.referenced,
+            Referenced::Type { module: m, name: n } if m == module && n == name
+        )
+    }
+
+    fn declaration(&mut self, is_referenced: bool, location: SrcSpan) {
+        if self.include_declaration && is_referenced {
+            self.references.push(location);
+        }
+    }
+
+    fn import(&mut self, import: &Import<EcoString>) {
+        for unqualified in &import.unqualified_values {
+            let location =
+                value_location(self.importable_modules, &import.module, &unqualified.name);
+            if location.is_some_and(|location| self.is_referenced_value(&import.module, location)) {
+                self.references.push(name_location(
+                    unqualified.location,
+                    &None,
+                    &unqualified.name,
+                ));
+            }
+        }
+        for unqualified in &import.unqualified_types {
+            if self.is_referenced_type(&import.module, &unqualified.name) {
+                // The location of an unqualified type import starts at the
+                // `type` keyword and ends after the alias, if there is one.
+                let end = match unqualified.as_name {
+                    None => unqualified.location.end,
+                    Some(_) => {
+                        unqualified.location.start
+                            + "type ".len() as u32
+                            + unqualified.name.len() as u32
+                    }
+                };
+                self.references
+                    .push(SrcSpan::new(end - unqualified.name.len() as u32, end));
+            }
+        }
+    }
+
+    fn annotation(&mut self, annotation: &Option<TypeAst>) {
+        if let Some(annotation) = annotation {
+            self.visit_type_ast(annotation);
+        }
+    }
+
+    fn arguments(&mut self, arguments: &[TypedArg]) {
+        for argument in arguments {
+            self.annotation(&argument.annotation);
+        }
+    }
+
+    fn constant(&mut self, constant: &TypedConstant) {
+        match constant {
+            Constant::Int { .. }
+            | Constant::Float { .. }
+            | Constant::String { .. }
+            | Constant::Invalid { .. } => {}
+
+            Constant::Tuple { elements, .. } | Constant::List { elements, .. } => {
+                for element in elements {
+                    self.constant(element);
+                }
+            }
+
+            Constant::Record {
+                location,
+                module,
+                name,
+                args,
+                type_,
+                ..
+            } => {
+                let qualifier = module.as_ref().map(|(qualifier, _)| qualifier.as_str());
+                let defined_in = match collapse_links(type_.clone()).as_ref() {
+                    Type::Named { module, .. } => Some(module.clone()),
+                    _ => None,
+                };
+                let is_referenced = self
+                    .names
+                    .value(qualifier, name)
+                    .zip(defined_in)
+                    .and_then(|((_, real_name), defined_in)| {
+                        let location =
+                            value_location(self.importable_modules, &defined_in, &real_name)?;
+                        Some(self.is_referenced_value(&defined_in, location))
+                    })
+                    .unwrap_or(false);
+                if is_referenced {
+                    self.references.push(name_location(*location, module, name));
+                }
+                for argument in args {
+                    self.constant(&argument.value);
+                }
+            }
+
+            Constant::BitArray { segments, .. } => {
+                for segment in segments {
+                    self.constant(&segment.value);
+                }
+            }
+
+            Constant::Var {
+                location,
+                module,
+                name,
+                constructor,
+                ..
+            } => {
+                let is_referenced = constructor
+                    .as_ref()
+                    .and_then(|constructor| module_value(&constructor.variant))
+                    .is_some_and(|referenced| &referenced == self.referenced);
+                if is_referenced {
+                    self.references.push(name_location(*location, module, name));
+                }
+            }
+
+            Constant::StringConcatenation { left, right, .. } => {
+                self.constant(left);
+                self.constant(right);
+            }
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for FindReferences<'_> {
+    fn visit_typed_definition(&mut self, definition: &'ast ast::TypedDefinition) {
+        match definition {
+            Definition::Import(import) => self.import(import),
+            Definition::TypeAlias(alias) => {
+                let is_referenced = self.is_referenced_type(&self.module.name, &alias.alias);
+                self.declaration(is_referenced, alias.name_location);
+                self.visit_type_ast(&alias.type_ast);
+            }
+            Definition::Function(_) | Definition::CustomType(_) | Definition::ModuleConstant(_) => {
+                ast::visit::visit_typed_definition(self, definition)
+            }
+        }
+    }
+
+    fn visit_typed_function(&mut self, function: &'ast ast::TypedFunction) {
+        if let Some((location, _)) = &function.name {
+            let is_referenced = self.is_referenced_value(&self.module.name, function.location);
+            self.declaration(is_referenced, *location);
+        }
+        self.arguments(&function.arguments);
+        self.annotation(&function.return_annotation);
+        ast::visit::visit_typed_function(self, function);
+    }
+
+    fn visit_typed_module_constant(&mut self, constant: &'ast TypedModuleConstant) {
+        let is_referenced = self.is_referenced_value(&self.module.name, constant.location);
+        self.declaration(is_referenced, constant.name_location);
+        self.annotation(&constant.annotation);
+        self.constant(&constant.value);
+    }
+
+    fn visit_typed_custom_type(&mut self, custom_type: &'ast ast::TypedCustomType) {
+        let is_referenced = self.is_referenced_type(&self.module.name, &custom_type.name);
+        self.declaration(is_referenced, custom_type.name_location);
+        for constructor in &custom_type.constructors {
+            let is_referenced = self.is_referenced_value(&self.module.name, constructor.location);
+            self.declaration(is_referenced, constructor.name_location);
+            for argument in &constructor.arguments {
+                self.visit_type_ast(&argument.ast);
+            }
+        }
+    }
+
+    fn visit_type_ast_constructor(
+        &mut self,
+        location: &'ast SrcSpan,
+        module: &'ast Option<(EcoString, SrcSpan)>,
+        name: &'ast EcoString,
+        arguments: &'ast Vec<TypeAst>,
+    ) {
+        let qualifier = module.as_ref().map(|(qualifier, _)| qualifier.as_str());
+        if let Some((defined_in, real_name)) = self.names.type_(qualifier, name) {
+            if self.is_referenced_type(&defined_in, &real_name) {
+                self.references.push(name_location(*location, module, name));
+            }
+        }
+        ast::visit::visit_type_ast_constructor(self, location, module, name, arguments);
+    }
+
+    fn visit_typed_expr_var(
+        &mut self,
+        location: &'ast SrcSpan,
+        constructor: &'ast ValueConstructor,
+        _name: &'ast EcoString,
+    ) {
+        if module_value(&constructor.variant).as_ref() == Some(self.referenced) {
+            self.references.push(*location);
+        }
+    }
+
+    fn visit_typed_expr_module_select(
+        &mut self,
+        location: &'ast SrcSpan,
+        _type_: &'ast Arc<Type>,
+        label: &'ast EcoString,
+        module_name: &'ast EcoString,
+        _module_alias: &'ast EcoString,
+        constructor: &'ast ModuleValueConstructor,
+    ) {
+        if self.is_referenced_value(module_name, constructor.location()) {
+            self.references.push(SrcSpan::new(
+                location.end - label.len() as u32,
+                location.end,
+            ));
+        }
+    }
+
+    fn visit_typed_expr_fn(
+        &mut self,
+        location: &'ast SrcSpan,
+        type_: &'ast Arc<Type>,
+        kind: &'ast FunctionLiteralKind,
+        args: &'ast [TypedArg],
+        body: &'ast Vec1<TypedStatement>,
+        return_annotation: &'ast Option<TypeAst>,
+    ) {
+        self.arguments(args);
+        self.annotation(return_annotation);
+        ast::visit::visit_typed_expr_fn(self, location, type_, kind, args, body, return_annotation);
+    }
+
+    fn visit_typed_assignment(&mut self, assignment: &'ast ast::TypedAssignment) {
+        self.annotation(&assignment.annotation);
+        ast::visit::visit_typed_assignment(self, assignment);
+    }
+
+    fn visit_typed_use(&mut self, use_: &'ast ast::TypedUse) {
+        for assignment in &use_.assignments {
+            self.annotation(&assignment.annotation);
+        }
+        ast::visit::visit_typed_use(self, use_);
+    }
+
+    fn visit_typed_clause_guard_module_select(
+        &mut self,
+        location: &'ast SrcSpan,
+        _type_: &'ast Arc<Type>,
+        label: &'ast EcoString,
+        module_name: &'ast EcoString,
+        _module_alias: &'ast EcoString,
+        _literal: &'ast TypedConstant,
+    ) {
+        let definition = value_location(self.importable_modules, module_name, label);
+        if definition.is_some_and(|definition| self.is_referenced_value(module_name, definition)) {
+            // The location of a guard's module select only covers the module
+            // name, the label comes after the dot.
+            let start = location.end + 1;
+            self.references
+                .push(SrcSpan::new(start, start + label.len() as u32));
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn visit_typed_pattern_constructor(
+        &mut self,
+        location: &'ast SrcSpan,
+        name: &'ast EcoString,
+        arguments: &'ast Vec<CallArg<TypedPattern>>,
+        module: &'ast Option<(EcoString, SrcSpan)>,
+        constructor: &'ast Inferred<PatternConstructor>,
+        spread: &'ast Option<SrcSpan>,
+        type_: &'ast Arc<Type>,
+    ) {
+        if let Inferred::Known(PatternConstructor {
+            module: defined_in,
+            location: definition,
+            ..
+        }) = constructor
+        {
+            if self.is_referenced_value(defined_in, *definition) {
+                self.references.push(name_location(*location, module, name));
+            }
+        }
+        ast::visit::visit_typed_pattern_constructor(
+            self,
+            location,
+            name,
+            arguments,
+            module,
+            constructor,
+            spread,
+            type_,
+        );
+    }
+}
@@ -5,13 +5,17 @@ use lsp_types::{RenameParams, TextEdit, Url, WorkspaceEdit};
 
 use crate::{
     analyse::name,
-    ast::{self, visit::Visit, SrcSpan, TypedModule},
+    ast::{self, visit::Visit, Definition, SrcSpan, TypedModule},
     build::Module,
     line_numbers::LineNumbers,
-    type_::{error::Named, ValueConstructor, ValueConstructorVariant},
+    type_::{error::Named, ModuleInterface, ValueConstructor, ValueConstructorVariant},
 };
 
-use super::TextEdits;
+use super::{
+    path_to_uri,
+    references::{find_references, Referenced},
+    TextEdits,
+};
 
 fn workspace_edit(uri: Url, edits: Vec<TextEdit>) -> WorkspaceEdit {
     let mut changes = HashMap::new();
@@ -65,6 +69,171 @@ pub fn rename_local_variable(
     Some(workspace_edit(uri, edits.edits))
 }
 
+/// Renames a module level function, constant, type or record constructor
+/// everywhere it is used in the root package. Values and types defined in a
+/// dependency can't be renamed, so `None` is returned for them.
+///
+/// Uses that refer to the value or type by an alias, as in
+/// `import wibble.{wobble as wubble}`, are left as they are: only the name in
+/// the import is changed.
+///
+/// The rename is refused if the new name is already taken in a module where
+/// the value or type is used unqualified, so that the rename doesn't change
+/// what any name refers to.
+pub fn rename_module_definition(
+    modules: &HashMap<EcoString, Module>,
+    importable_modules: &im::HashMap<EcoString, ModuleInterface>,
+    params: &RenameParams,
+    referenced: &Referenced,
+) -> Option<WorkspaceEdit> {
+    let defining_module = modules.get(referenced.module())?;
+    let (old_name, kind) = definition_name(&defining_module.ast, referenced)?;
+    let new_name = EcoString::from(params.new_name.as_str());
+    if name::check_name_case(Default::default(), &new_name, kind).is_err() {
+        return None;
+    }
+    let is_type = matches!(referenced, Referenced::Type { .. });
+    let name_clashes = modules.values().any(|module| {
+        uses_unqualified(&module.ast, referenced.module(), &old_name, is_type)
+            && is_name_taken(&module.ast, &new_name, is_type)
+    });
+    if name_clashes {
+        return None;
+    }
+
+    let mut changes = HashMap::new();
+    for module in modules.values() {
+        let line_numbers = LineNumbers::new(&module.code);
+        let mut edits = TextEdits::new(&line_numbers);
+        for location in find_references(referenced, module, importable_modules, true) {
+            let written = module
+                .code
+                .get(location.start as usize..location.end as usize);
+            if written == Some(old_name.as_str()) {
+                edits.replace(location, params.new_name.clone());
+            }
+        }
+        if !edits.edits.is_empty() {
+            let _ = changes.insert(path_to_uri(module.input_path.clone()), edits.edits);
+        }
+    }
+
+    Some(WorkspaceEdit {
+        changes: Some(changes),
+        document_changes: None,
+        change_annotations: None,
+    })
+}
+
+/// Whether a module refers to a value or type of the given module by its
+/// unqualified name, either because it is defined there or because it is
+/// imported unqualified without an alias.
+fn uses_unqualified(
+    module: &TypedModule,
+    defining_module: &EcoString,
+    name: &EcoString,
+    is_type: bool,
+) -> bool {
+    if &module.name == defining_module {
+        return true;
+    }
+    module
+        .definitions
+        .iter()
+        .any(|definition| match definition {
+            Definition::Import(import) if &import.module == defining_module => {
+                let unqualified = if is_type {
+                    &import.unqualified_types
+                } else {
+                    &import.unqualified_values
+                };
+                unqualified
+                    .iter()
+                    .any(|item| &item.name == name && item.as_name.is_none())
+            }
+            _ => false,
+        })
+}
+
+/// Whether a name is already used for a type, or for a value, in a module:
+/// by a definition, an unqualified import, or for values by an imported
+/// module, which a value of the same name would shadow.
+fn is_name_taken(module: &TypedModule, name: &EcoString, is_type: bool) -> bool {
+    module
+        .definitions
+        .iter()
+        .any(|definition| match definition {
+            Definition::Function(function) => {
+                !is_type && function.name.as_ref().is_some_and(|(_, n)| n == name)
+            }
+            Definition::ModuleConstant(constant) => !is_type && &constant.name == name,
+            Definition::TypeAlias(alias) => is_type && &alias.alias == name,
+            Definition::CustomType(custom_type) => {
+                if is_type {
+                    &custom_type.name == name
+                } else {
+                    custom_type
+                        .constructors
+                        .iter()
+                        .any(|constructor| &constructor.name == name)
+                }
+            }
+            Definition::Import(import) => {
+                if is_type {
+                    import
+                        .unqualified_types
+                        .iter()
+                        .any(|item| item.used_name() == name)
+                } else {
+                    import.used_name().as_ref() == Some(name)
+                        || import
+                            .unqualified_values
+                            .iter()
+                            .any(|item| item.used_name() == name)
+                }
+            }
+        })
+}
+
+/// The name a module level value or type is defined with, and what kind of
+/// name it is.
+fn definition_name(module: &TypedModule, referenced: &Referenced) -> Option<(EcoString, Named)> {
+    match referenced {
+        Referenced::Type { name, .. } => {
+            let is_alias = module.definitions.iter().any(
+                |definition| matches!(definition, Definition::TypeAlias(alias) if &alias.alias == name),
+            );
+            let kind = if is_alias {
+                Named::TypeAlias
+            } else {
+                Named::Type
+            };
+            Some((name.clone(), kind))
+        }
+
+        Referenced::Value { location, .. } => {
+            module
+                .definitions
+                .iter()
+                .find_map(|definition| match definition {
+                    Definition::Function(function) if function.location == *location => function
+                        .name
+                        .as_ref()
+                        .map(|(_, name)| (name.clone(), Named::Function)),
+                    Definition::ModuleConstant(constant) if constant.location == *location => {
+                        Some((constant.name.clone(), Named::Constant))
+                    }
+                    Definition::CustomType(custom_type) => custom_type
+                        .constructors
+                        .iter()
+                        .find(|constructor| constructor.location == *location)
+                        .map(|constructor| (constructor.name.clone(), Named::CustomTypeVariant)),
+                    _ => None,
+                })
+        }
+    }
+}
+
 struct RenameLocalVariable {
     definition_location: SrcSpan,
     references: Vec<SrcSpan>,
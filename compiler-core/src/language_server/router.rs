@@ -57,6 +57,11 @@ where
         find_gleam_project_parent(&self.io, path)
     }
 
+    /// Whether the path is the root of a project the server has opened.
+    pub fn is_project_root(&self, path: &Utf8Path) -> bool {
+        self.engines.contains_key(path)
+    }
+
     pub fn project_for_path(
         &mut self,
         path: Utf8PathBuf,
@@ -0,0 +1,805 @@
+use std::sync::Arc;
+
+use ecow::EcoString;
+use lsp_types::{
+    Range, SemanticToken, SemanticTokenModifier, SemanticTokenType, SemanticTokens,
+    SemanticTokensLegend,
+};
+use vec1::Vec1;
+
+use crate::{
+    analyse::Inferred,
+    ast::{
+        visit::{self, Visit},
+        ArgNames, AssignName, CallArg, Constant, Definition, FunctionLiteralKind, Import, SrcSpan,
+        TypeAst, TypedArg, TypedAssignment, TypedConstant, TypedCustomType, TypedExpr,
+        TypedFunction, TypedModuleConstant, TypedPattern, TypedStatement, TypedUse,
+    },
+    build::Module,
+    line_numbers::LineNumbers,
+    parse::{lexer::make_tokenizer, token::Token},
+    type_::{
+        self, error::VariableOrigin, Deprecation, ModuleInterface, ModuleValueConstructor,
+        PatternConstructor, Type, TypedCallArg, ValueConstructor, ValueConstructorVariant,
+    },
+};
+
+use super::{
+    engine::overlaps,
+    references::{name_location, Names},
+    src_span_to_lsp_range,
+};
+
+/// The kinds of token that are highlighted, in the same order as in the
+/// legend sent to the client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TokenType {
+    Namespace,
+    Type,
+    TypeParameter,
+    Parameter,
+    Variable,
+    Property,
+    EnumMember,
+    Function,
+    Decorator,
+}
+
+const TOKEN_TYPES: [SemanticTokenType; 9] = [
+    SemanticTokenType::NAMESPACE,
+    SemanticTokenType::TYPE,
+    SemanticTokenType::TYPE_PARAMETER,
+    SemanticTokenType::PARAMETER,
+    SemanticTokenType::VARIABLE,
+    SemanticTokenType::PROPERTY,
+    SemanticTokenType::ENUM_MEMBER,
+    SemanticTokenType::FUNCTION,
+    SemanticTokenType::DECORATOR,
+];
+
+// Modifiers are sent as a bit set, each bit corresponding to the modifier at
+// the same index in the legend.
+const DECLARATION: u32 = 1 << 0;
+const READONLY: u32 = 1 << 1;
+const DEPRECATED: u32 = 1 << 2;
+const UNUSED: u32 = 1 << 3;
+
+const TOKEN_MODIFIERS: [SemanticTokenModifier; 4] = [
+    SemanticTokenModifier::DECLARATION,
+    SemanticTokenModifier::READONLY,
+    SemanticTokenModifier::DEPRECATED,
+    SemanticTokenModifier::new("unused"),
+];
+
+pub fn legend() -> SemanticTokensLegend {
+    SemanticTokensLegend {
+        token_types: TOKEN_TYPES.to_vec(),
+        token_modifiers: TOKEN_MODIFIERS.to_vec(),
+    }
+}
+
+/// Returns the semantic tokens of a module. If a range is given only the
+/// tokens overlapping with it are returned.
+///
+pub fn semantic_tokens(
+    module: &Module,
+    line_numbers: &LineNumbers,
+    importable_modules: &im::HashMap<EcoString, ModuleInterface>,
+    range: Option<Range>,
+) -> SemanticTokens {
+    let mut highlighter = Highlighter::new(module, importable_modules);
+    highlighter.attributes();
+    highlighter.visit_typed_module(&module.ast);
+
+    SemanticTokens {
+        result_id: None,
+        data: encode(highlighter.tokens, line_numbers, range),
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Highlight {
+    location: SrcSpan,
+    type_: TokenType,
+    modifiers: u32,
+}
+
+/// Tokens are sent as a flat list where each token's position is relative to
+/// the one of the token before it.
+fn encode(
+    mut tokens: Vec<Highlight>,
+    line_numbers: &LineNumbers,
+    range: Option<Range>,
+) -> Vec<SemanticToken> {
+    tokens.sort_by_key(|token| token.location.start);
+
+    let mut data = vec![];
+    let mut previous_end = 0;
+    let mut previous_line = 0;
+    let mut previous_start = 0;
+    for token in tokens {
+        // Tokens can't overlap, this can happen if a name is both a label and
+        // a variable, like in `wibble(label:)`. The first one wins.
+        if token.location.start < previous_end {
+            continue;
+        }
+        previous_end = token.location.end;
+
+        let token_range = src_span_to_lsp_range(token.location, line_numbers);
+        let (start, end) = (token_range.start, token_range.end);
+        if start.line != end.line || range.is_some_and(|range| !overlaps(token_range, range)) {
+            continue;
+        }
+
+        let delta_line = start.line - previous_line;
+        let delta_start = if delta_line == 0 {
+            start.character - previous_start
+        } else {
+            start.character
+        };
+        data.push(SemanticToken {
+            delta_line,
+            delta_start,
+            length: end.character - start.character,
+            token_type: token.type_ as u32,
+            token_modifiers_bitset: token.modifiers,
+        });
+        previous_line = start.line;
+        previous_start = start.character;
+    }
+    data
+}
+
+fn deprecated(deprecation: &Deprecation) -> u32 {
+    if deprecation.is_deprecated() {
+        DEPRECATED
+    } else {
+        0
+    }
+}
+
+struct Highlighter<'a> {
+    module: &'a Module,
+    names: Names<'a>,
+    importable_modules: &'a im::HashMap<EcoString, ModuleInterface>,
+    /// The locations where the parameters of the function being highlighted
+    /// are defined, to tell them apart from other local variables.
+    parameters: Vec<SrcSpan>,
+    /// The locations of the unused definitions reported by the type checker.
+    /// The name is `None` if the warning only covers the name.
+    unused: Vec<(Option<EcoString>, SrcSpan)>,
+    tokens: Vec<Highlight>,
+}
+
+impl<'a> Highlighter<'a> {
+    fn new(
+        module: &'a Module,
+        importable_modules: &'a im::HashMap<EcoString, ModuleInterface>,
+    ) -> Self {
+        let unused = module
+            .ast
+            .type_info
+            .warnings
+            .iter()
+            .filter_map(|warning| match warning {
+                type_::Warning::UnusedVariable { location, .. }
+                | type_::Warning::UnusedImportedModule { location, .. } => Some((None, *location)),
+                type_::Warning::UnusedPrivateFunction { name, location }
+                | type_::Warning::UnusedPrivateModuleConstant { name, location }
+                | type_::Warning::UnusedImportedValue { name, location }
+                | type_::Warning::UnusedType { name, location, .. }
+                | type_::Warning::UnusedConstructor { name, location, .. }
+                | type_::Warning::UnusedImportedModuleAlias {
+                    alias: name,
+                    location,
+                    ..
+                } => Some((Some(name.clone()), *location)),
+                _ => None,
+            })
+            .collect();
+
+        Self {
+            module,
+            names: Names::new(&module.ast),
+            importable_modules,
+            parameters: vec![],
+            unused,
+            tokens: vec![],
+        }
+    }
+
+    /// Adds a token, as long as the location really is the one of the name in
+    /// the source code. Generated nodes, like the variables introduced by
+    /// pipelines and `use` expressions, don't appear there.
+    fn push(&mut self, location: SrcSpan, name: &str, type_: TokenType, modifiers: u32) {
+        let text = self
+            .module
+            .code
+            .get(location.start as usize..location.end as usize);
+        if text == Some(name) {
+            self.tokens.push(Highlight {
+                location,
+                type_,
+                modifiers,
+            });
+        }
+    }
+
+    /// Adds a token for a name that is being defined, which might be unused.
+    fn binding(&mut self, location: SrcSpan, name: &str, type_: TokenType, modifiers: u32) {
+        let is_unused = self.unused.iter().any(|(unused_name, unused_location)| {
+            unused_location.contains(location.start)
+                && location.end <= unused_location.end
+                && unused_name
+                    .as_ref()
+                    .is_none_or(|unused_name| unused_name == name)
+        });
+        let modifiers = if is_unused {
+            modifiers | UNUSED
+        } else {
+            modifiers
+        };
+        self.push(location, name, type_, modifiers)
+    }
+
+    fn value_modifiers(&self, module: &str, name: &str) -> u32 {
+        let Some(value) = self
+            .importable_modules
+            .get(module)
+            .and_then(|module| module.values.get(name))
+        else {
+            return 0;
+        };
+        let readonly = match value.variant {
+            ValueConstructorVariant::ModuleConstant { .. } => READONLY,
+            _ => 0,
+        };
+        readonly | deprecated(&value.deprecation)
+    }
+
+    fn type_modifiers(&self, module: &str, name: &str) -> u32 {
+        self.importable_modules
+            .get(module)
+            .and_then(|module| module.types.get(name))
+            .map(|type_| deprecated(&type_.deprecation))
+            .unwrap_or(0)
+    }
+
+    fn local_variable(&mut self, location: SrcSpan, name: &str, definition: SrcSpan) {
+        let type_ = if self.parameters.contains(&definition) {
+            TokenType::Parameter
+        } else {
+            TokenType::Variable
+        };
+        self.push(location, name, type_, 0);
+    }
+
+    /// Highlights the attributes, like `@external` and `@deprecated`. These
+    /// don't make it to the typed AST so we look for them in the source code.
+    fn attributes(&mut self) {
+        let mut previous_at = None;
+        for (start, token, end) in make_tokenizer(&self.module.code).flatten() {
+            match (previous_at, &token) {
+                (Some(at), Token::Name { name }) if at == start - 1 => {
+                    let mut attribute = EcoString::from("@");
+                    attribute.push_str(name);
+                    self.push(SrcSpan::new(at, end), &attribute, TokenType::Decorator, 0);
+                }
+                _ => (),
+            }
+            previous_at = match token {
+                Token::At => Some(start),
+                _ => None,
+            };
+        }
+    }
+
+    fn import(&mut self, import: &Import<EcoString>) {
+        // The module's name comes right after the `import` keyword.
+        let code = &self.module.code;
+        let after_keyword = import.location.start + "import".len() as u32;
+        let module_start = code
+            .get(after_keyword as usize..import.location.end as usize)
+            .and_then(|text| text.find(import.module.as_str()));
+        if let Some(offset) = module_start {
+            let start = after_keyword + offset as u32;
+            let location = SrcSpan::new(start, start + import.module.len() as u32);
+            self.binding(location, &import.module, TokenType::Namespace, 0);
+        }
+
+        if let Some((AssignName::Variable(name) | AssignName::Discard(name), location)) =
+            &import.as_name
+        {
+            self.binding(*location, name, TokenType::Namespace, 0);
+        }
+
+        for unqualified in &import.unqualified_values {
+            let modifiers = self.value_modifiers(&import.module, &unqualified.name);
+            let type_ = if unqualified.name.starts_with(char::is_uppercase) {
+                TokenType::EnumMember
+            } else if modifiers & READONLY != 0 {
+                TokenType::Variable
+            } else {
+                TokenType::Function
+            };
+            let location = name_location(unqualified.location, &None, &unqualified.name);
+            self.binding(location, &unqualified.name, type_, modifiers);
+            if let Some(alias) = &unqualified.as_name {
+                let end = unqualified.location.end;
+                let location = SrcSpan::new(end - alias.len() as u32, end);
+                self.binding(location, alias, type_, modifiers);
+            }
+        }
+
+        for unqualified in &import.unqualified_types {
+            let modifiers = self.type_modifiers(&import.module, &unqualified.name);
+            // The location of an unqualified type import starts at the `type`
+            // keyword.
+            let start = unqualified.location.start + "type ".len() as u32;
+            let location = SrcSpan::new(start, start + unqualified.name.len() as u32);
+            self.binding(location, &unqualified.name, TokenType::Type, modifiers);
+            if let Some(alias) = &unqualified.as_name {
+                let end = unqualified.location.end;
+                let location = SrcSpan::new(end - alias.len() as u32, end);
+                self.binding(location, alias, TokenType::Type, modifiers);
+            }
+        }
+    }
+
+    fn arguments(&mut self, arguments: &[TypedArg]) {
+        for argument in arguments {
+            let (name, location) = match &argument.names {
+                ArgNames::Discard { name, location } | ArgNames::Named { name, location } => {
+                    (name, *location)
+                }
+                ArgNames::LabelledDiscard {
+                    label,
+                    label_location,
+                    name,
+                    name_location,
+                }
+                | ArgNames::NamedLabelled {
+                    label,
+                    label_location,
+                    name,
+                    name_location,
+                } => {
+                    self.push(*label_location, label, TokenType::Property, DECLARATION);
+                    (name, *name_location)
+                }
+            };
+            self.parameters.push(location);
+            self.binding(location, name, TokenType::Parameter, DECLARATION);
+            self.annotation(&argument.annotation);
+        }
+    }
+
+    fn annotation(&mut self, annotation: &Option<TypeAst>) {
+        if let Some(annotation) = annotation {
+            self.visit_type_ast(annotation);
+        }
+    }
+
+    fn type_parameters(&mut self, parameters: &[(SrcSpan, EcoString)]) {
+        for (location, name) in parameters {
+            self.push(*location, name, TokenType::TypeParameter, DECLARATION);
+        }
+    }
+
+    /// Highlights a label at the start of an argument, as in `wibble(label: 1)`.
+    fn call_arg_label<A>(&mut self, argument: &CallArg<A>) {
+        if let (Some(label), None) = (&argument.label, &argument.implicit) {
+            let start = argument.location.start;
+            let location = SrcSpan::new(start, start + label.len() as u32);
+            self.push(location, label, TokenType::Property, 0);
+        }
+    }
+
+    fn qualifier(&mut self, module: &Option<(EcoString, SrcSpan)>) {
+        if let Some((name, location)) = module {
+            self.push(*location, name, TokenType::Namespace, 0);
+        }
+    }
+
+    fn constant(&mut self, constant: &TypedConstant) {
+        match constant {
+            Constant::Int { .. }
+            | Constant::Float { .. }
+            | Constant::String { .. }
+            | Constant::Invalid { .. } => {}
+
+            Constant::Tuple { elements, .. } | Constant::List { elements, .. } => {
+                for element in elements {
+                    self.constant(element);
+                }
+            }
+
+            Constant::Record {
+                location,
+                module,
+                name,
+                args,
+                ..
+            } => {
+                let qualifier = module.as_ref().map(|(qualifier, _)| qualifier.as_str());
+                let modifiers = self
+                    .names
+                    .value(qualifier, name)
+                    .map(|(module, name)| self.value_modifiers(&module, &name))
+                    .unwrap_or(0);
+                self.qualifier(module);
+                let location = name_location(*location, module, name);
+                self.push(location, name, TokenType::EnumMember, modifiers);
+                for argument in args {
+                    self.call_arg_label(argument);
+                    self.constant(&argument.value);
+                }
+            }
+
+            Constant::BitArray { segments, .. } => {
+                for segment in segments {
+                    self.constant(&segment.value);
+                }
+            }
+
+            Constant::Var {
+                location,
+                module,
+                name,
+                constructor,
+                ..
+            } => {
+                let Some(constructor) = constructor else {
+                    return;
+                };
+                let type_ = match constructor.variant {
+                    ValueConstructorVariant::ModuleFn { .. } => TokenType::Function,
+                    ValueConstructorVariant::Record { .. } => TokenType::EnumMember,
+                    ValueConstructorVariant::LocalVariable { .. }
+                    | ValueConstructorVariant::LocalConstant { .. }
+                    | ValueConstructorVariant::ModuleConstant { .. } => TokenType::Variable,
+                };
+                let readonly = match type_ {
+                    TokenType::Variable => READONLY,
+                    _ => 0,
+                };
+                self.qualifier(module);
+                let location = name_location(*location, module, name);
+                let modifiers = readonly | deprecated(&constructor.deprecation);
+                self.push(location, name, type_, modifiers);
+            }
+
+            Constant::StringConcatenation { left, right, .. } => {
+                self.constant(left);
+                self.constant(right);
+            }
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for Highlighter<'_> {
+    fn visit_typed_definition(&mut self, definition: &'ast crate::ast::TypedDefinition) {
+        match definition {
+            Definition::Import(import) => self.import(import),
+            Definition::TypeAlias(alias) => {
+                let modifiers = DECLARATION | deprecated(&alias.deprecation);
+                self.binding(
+                    alias.name_location,
+                    &alias.alias,
+                    TokenType::Type,
+                    modifiers,
+                );
+                self.type_parameters(&alias.parameters);
+                self.visit_type_ast(&alias.type_ast);
+            }
+            Definition::Function(_) | Definition::CustomType(_) | Definition::ModuleConstant(_) => {
+                visit::visit_typed_definition(self, definition)
+            }
+        }
+    }
+
+    fn visit_typed_function(&mut self, function: &'ast TypedFunction) {
+        if let Some((location, name)) = &function.name {
+            let modifiers = DECLARATION | deprecated(&function.deprecation);
+            self.binding(*location, name, TokenType::Function, modifiers);
+        }
+        self.parameters.clear();
+        self.arguments(&function.arguments);
+        self.annotation(&function.return_annotation);
+        visit::visit_typed_function(self, function);
+    }
+
+    fn visit_typed_module_constant(&mut self, constant: &'ast TypedModuleConstant) {
+        let modifiers = DECLARATION | READONLY | deprecated(&constant.deprecation);
+        self.binding(
+            constant.name_location,
+            &constant.name,
+            TokenType::Variable,
+            modifiers,
+        );
+        self.annotation(&constant.annotation);
+        self.constant(&constant.value);
+    }
+
+    fn visit_typed_custom_type(&mut self, custom_type: &'ast TypedCustomType) {
+        let modifiers = DECLARATION | deprecated(&custom_type.deprecation);
+        self.binding(
+            custom_type.name_location,
+            &custom_type.name,
+            TokenType::Type,
+            modifiers,
+        );
+        self.type_parameters(&custom_type.parameters);
+
+        for constructor in &custom_type.constructors {
+            let modifiers = DECLARATION | deprecated(&constructor.deprecation);
+            self.binding(
+                constructor.name_location,
+                &constructor.name,
+                TokenType::EnumMember,
+                modifiers,
+            );
+            for argument in &constructor.arguments {
+                if let Some((location, label)) = &argument.label {
+                    self.push(*location, label, TokenType::Property, DECLARATION);
+                }
+                self.visit_type_ast(&argument.ast);
+            }
+        }
+    }
+
+    fn visit_typed_expr_var(
+        &mut self,
+        location: &'ast SrcSpan,
+        constructor: &'ast ValueConstructor,
+        name: &'ast EcoString,
+    ) {
+        let (type_, modifiers) = match &constructor.variant {
+            ValueConstructorVariant::LocalVariable {
+                location: definition,
+                ..
+            } => return self.local_variable(*location, name, *definition),
+            ValueConstructorVariant::LocalConstant { .. }
+            | ValueConstructorVariant::ModuleConstant { .. } => (TokenType::Variable, READONLY),
+            ValueConstructorVariant::ModuleFn { .. } => (TokenType::Function, 0),
+            ValueConstructorVariant::Record { .. } => (TokenType::EnumMember, 0),
+        };
+        let modifiers = modifiers | deprecated(&constructor.deprecation);
+        self.push(*location, name, type_, modifiers);
+    }
+
+    fn visit_typed_expr_module_select(
+        &mut self,
+        location: &'ast SrcSpan,
+        _type_: &'ast Arc<Type>,
+        label: &'ast EcoString,
+        module_name: &'ast EcoString,
+        module_alias: &'ast EcoString,
+        constructor: &'ast ModuleValueConstructor,
+    ) {
+        // The location of a module select doesn't include the module name, it
+        // comes right before the dot.
+        let label_start = location.end - label.len() as u32;
+        let module_end = label_start - 1;
+        let module_location = SrcSpan::new(module_end - module_alias.len() as u32, module_end);
+        self.push(module_location, module_alias, TokenType::Namespace, 0);
+
+        let type_ = match constructor {
+            ModuleValueConstructor::Record { .. } => TokenType::EnumMember,
+            ModuleValueConstructor::Fn { .. } => TokenType::Function,
+            ModuleValueConstructor::Constant { .. } => TokenType::Variable,
+        };
+        let modifiers = self.value_modifiers(module_name, label);
+        let label_location = SrcSpan::new(label_start, location.end);
+        self.push(label_location, label, type_, modifiers);
+    }
+
+    fn visit_typed_expr_fn(
+        &mut self,
+        location: &'ast SrcSpan,
+        type_: &'ast Arc<Type>,
+        kind: &'ast FunctionLiteralKind,
+        args: &'ast [TypedArg],
+        body: &'ast Vec1<TypedStatement>,
+        return_annotation: &'ast Option<TypeAst>,
+    ) {
+        self.arguments(args);
+        self.annotation(return_annotation);
+        visit::visit_typed_expr_fn(self, location, type_, kind, args, body, return_annotation);
+    }
+
+    fn visit_typed_expr_record_access(
+        &mut self,
+        location: &'ast SrcSpan,
+        type_: &'ast Arc<Type>,
+        label: &'ast EcoString,
+        index: &'ast u64,
+        record: &'ast TypedExpr,
+    ) {
+        let label_location = SrcSpan::new(location.end - label.len() as u32, location.end);
+        self.push(label_location, label, TokenType::Property, 0);
+        visit::visit_typed_expr_record_access(self, location, type_, label, index, record);
+    }
+
+    fn visit_typed_call_arg(&mut self, arg: &'ast TypedCallArg) {
+        self.call_arg_label(arg);
+        visit::visit_typed_call_arg(self, arg);
+    }
+
+    fn visit_typed_assignment(&mut self, assignment: &'ast TypedAssignment) {
+        self.annotation(&assignment.annotation);
+        visit::visit_typed_assignment(self, assignment);
+    }
+
+    fn visit_typed_use(&mut self, use_: &'ast TypedUse) {
+        for assignment in &use_.assignments {
+            self.annotation(&assignment.annotation);
+        }
+        visit::visit_typed_use(self, use_);
+    }
+
+    fn visit_typed_clause_guard_var(
+        &mut self,
+        location: &'ast SrcSpan,
+        name: &'ast EcoString,
+        _type_: &'ast Arc<Type>,
+        definition_location: &'ast SrcSpan,
+    ) {
+        self.local_variable(*location, name, *definition_location);
+    }
+
+    fn visit_typed_clause_guard_field_access(
+        &mut self,
+        location: &'ast SrcSpan,
+        index: &'ast Option<u64>,
+        label: &'ast EcoString,
+        type_: &'ast Arc<Type>,
+        container: &'ast crate::ast::TypedClauseGuard,
+    ) {
+        let label_location = SrcSpan::new(location.end - label.len() as u32, location.end);
+        self.push(label_location, label, TokenType::Property, 0);
+        visit::visit_typed_clause_guard_field_access(
+            self, location, index, label, type_, container,
+        );
+    }
+
+    fn visit_typed_clause_guard_module_select(
+        &mut self,
+        location: &'ast SrcSpan,
+        _type_: &'ast Arc<Type>,
+        label: &'ast EcoString,
+        module_name: &'ast EcoString,
+        module_alias: &'ast EcoString,
+        _literal: &'ast TypedConstant,
+    ) {
+        // The location of a guard's module select only covers the module
+        // name, the label comes after the dot.
+        self.push(*location, module_alias, TokenType::Namespace, 0);
+        let start = location.end + 1;
+        let label_location = SrcSpan::new(start, start + label.len() as u32);
+        let modifiers = self.value_modifiers(module_name, label);
+        self.push(label_location, label, TokenType::Variable, modifiers);
+    }
+
+    fn visit_typed_pattern_variable(
+        &mut self,
+        location: &'ast SrcSpan,
+        name: &'ast EcoString,
+        _type_: &'ast Arc<Type>,
+        _origin: &'ast VariableOrigin,
+    ) {
+        self.binding(*location, name, TokenType::Variable, DECLARATION);
+    }
+
+    fn visit_typed_pattern_var_usage(
+        &mut self,
+        location: &'ast SrcSpan,
+        name: &'ast EcoString,
+        constructor: &'ast Option<ValueConstructor>,
+        _type_: &'ast Arc<Type>,
+    ) {
+        let Some(ValueConstructor {
+            variant:
+                ValueConstructorVariant::LocalVariable {
+                    location: definition,
+                    ..
+                },
+            ..
+        }) = constructor
+        else {
+            return;
+        };
+        self.local_variable(*location, name, *definition);
+    }
+
+    fn visit_typed_pattern_assign(
+        &mut self,
+        location: &'ast SrcSpan,
+        name: &'ast EcoString,
+        pattern: &'ast TypedPattern,
+    ) {
+        self.binding(*location, name, TokenType::Variable, DECLARATION);
+        visit::visit_typed_pattern_assign(self, location, name, pattern);
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn visit_typed_pattern_constructor(
+        &mut self,
+        location: &'ast SrcSpan,
+        name: &'ast EcoString,
+        arguments: &'ast Vec<CallArg<TypedPattern>>,
+        module: &'ast Option<(EcoString, SrcSpan)>,
+        constructor: &'ast Inferred<PatternConstructor>,
+        spread: &'ast Option<SrcSpan>,
+        type_: &'ast Arc<Type>,
+    ) {
+        let modifiers = match constructor {
+            Inferred::Known(constructor) => {
+                self.value_modifiers(&constructor.module, &constructor.name)
+            }
+            Inferred::Unknown => 0,
+        };
+        self.qualifier(module);
+        let name_location = name_location(*location, module, name);
+        self.push(name_location, name, TokenType::EnumMember, modifiers);
+        visit::visit_typed_pattern_constructor(
+            self,
+            location,
+            name,
+            arguments,
+            module,
+            constructor,
+            spread,
+            type_,
+        );
+    }
+
+    fn visit_typed_pattern_call_arg(&mut self, arg: &'ast CallArg<TypedPattern>) {
+        self.call_arg_label(arg);
+        visit::visit_typed_pattern_call_arg(self, arg);
+    }
+
+    fn visit_typed_pattern_string_prefix(
+        &mut self,
+        _location: &'ast SrcSpan,
+        _left_location: &'ast SrcSpan,
+        left_side_assignment: &'ast Option<(EcoString, SrcSpan)>,
+        right_location: &'ast SrcSpan,
+        _left_side_string: &'ast EcoString,
+        right_side_assignment: &'ast AssignName,
+    ) {
+        if let Some((name, location)) = left_side_assignment {
+            self.binding(*location, name, TokenType::Variable, DECLARATION);
+        }
+        if let AssignName::Variable(name) = right_side_assignment {
+            self.binding(*right_location, name, TokenType::Variable, DECLARATION);
+        }
+    }
+
+    fn visit_type_ast_constructor(
+        &mut self,
+        location: &'ast SrcSpan,
+        module: &'ast Option<(EcoString, SrcSpan)>,
+        name: &'ast EcoString,
+        arguments: &'ast Vec<TypeAst>,
+    ) {
+        let qualifier = module.as_ref().map(|(qualifier, _)| qualifier.as_str());
+        let modifiers = self
+            .names
+            .type_(qualifier, name)
+            .map(|(module, name)| self.type_modifiers(&module, &name))
+            .unwrap_or(0);
+        self.qualifier(module);
+        self.push(
+            name_location(*location, module, name),
+            name,
+            TokenType::Type,
+            modifiers,
+        );
+        visit::visit_type_ast_constructor(self, location, module, name, arguments);
+    }
+
+    fn visit_type_ast_var(&mut self, location: &'ast SrcSpan, name: &'ast EcoString) {
+        self.push(*location, name, TokenType::TypeParameter, 0);
+    }
+}
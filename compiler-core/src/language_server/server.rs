@@ -6,13 +6,18 @@ use crate::{
     diagnostic::{Diagnostic, Level},
     io::{BeamCompiler, CommandExecutor, FileSystemReader, FileSystemWriter},
     language_server::{
+        call_hierarchy::ItemData,
+        code_lens::{RunArguments, RUN_COMMAND},
         engine::{self, LanguageServerEngine},
         feedback::{Feedback, FeedbackBookKeeper},
         files::FileSystemProxy,
+        path_to_uri,
         router::Router,
-        src_span_to_lsp_range, DownloadDependencies, MakeLocker,
+        semantic_tokens, src_span_to_lsp_range, CommandOutput, DownloadDependencies, MakeLocker,
+        RunGleamCommand,
     },
     line_numbers::LineNumbers,
+    paths::ProjectPaths,
     Result,
 };
 use camino::{Utf8Path, Utf8PathBuf};
@@ -20,7 +25,7 @@ use debug_ignore::DebugIgnore;
 use itertools::Itertools;
 use lsp_types::{
     self as lsp, HoverProviderCapability, InitializeParams, Position, PublishDiagnosticsParams,
-    Range, RenameOptions, TextEdit, Url,
+    Range, RenameOptions, TextEdit,
 };
 use serde_json::Value as Json;
 use std::collections::{HashMap, HashSet};
@@ -53,6 +58,7 @@ where
         + CommandExecutor
         + DownloadDependencies
         + MakeLocker
+        + RunGleamCommand
         + Clone,
 {
     pub fn new(connection: &'a lsp_server::Connection, io: IO) -> Result<Self> {
@@ -101,12 +107,24 @@ where
             Request::Format(param) => self.format(param),
             Request::Hover(param) => self.hover(param),
             Request::GoToDefinition(param) => self.goto_definition(param),
+            Request::GoToTypeDefinition(param) => self.goto_type_definition(param),
+            Request::GoToImplementation(param) => self.goto_implementation(param),
             Request::Completion(param) => self.completion(param),
             Request::CodeAction(param) => self.code_action(param),
             Request::SignatureHelp(param) => self.signature_help(param),
             Request::DocumentSymbol(param) => self.document_symbol(param),
             Request::PrepareRename(param) => self.prepare_rename(param),
             Request::Rename(param) => self.rename(param),
+            Request::References(param) => self.references(param),
+            Request::WorkspaceSymbol(param) => self.workspace_symbol(param),
+            Request::InlayHint(param) => self.inlay_hint(param),
+            Request::SemanticTokensFull(param) => self.semantic_tokens_full(param),
+            Request::SemanticTokensRange(param) => self.semantic_tokens_range(param),
+            Request::PrepareCallHierarchy(param) => self.prepare_call_hierarchy(param),
+            Request::IncomingCalls(param) => self.incoming_calls(*param),
+            Request::OutgoingCalls(param) => self.outgoing_calls(*param),
+            Request::CodeLens(param) => self.code_lens(param),
+            Request::ExecuteCommand(param) => self.execute_command(param),
         };
 
         self.publish_feedback(feedback);
@@ -308,6 +326,22 @@ where
         self.respond_with_engine(path, |engine| engine.goto_definition(params))
     }
 
+    fn goto_type_definition(
+        &mut self,
+        params: lsp::request::GotoTypeDefinitionParams,
+    ) -> (Json, Feedback) {
+        let path = super::path(&params.text_document_position_params.text_document.uri);
+        self.respond_with_engine(path, |engine| engine.goto_type_definition(params))
+    }
+
+    fn goto_implementation(
+        &mut self,
+        params: lsp::request::GotoImplementationParams,
+    ) -> (Json, Feedback) {
+        let path = super::path(&params.text_document_position_params.text_document.uri);
+        self.respond_with_engine(path, |engine| engine.goto_implementation(params))
+    }
+
     fn completion(&mut self, params: lsp::CompletionParams) -> (Json, Feedback) {
         let path = super::path(&params.text_document_position.text_document.uri);
 
@@ -345,6 +379,139 @@ where
         self.respond_with_engine(path, |engine| engine.rename(params))
     }
 
+    fn references(&mut self, params: lsp::ReferenceParams) -> (Json, Feedback) {
+        let path = super::path(&params.text_document_position.text_document.uri);
+        self.respond_with_engine(path, |engine| engine.find_references(params))
+    }
+
+    fn workspace_symbol(&mut self, params: lsp::WorkspaceSymbolParams) -> (Json, Feedback) {
+        // This request isn't about any particular document, so the symbols are
+        // searched for in the project the editor has been opened in.
+        let Some(path) = self.workspace_root() else {
+            return (Json::Null, Feedback::default());
+        };
+        self.respond_with_engine(path, |engine| engine.workspace_symbol(params))
+    }
+
+    fn inlay_hint(&mut self, params: lsp::InlayHintParams) -> (Json, Feedback) {
+        let path = super::path(&params.text_document.uri);
+        self.respond_with_engine(path, |engine| engine.inlay_hints(params))
+    }
+
+    fn semantic_tokens_full(&mut self, params: lsp::SemanticTokensParams) -> (Json, Feedback) {
+        let path = super::path(&params.text_document.uri);
+        self.respond_with_engine(path, |engine| engine.semantic_tokens_full(params))
+    }
+
+    fn semantic_tokens_range(
+        &mut self,
+        params: lsp::SemanticTokensRangeParams,
+    ) -> (Json, Feedback) {
+        let path = super::path(&params.text_document.uri);
+        self.respond_with_engine(path, |engine| engine.semantic_tokens_range(params))
+    }
+
+    fn prepare_call_hierarchy(
+        &mut self,
+        params: lsp::CallHierarchyPrepareParams,
+    ) -> (Json, Feedback) {
+        let path = super::path(&params.text_document_position_params.text_document.uri);
+        self.respond_with_engine(path, |engine| engine.prepare_call_hierarchy(params))
+    }
+
+    fn incoming_calls(
+        &mut self,
+        params: lsp::CallHierarchyIncomingCallsParams,
+    ) -> (Json, Feedback) {
+        let Some(ItemData { root, .. }) = ItemData::from_item(&params.item) else {
+            return (Json::Null, Feedback::default());
+        };
+        self.respond_with_engine(root, |engine| engine.incoming_calls(params))
+    }
+
+    fn outgoing_calls(
+        &mut self,
+        params: lsp::CallHierarchyOutgoingCallsParams,
+    ) -> (Json, Feedback) {
+        let Some(ItemData { root, .. }) = ItemData::from_item(&params.item) else {
+            return (Json::Null, Feedback::default());
+        };
+        self.respond_with_engine(root, |engine| engine.outgoing_calls(params))
+    }
+
+    fn code_lens(&mut self, params: lsp::CodeLensParams) -> (Json, Feedback) {
+        let path = super::path(&params.text_document.uri);
+        self.respond_with_engine(path, |engine| engine.code_lens(params))
+    }
+
+    fn execute_command(&mut self, params: lsp::ExecuteCommandParams) -> (Json, Feedback) {
+        let arguments = match params.command.as_str() {
+            RUN_COMMAND => RunArguments::from_arguments(&params.arguments),
+            _ => None,
+        };
+        let Some(arguments) = arguments else {
+            return (Json::Null, Feedback::default());
+        };
+        // The root comes from the client, so nothing is run unless it's one
+        // of the projects the server has opened, which are the only ones it
+        // could have offered a run lens for.
+        if !self.router.is_project_root(&arguments.root) {
+            return (Json::Null, Feedback::default());
+        }
+
+        // The command may take a long time to finish so it's run in the
+        // background, its output being sent to the client as log messages
+        // while the server keeps handling requests.
+        let paths = ProjectPaths::new(arguments.root.clone());
+        let command_line = arguments.command_line();
+        let command = format!("gleam {}", command_line.join(" "));
+        let sender = self.connection.sender.clone();
+        let output = Box::new(move |output| {
+            let (typ, message) = match output {
+                CommandOutput::Line(line) => (lsp::MessageType::LOG, line),
+                CommandOutput::Exited(0) => (
+                    lsp::MessageType::INFO,
+                    format!("`{command}` finished successfully"),
+                ),
+                CommandOutput::Exited(code) => (
+                    lsp::MessageType::ERROR,
+                    format!("`{command}` exited with code {code}"),
+                ),
+            };
+            let params = lsp::LogMessageParams { typ, message };
+            let notification = lsp_server::Notification {
+                method: "window/logMessage".into(),
+                params: serde_json::to_value(params).expect("window/logMessage to json"),
+            };
+            // The client may have gone away while the command was running, in
+            // which case there's no one left to send the output to.
+            let _ = sender.send(lsp_server::Message::Notification(notification));
+        });
+
+        match self
+            .io
+            .inner()
+            .run_gleam_command(&paths, command_line, output)
+        {
+            Ok(()) => (Json::Null, Feedback::default()),
+            Err(error) => (Json::Null, self.outside_of_project_feedback.error(error)),
+        }
+    }
+
+    fn workspace_root(&self) -> Option<Utf8PathBuf> {
+        if let Some(folder) = self
+            .initialise_params
+            .workspace_folders
+            .iter()
+            .flatten()
+            .next()
+        {
+            return Some(super::path(&folder.uri));
+        }
+        #[allow(deprecated)]
+        self.initialise_params.root_uri.as_ref().map(super::path)
+    }
+
     fn cache_file_in_memory(&mut self, path: Utf8PathBuf, text: String) -> Feedback {
         self.project_changed(&path);
         if let Err(error) = self.io.write_mem_cache(&path, &text) {
@@ -418,14 +585,16 @@ fn initialisation_handshake(connection: &lsp_server::Connection) -> InitializePa
             },
         }),
         definition_provider: Some(lsp::OneOf::Left(true)),
-        type_definition_provider: None,
-        implementation_provider: None,
-        references_provider: None,
+        type_definition_provider: Some(lsp::TypeDefinitionProviderCapability::Simple(true)),
+        implementation_provider: Some(lsp::ImplementationProviderCapability::Simple(true)),
+        references_provider: Some(lsp::OneOf::Left(true)),
         document_highlight_provider: None,
         document_symbol_provider: Some(lsp::OneOf::Left(true)),
-        workspace_symbol_provider: None,
+        workspace_symbol_provider: Some(lsp::OneOf::Left(true)),
         code_action_provider: Some(lsp::CodeActionProviderCapability::Simple(true)),
-        code_lens_provider: None,
+        code_lens_provider: Some(lsp::CodeLensOptions {
+            resolve_provider: Some(false),
+        }),
         document_formatting_provider: Some(lsp::OneOf::Left(true)),
         document_range_formatting_provider: None,
         document_on_type_formatting_provider: None,
@@ -439,16 +608,32 @@ fn initialisation_handshake(connection: &lsp_server::Connection) -> InitializePa
         color_provider: None,
         folding_range_provider: None,
         declaration_provider: None,
-        execute_command_provider: None,
+        execute_command_provider: Some(lsp::ExecuteCommandOptions {
+            commands: vec![RUN_COMMAND.into()],
+            work_done_progress_options: lsp::WorkDoneProgressOptions {
+                work_done_progress: None,
+            },
+        }),
         workspace: None,
-        call_hierarchy_provider: None,
-        semantic_tokens_provider: None,
+        call_hierarchy_provider: Some(lsp::CallHierarchyServerCapability::Simple(true)),
+        semantic_tokens_provider: Some(
+            lsp::SemanticTokensServerCapabilities::SemanticTokensOptions(
+                lsp::SemanticTokensOptions {
+                    work_done_progress_options: lsp::WorkDoneProgressOptions {
+                        work_done_progress: None,
+                    },
+                    legend: semantic_tokens::legend(),
+                    range: Some(true),
+                    full: Some(lsp::SemanticTokensFullOptions::Bool(true)),
+                },
+            ),
+        ),
         moniker_provider: None,
         linked_editing_range_provider: None,
         experimental: None,
         position_encoding: None,
         inline_value_provider: None,
-        inlay_hint_provider: None,
+        inlay_hint_provider: Some(lsp::OneOf::Left(true)),
         diagnostic_provider: None,
     };
     let server_capabilities_json =
@@ -544,9 +729,3 @@ fn diagnostic_to_lsp(diagnostic: Diagnostic) -> Vec<lsp::Diagnostic> {
         None => vec![main],
     }
 }
-
-fn path_to_uri(path: Utf8PathBuf) -> Url {
-    let mut file: String = "file://".into();
-    file.push_str(&path.as_os_str().to_string_lossy());
-    Url::parse(&file).expect("path_to_uri URL parse")
-}
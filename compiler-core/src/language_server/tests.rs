@@ -1,11 +1,20 @@
 mod action;
+mod call_hierarchy;
+mod code_lens;
 mod compilation;
 mod completion;
 mod definition;
 mod document_symbols;
 mod hover;
+mod implementation;
+mod inlay_hints;
+mod references;
 mod rename;
+mod run_command;
+mod semantic_tokens;
 mod signature_help;
+mod type_definition;
+mod workspace_symbols;
 
 use std::{
     collections::{HashMap, HashSet},
@@ -28,7 +37,7 @@ use crate::{
     },
     language_server::{
         engine::LanguageServerEngine, files::FileSystemProxy, progress::ProgressReporter,
-        DownloadDependencies, LockGuard, Locker, MakeLocker,
+        CommandOutput, DownloadDependencies, LockGuard, Locker, MakeLocker, RunGleamCommand,
     },
     line_numbers::LineNumbers,
     manifest::{Base16Checksum, Manifest, ManifestPackage, ManifestPackageSource},
@@ -48,6 +57,10 @@ enum Action {
     DownloadDependencies,
     LockBuild,
     UnlockBuild,
+    RunGleamCommand {
+        root: Utf8PathBuf,
+        arguments: Vec<String>,
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -56,6 +69,8 @@ struct LanguageServerTestIO {
     paths: ProjectPaths,
     actions: Arc<Mutex<Vec<Action>>>,
     manifest: Manifest,
+    /// What the `gleam` commands run by the language server print.
+    gleam_command_output: Vec<CommandOutput>,
 }
 
 impl LanguageServerTestIO {
@@ -68,6 +83,7 @@ impl LanguageServerTestIO {
                 requirements: HashMap::new(),
                 packages: vec![],
             },
+            gleam_command_output: vec![],
         }
     }
 
@@ -221,6 +237,24 @@ impl CommandExecutor for LanguageServerTestIO {
     }
 }
 
+impl RunGleamCommand for LanguageServerTestIO {
+    fn run_gleam_command(
+        &self,
+        paths: &ProjectPaths,
+        arguments: Vec<String>,
+        output: Box<dyn FnMut(CommandOutput) + Send>,
+    ) -> Result<()> {
+        self.record(Action::RunGleamCommand {
+            root: paths.root().to_path_buf(),
+            arguments,
+        });
+        // The output is passed on straight away rather than as the command
+        // runs, so it's all sent to the client before the server responds.
+        self.gleam_command_output.iter().cloned().for_each(output);
+        Ok(())
+    }
+}
+
 impl BeamCompiler for LanguageServerTestIO {
     fn compile_beam(
         &self,
@@ -384,6 +418,7 @@ struct TestProject<'a> {
     hex_modules: Vec<(&'a str, &'a str)>,
     dev_hex_modules: Vec<(&'a str, &'a str)>,
     indirect_hex_modules: Vec<(&'a str, &'a str)>,
+    native_files: Vec<(&'a str, &'a str)>,
 }
 
 impl<'a> TestProject<'a> {
@@ -396,13 +431,14 @@ impl<'a> TestProject<'a> {
             hex_modules: vec![],
             dev_hex_modules: vec![],
             indirect_hex_modules: vec![],
+            native_files: vec![],
         }
     }
 
     pub fn src_from_module_url(&self, url: &Url) -> Option<&str> {
         let module_name: EcoString = url
             .path_segments()?
-            .skip_while(|segment| *segment != "src")
+            .skip_while(|segment| *segment != "src" && *segment != "test")
             .skip(1)
             .join("/")
             .trim_end_matches(".gleam")
@@ -457,6 +493,13 @@ impl<'a> TestProject<'a> {
         self
     }
 
+    /// Adds a non-Gleam file to the root package, at the given path relative
+    /// to its `src` directory.
+    pub fn add_native_file(mut self, path: &'a str, src: &'a str) -> Self {
+        self.native_files.push((path, src));
+        self
+    }
+
     pub fn build_engine(
         &self,
         io: &mut LanguageServerTestIO,
@@ -491,6 +534,11 @@ impl<'a> TestProject<'a> {
         self.test_modules.iter().for_each(|(name, code)| {
             let _ = io.test_module(name, code);
         });
+
+        // Add all the native files belonging to the root package
+        self.native_files.iter().for_each(|(path, code)| {
+            io.module(&io.paths.src_directory().join(path), code);
+        });
         for package in &io.manifest.packages {
             let toml_path = engine.paths.build_packages_package_config(&package.name);
             add_package_from_manifest(&mut engine, toml_path, package.clone());
@@ -728,6 +776,36 @@ fn byte_index_to_position(src: &str, byte_index: usize) -> Position {
     Position::new(line, col)
 }
 
+/// Shows the code with the given ranges underlined.
+pub fn show_ranges(code: &str, ranges: &[lsp_types::Range]) -> String {
+    let mut buffer = String::new();
+    for (line_number, line) in code.lines().enumerate() {
+        let mut underline = String::new();
+        let mut underline_empty = true;
+
+        for (column_number, _) in line.chars().enumerate() {
+            let position = Position::new(line_number as u32, column_number as u32);
+            if ranges
+                .iter()
+                .any(|range| range.start <= position && position < range.end)
+            {
+                underline_empty = false;
+                underline.push('▔');
+            } else {
+                underline.push(' ');
+            }
+        }
+
+        buffer.push_str(line);
+        if !underline_empty {
+            buffer.push('\n');
+            buffer.push_str(underline.trim_end());
+        }
+        buffer.push('\n');
+    }
+    buffer
+}
+
 /// This function replicates how the text editor applies TextEdit.
 ///
 pub fn apply_code_edit(src: &str, mut change: Vec<lsp_types::TextEdit>) -> String {
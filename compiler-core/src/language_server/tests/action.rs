@@ -74,6 +74,9 @@ const GENERATE_DYNAMIC_DECODER: &str = "Generate dynamic decoder";
 const PATTERN_MATCH_ON_ARGUMENT: &str = "Pattern match on argument";
 const PATTERN_MATCH_ON_VARIABLE: &str = "Pattern match on variable";
 const GENERATE_FUNCTION: &str = "Generate function";
+const EXTRACT_FUNCTION: &str = "Extract function";
+const INLINE_VARIABLE: &str = "Inline variable";
+const INLINE_FUNCTION_CALL: &str = "Inline function call";
 
 macro_rules! assert_code_action {
     ($title:expr, $code:literal, $range:expr $(,)?) => {
@@ -4854,3 +4857,646 @@ pub type Names {
         find_position_of("[").to_selection()
     );
 }
+
+#[test]
+fn extract_function_from_expression() {
+    assert_code_action!(
+        EXTRACT_FUNCTION,
+        "
+pub fn main() {
+  let wibble = 1
+  let wobble = wibble + 2
+  wobble
+}
+",
+        find_position_of("wibble +").select_until(find_position_of("+ 2").with_char_offset(3))
+    );
+}
+
+#[test]
+fn extract_function_from_statements() {
+    assert_code_action!(
+        EXTRACT_FUNCTION,
+        r#"
+pub fn main(wibble: String) {
+  let wobble = 1
+  log(wibble)
+  log(wobble)
+}
+
+fn log(a) { a }
+"#,
+        find_position_of("log(wibble)")
+            .select_until(find_position_of("log(wobble)").with_char_offset(11))
+    );
+}
+
+#[test]
+fn extract_function_returns_variables_used_after_selection() {
+    assert_code_action!(
+        EXTRACT_FUNCTION,
+        "
+pub fn main() {
+  let wibble = 1
+  let wobble = wibble + 1
+  wobble
+}
+",
+        find_position_of("let wibble")
+            .select_until(find_position_of("wibble + 1").with_char_offset(10))
+    );
+}
+
+#[test]
+fn extract_function_returns_multiple_variables_as_tuple() {
+    assert_code_action!(
+        EXTRACT_FUNCTION,
+        r#"
+pub fn main() {
+  let wibble = 1
+  let wobble = "a"
+  #(wibble, wobble)
+}
+"#,
+        find_position_of("let wibble").select_until(find_position_of("\"a\"").with_char_offset(3))
+    );
+}
+
+#[test]
+fn extract_function_reindents_multiline_code() {
+    assert_code_action!(
+        EXTRACT_FUNCTION,
+        "
+pub fn main(x) {
+  case x {
+    1 -> {
+      let y = x + 1
+      let z = [
+        y,
+        x,
+      ]
+      z
+    }
+    _ -> []
+  }
+}
+",
+        find_position_of("let y").select_until(find_position_of("      z\n").with_char_offset(7))
+    );
+}
+
+#[test]
+fn extract_function_with_clause_guard_variable() {
+    assert_code_action!(
+        EXTRACT_FUNCTION,
+        "
+pub fn main(x, limit) {
+  case x {
+    n if n > limit -> n
+    _ -> 0
+  }
+}
+",
+        find_position_of("case").select_until(find_position_of("  }\n}").with_char_offset(3))
+    );
+}
+
+#[test]
+fn extract_function_with_bit_array_size_variable() {
+    assert_code_action!(
+        EXTRACT_FUNCTION,
+        "
+pub fn main(bits, size) {
+  case bits {
+    <<x:size(size)>> -> x
+    _ -> 0
+  }
+}
+",
+        find_position_of("case").select_until(find_position_of("  }\n}").with_char_offset(3))
+    );
+}
+
+#[test]
+fn extract_function_avoids_name_clashes() {
+    assert_code_action!(
+        EXTRACT_FUNCTION,
+        "
+pub fn main() {
+  let function = 1
+  function + 2
+}
+
+fn function_2() { 1 }
+",
+        find_position_of("function +").select_until(find_position_of("2\n").with_char_offset(1))
+    );
+}
+
+#[test]
+fn extract_function_with_use() {
+    assert_code_action!(
+        EXTRACT_FUNCTION,
+        "
+pub fn main() {
+  let x = 1
+  use y <- apply(x)
+  y + 1
+}
+
+fn apply(a, f) { f(a) }
+",
+        find_position_of("use").select_until(find_position_of("y + 1").with_char_offset(5))
+    );
+}
+
+#[test]
+fn extract_function_does_not_extract_part_of_a_statement() {
+    assert_no_code_actions!(
+        EXTRACT_FUNCTION,
+        "
+pub fn main() {
+  let wibble = 1
+  let wobble = wibble + 2
+  wobble
+}
+",
+        find_position_of("= 1").select_until(find_position_of("wibble +"))
+    );
+}
+
+#[test]
+fn extract_function_does_not_extract_pipeline_step() {
+    assert_no_code_actions!(
+        EXTRACT_FUNCTION,
+        "
+pub fn main() {
+  [1, 2]
+  |> map(todo)
+}
+
+fn map(list, fun) { todo }
+",
+        find_position_of("map(").select_until(find_position_of("todo)").with_char_offset(5))
+    );
+}
+
+#[test]
+fn extract_function_does_nothing_without_selection() {
+    assert_no_code_actions!(
+        EXTRACT_FUNCTION,
+        "
+pub fn main() {
+  1 + 2
+}
+",
+        find_position_of("1").to_selection()
+    );
+}
+
+#[test]
+fn inline_variable() {
+    assert_code_action!(
+        INLINE_VARIABLE,
+        "
+pub fn main() {
+  let wibble = [1, 2]
+  let wobble = wibble
+  #(wibble, wobble)
+}
+",
+        find_position_of("wibble").to_selection()
+    );
+}
+
+#[test]
+fn inline_variable_from_its_use() {
+    assert_code_action!(
+        INLINE_VARIABLE,
+        r#"
+pub fn main() {
+  let wibble = "wibble"
+  wibble <> "wobble"
+}
+"#,
+        find_position_of("wibble <>").to_selection()
+    );
+}
+
+#[test]
+fn inline_variable_groups_operators() {
+    assert_code_action!(
+        INLINE_VARIABLE,
+        "
+pub fn main(a, b) {
+  let wibble = a + b
+  wibble * 2
+}
+",
+        find_position_of("wibble").to_selection()
+    );
+}
+
+#[test]
+fn inline_variable_with_label_shorthand() {
+    assert_code_action!(
+        INLINE_VARIABLE,
+        "
+pub type Wibble {
+  Wibble(wobble: Int)
+}
+
+pub fn main() {
+  let wobble = 1
+  Wibble(wobble:)
+}
+",
+        find_position_of("wobble =").to_selection()
+    );
+}
+
+#[test]
+fn inline_variable_does_not_inline_side_effects() {
+    assert_no_code_actions!(
+        INLINE_VARIABLE,
+        "
+pub fn main() {
+  let wibble = wobble()
+  wibble
+}
+
+fn wobble() { 1 }
+",
+        find_position_of("wibble").to_selection()
+    );
+}
+
+#[test]
+fn inline_variable_does_not_inline_last_statement() {
+    assert_no_code_actions!(
+        INLINE_VARIABLE,
+        "
+pub fn main() {
+  let wibble = 1
+}
+",
+        find_position_of("wibble").to_selection()
+    );
+}
+
+#[test]
+fn inline_variable_does_not_inline_variable_used_in_guard() {
+    assert_no_code_actions!(
+        INLINE_VARIABLE,
+        "
+pub fn main(x) {
+  let wibble = x + 1
+  case x {
+    _ if wibble > 2 -> 1
+    _ -> 2
+  }
+}
+",
+        find_position_of("wibble").to_selection()
+    );
+}
+
+#[test]
+fn inline_variable_does_not_inline_variable_used_as_bit_array_size() {
+    assert_no_code_actions!(
+        INLINE_VARIABLE,
+        "
+pub fn main(bits) {
+  let size = 4 + 4
+  case bits {
+    <<x:size(size), _:bits>> -> x
+    _ -> 0
+  }
+}
+",
+        find_position_of("size =").to_selection()
+    );
+}
+
+#[test]
+fn inline_variable_does_not_inline_shadowed_value() {
+    assert_no_code_actions!(
+        INLINE_VARIABLE,
+        "
+pub fn main(x) {
+  let wibble = x + 1
+  let x = 10
+  wibble + x
+}
+",
+        find_position_of("wibble").to_selection()
+    );
+}
+
+#[test]
+fn inline_function_call() {
+    assert_code_action!(
+        INLINE_FUNCTION_CALL,
+        "
+pub fn main() {
+  double(3) + 1
+}
+
+fn double(value: Int) -> Int {
+  value * 2
+}
+",
+        find_position_of("double").to_selection()
+    );
+}
+
+#[test]
+fn inline_function_call_with_labelled_arguments() {
+    assert_code_action!(
+        INLINE_FUNCTION_CALL,
+        "
+pub fn main() {
+  subtract(from: 10, value: 1 + 2)
+}
+
+fn subtract(value value: Int, from from: Int) -> Int {
+  from - value
+}
+",
+        find_position_of("subtract").to_selection()
+    );
+}
+
+#[test]
+fn inline_function_call_with_positional_and_labelled_arguments_out_of_order() {
+    assert_code_action!(
+        INLINE_FUNCTION_CALL,
+        "
+pub fn main() {
+  combine(1, third: 3, second: 2)
+}
+
+fn combine(first: Int, second second: Int, third third: Int) -> Int {
+  first - second * third
+}
+",
+        find_position_of("combine").to_selection()
+    );
+}
+
+#[test]
+fn inline_function_call_with_label_shorthand() {
+    assert_code_action!(
+        INLINE_FUNCTION_CALL,
+        "
+pub type Wibble {
+  Wibble(wobble: Int)
+}
+
+pub fn main() {
+  let wobble = 1
+  make(wobble:)
+}
+
+fn make(wobble wobble: Int) -> Wibble {
+  Wibble(wobble:)
+}
+",
+        find_position_of("make").to_selection()
+    );
+}
+
+#[test]
+fn inline_function_call_multiline_body() {
+    assert_code_action!(
+        INLINE_FUNCTION_CALL,
+        "
+pub fn main(x) {
+  let y = describe(x)
+  y
+}
+
+fn describe(number) {
+  case number {
+    0 -> \"zero\"
+    _ -> \"other\"
+  }
+}
+",
+        find_position_of("describe").to_selection()
+    );
+}
+
+#[test]
+fn inline_function_call_with_side_effects_used_once_in_order() {
+    assert_code_action!(
+        INLINE_FUNCTION_CALL,
+        "
+pub fn main() {
+  subtract(wibble(), wobble())
+}
+
+fn subtract(a: Int, b: Int) -> Int {
+  a - b
+}
+
+fn wibble() { 1 }
+
+fn wobble() { 2 }
+",
+        find_position_of("subtract").to_selection()
+    );
+}
+
+#[test]
+fn inline_function_call_binds_side_effects_used_more_than_once() {
+    assert_code_action!(
+        INLINE_FUNCTION_CALL,
+        "
+pub fn main() {
+  double(wibble())
+}
+
+fn double(value: Int) -> Int {
+  value + value
+}
+
+fn wibble() { 1 }
+",
+        find_position_of("double").to_selection()
+    );
+}
+
+#[test]
+fn inline_function_call_binds_side_effects_used_out_of_order() {
+    assert_code_action!(
+        INLINE_FUNCTION_CALL,
+        "
+pub fn main() {
+  subtract(wibble(), wobble())
+}
+
+fn subtract(a: Int, b: Int) -> Int {
+  b - a
+}
+
+fn wibble() { 1 }
+
+fn wobble() { 2 }
+",
+        find_position_of("subtract").to_selection()
+    );
+}
+
+#[test]
+fn inline_function_call_binds_side_effects_used_in_case_clause() {
+    assert_code_action!(
+        INLINE_FUNCTION_CALL,
+        "
+pub fn main(x) {
+  describe(x, wibble())
+}
+
+fn describe(number: Int, other: Int) -> Int {
+  case number {
+    0 -> other
+    _ -> 1
+  }
+}
+
+fn wibble() { 1 }
+",
+        find_position_of("describe").to_selection()
+    );
+}
+
+#[test]
+fn inline_function_call_binds_side_effects_used_in_anonymous_function() {
+    assert_code_action!(
+        INLINE_FUNCTION_CALL,
+        "
+pub fn main() {
+  later(wibble())
+}
+
+fn later(value: Int) -> fn() -> Int {
+  fn() { value }
+}
+
+fn wibble() { 1 }
+",
+        find_position_of("later").to_selection()
+    );
+}
+
+#[test]
+fn inline_function_call_binds_side_effects_used_after_boolean_operator() {
+    assert_code_action!(
+        INLINE_FUNCTION_CALL,
+        "
+pub fn main(x) {
+  both(x, wibble())
+}
+
+fn both(a: Bool, b: Bool) -> Bool {
+  a && b
+}
+
+fn wibble() { True }
+",
+        find_position_of("both").to_selection()
+    );
+}
+
+#[test]
+fn inline_function_call_binds_argument_used_as_bit_array_size() {
+    assert_code_action!(
+        INLINE_FUNCTION_CALL,
+        "
+pub fn main(bits) {
+  first(bits, 4 + 4)
+}
+
+fn first(bits: BitArray, size: Int) -> Int {
+  case bits {
+    <<x:size(size), _:bits>> -> x
+    _ -> 0
+  }
+}
+",
+        find_position_of("first").to_selection()
+    );
+}
+
+#[test]
+fn inline_function_call_does_not_shadow_argument_with_binding() {
+    assert_no_code_actions!(
+        INLINE_FUNCTION_CALL,
+        "
+pub fn main(b) {
+  subtract(b, wibble())
+}
+
+fn subtract(a: Int, b: Int) -> Int {
+  a - b - b
+}
+
+fn wibble() { 1 }
+",
+        find_position_of("subtract").to_selection()
+    );
+}
+
+#[test]
+fn inline_function_call_does_not_inline_public_function() {
+    assert_no_code_actions!(
+        INLINE_FUNCTION_CALL,
+        "
+pub fn main() {
+  double(1)
+}
+
+pub fn double(value: Int) -> Int {
+  value * 2
+}
+",
+        find_position_of("double").to_selection()
+    );
+}
+
+#[test]
+fn inline_function_call_does_not_inline_shadowed_module_value() {
+    assert_no_code_actions!(
+        INLINE_FUNCTION_CALL,
+        "
+pub fn main() {
+  let one = 2
+  add_one(one)
+}
+
+fn add_one(value: Int) -> Int {
+  value + one()
+}
+
+fn one() { 1 }
+",
+        find_position_of("add_one").to_selection()
+    );
+}
+
+#[test]
+fn inline_function_call_does_not_inline_pipeline_step() {
+    assert_no_code_actions!(
+        INLINE_FUNCTION_CALL,
+        "
+pub fn main() {
+  1 |> double
+}
+
+fn double(value: Int) -> Int {
+  value * 2
+}
+",
+        find_position_of("double").to_selection()
+    );
+}
@@ -0,0 +1,341 @@
+use lsp_types::{
+    CallHierarchyIncomingCallsParams, CallHierarchyItem, CallHierarchyOutgoingCallsParams,
+    CallHierarchyPrepareParams, Position, Range,
+};
+
+use super::*;
+
+fn prepare(tester: &TestProject<'_>, position: Position) -> Option<Vec<CallHierarchyItem>> {
+    tester.at(position, |engine, param, _| {
+        let params = CallHierarchyPrepareParams {
+            text_document_position_params: param,
+            work_done_progress_params: Default::default(),
+        };
+        engine.prepare_call_hierarchy(params).result.unwrap()
+    })
+}
+
+fn prepared_item(tester: &TestProject<'_>, position: Position) -> CallHierarchyItem {
+    let mut items = prepare(tester, position).expect("a function under the cursor");
+    assert_eq!(items.len(), 1);
+    items.remove(0)
+}
+
+fn module_name(item: &CallHierarchyItem) -> String {
+    item.detail.clone().expect("a module name")
+}
+
+fn pretty_incoming_calls(project: TestProject<'_>, position_finder: PositionFinder) -> String {
+    let position = position_finder.find_position(project.src);
+    let item = prepared_item(&project, position);
+
+    let incoming = project.at(position, |engine, _, _| {
+        let params = CallHierarchyIncomingCallsParams {
+            item: item.clone(),
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+        };
+        engine.incoming_calls(params).result.unwrap()
+    });
+
+    let mut output = format!("----- Calls to `{}.{}`\n", module_name(&item), item.name);
+    for call in incoming.expect("incoming calls") {
+        let src = project
+            .src_from_module_url(&call.from.uri)
+            .expect("a calling module");
+        output.push_str(&format!(
+            "----- From `{}.{}`\n{}",
+            module_name(&call.from),
+            call.from.name,
+            show_ranges(src, &call.from_ranges)
+        ));
+    }
+    output
+}
+
+fn pretty_outgoing_calls(project: TestProject<'_>, position_finder: PositionFinder) -> String {
+    let position = position_finder.find_position(project.src);
+    let item = prepared_item(&project, position);
+
+    let outgoing = project.at(position, |engine, _, _| {
+        let params = CallHierarchyOutgoingCallsParams {
+            item: item.clone(),
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+        };
+        engine.outgoing_calls(params).result.unwrap()
+    });
+
+    let mut output = format!(
+        "----- Calls from `{}.{}`\n{}",
+        module_name(&item),
+        item.name,
+        show_ranges(project.src, &[])
+    );
+    for call in outgoing.expect("outgoing calls") {
+        let ranges = call
+            .from_ranges
+            .iter()
+            .map(|range| format!("{}:{}", range.start.line, range.start.character))
+            .join(", ");
+        let destination = project
+            .src_from_module_url(&call.to.uri)
+            .map(|src| show_ranges(src, &[call.to.selection_range]))
+            .unwrap_or_default();
+        output.push_str(&format!(
+            "----- To `{}.{}` at {ranges}\n{destination}",
+            module_name(&call.to),
+            call.to.name,
+        ));
+    }
+    output
+}
+
+macro_rules! assert_incoming_calls {
+    ($src:literal, $position:expr) => {
+        let project = TestProject::for_source($src);
+        assert_incoming_calls!(project, $position);
+    };
+    ($project:expr, $position:expr) => {
+        let output = pretty_incoming_calls($project, $position);
+        insta::assert_snapshot!(insta::internals::AutoName, output);
+    };
+}
+
+macro_rules! assert_outgoing_calls {
+    ($src:literal, $position:expr) => {
+        let project = TestProject::for_source($src);
+        assert_outgoing_calls!(project, $position);
+    };
+    ($project:expr, $position:expr) => {
+        let output = pretty_outgoing_calls($project, $position);
+        insta::assert_snapshot!(insta::internals::AutoName, output);
+    };
+}
+
+#[test]
+fn prepare_call_hierarchy_on_function_definition() {
+    let code = "
+pub fn wibble() {
+  1
+}
+";
+    let position = find_position_of("wibble").find_position(code);
+    let item = prepared_item(&TestProject::for_source(code), position);
+    assert_eq!(item.name, "wibble");
+    assert_eq!(
+        item.selection_range,
+        Range::new(Position::new(1, 7), Position::new(1, 13))
+    );
+    assert_eq!(
+        item.range,
+        Range::new(Position::new(1, 0), Position::new(3, 1))
+    );
+}
+
+#[test]
+fn prepare_call_hierarchy_on_local_variable() {
+    let code = "
+pub fn main() {
+  let wibble = 1
+  wibble
+}
+";
+    let position = find_position_of("wibble")
+        .nth_occurrence(2)
+        .find_position(code);
+    assert_eq!(prepare(&TestProject::for_source(code), position), None);
+}
+
+#[test]
+fn prepare_call_hierarchy_on_record_constructor() {
+    let code = "
+pub type Wibble {
+  Wibble
+}
+
+pub fn main() {
+  Wibble
+}
+";
+    let position = find_position_of("Wibble")
+        .nth_occurrence(3)
+        .find_position(code);
+    assert_eq!(prepare(&TestProject::for_source(code), position), None);
+}
+
+#[test]
+fn incoming_calls_in_same_module() {
+    assert_incoming_calls!(
+        "
+pub fn wibble() {
+  1
+}
+
+pub fn wobble() {
+  wibble() + wibble()
+}
+
+pub fn main() {
+  wibble()
+}
+",
+        find_position_of("wibble")
+    );
+}
+
+#[test]
+fn incoming_calls_from_other_modules() {
+    let code = "
+import wibble.{wobble}
+
+pub fn main() {
+  wibble.wobble()
+  wobble()
+}
+";
+
+    assert_incoming_calls!(
+        TestProject::for_source(code)
+            .add_module("wibble", "pub fn wobble() { 1 }")
+            .add_module(
+                "wubble",
+                "import wibble as w
+
+pub fn wubble() {
+  w.wobble()
+}
+"
+            )
+            .add_test_module(
+                "wibble_test",
+                "import wibble
+
+pub fn wobble_test() {
+  wibble.wobble()
+}
+"
+            ),
+        find_position_of("wobble()").under_char('o')
+    );
+}
+
+#[test]
+fn incoming_calls_through_pipelines_use_and_captures() {
+    assert_incoming_calls!(
+        "
+pub fn wibble(a, f) {
+  f(a)
+}
+
+pub fn pipeline() {
+  1 |> wibble(fn(x) { x })
+}
+
+pub fn use_() {
+  use x <- wibble(1)
+  x
+}
+
+pub fn capture() {
+  wibble(1, _)
+}
+",
+        find_position_of("wibble")
+    );
+}
+
+#[test]
+fn incoming_calls_do_not_include_references() {
+    assert_incoming_calls!(
+        "
+pub fn wibble() {
+  1
+}
+
+pub fn main() {
+  let f = wibble
+  f()
+}
+",
+        find_position_of("wibble")
+    );
+}
+
+#[test]
+fn incoming_calls_to_dependency_function() {
+    let code = "
+import wibble
+
+pub fn main() {
+  wibble.wobble()
+}
+";
+
+    assert_incoming_calls!(
+        TestProject::for_source(code).add_hex_module("wibble", "pub fn wobble() { 1 }"),
+        find_position_of("wobble")
+    );
+}
+
+#[test]
+fn outgoing_calls() {
+    let code = "
+import wibble
+
+pub fn main() {
+  wibble.wobble()
+  |> add(1)
+  |> add(wibble.wobble())
+  |> wibble.wubble
+  |> Ok
+}
+
+fn add(a, b) {
+  a + b
+}
+";
+
+    assert_outgoing_calls!(
+        TestProject::for_source(code).add_module(
+            "wibble",
+            "pub fn wobble() { 1 }
+pub fn wubble(x) { x }"
+        ),
+        find_position_of("main")
+    );
+}
+
+#[test]
+fn outgoing_calls_from_called_function() {
+    let code = "
+pub fn main() {
+  wibble()
+}
+
+fn wibble() {
+  wobble()
+}
+
+fn wobble() {
+  1
+}
+";
+    let project = TestProject::for_source(code);
+    let position = find_position_of("wibble()").find_position(code);
+    let item = prepared_item(&project, position);
+    let outgoing = project.at(position, |engine, _, _| {
+        let params = CallHierarchyOutgoingCallsParams {
+            item,
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+        };
+        engine.outgoing_calls(params).result.unwrap()
+    });
+    let called = outgoing
+        .expect("outgoing calls")
+        .into_iter()
+        .map(|call| call.to.name)
+        .collect_vec();
+    assert_eq!(called, vec!["wobble"]);
+}
@@ -0,0 +1,209 @@
+use lsp_types::{CodeLens, CodeLensParams, Position, TextDocumentIdentifier};
+
+use crate::language_server::code_lens::RunArguments;
+
+use super::*;
+
+fn code_lenses(
+    engine: &mut LanguageServerEngine<LanguageServerTestIO, LanguageServerTestIO>,
+    document: TextDocumentIdentifier,
+) -> Vec<CodeLens> {
+    let params = CodeLensParams {
+        text_document: document,
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+    };
+    engine.code_lens(params).result.unwrap()
+}
+
+/// Shows the code with each line preceded by the lenses that are shown above
+/// it, along with the command each of them runs.
+fn show_lenses(code: &str, lenses: Vec<CodeLens>) -> String {
+    let mut buffer = String::new();
+    for (line_number, line) in code.lines().enumerate() {
+        for lens in &lenses {
+            if lens.range.start.line as usize != line_number {
+                continue;
+            }
+            let command = lens.command.as_ref().expect("a lens command");
+            let arguments = RunArguments::from_arguments(
+                command.arguments.as_deref().expect("command arguments"),
+            )
+            .expect("run arguments");
+            buffer.push_str(&format!(
+                "⟨{}⟩ gleam {}\n",
+                command.title,
+                arguments.command_line().join(" ")
+            ));
+        }
+        buffer.push_str(line);
+        buffer.push('\n');
+    }
+    buffer
+}
+
+fn pretty_src_lenses(project: TestProject<'_>) -> String {
+    let lenses = project.at(Position::default(), |engine, params, _| {
+        code_lenses(engine, params.text_document)
+    });
+    show_lenses(project.src, lenses)
+}
+
+fn pretty_test_lenses(project: TestProject<'_>, test_name: &str, test_src: &str) -> String {
+    let (mut engine, params) = project.positioned_with_io_in_test(Position::default(), test_name);
+    let lenses = code_lenses(&mut engine, params.text_document);
+    show_lenses(test_src, lenses)
+}
+
+macro_rules! assert_lenses {
+    ($src:literal) => {
+        let output = pretty_src_lenses(TestProject::for_source($src));
+        insta::assert_snapshot!(insta::internals::AutoName, output);
+    };
+}
+
+#[test]
+fn main_function_can_run_on_all_targets() {
+    assert_lenses!(
+        "
+pub fn main() {
+  wibble()
+}
+
+fn wibble() {
+  1
+}
+"
+    );
+}
+
+#[test]
+fn main_function_using_externals() {
+    assert_lenses!(
+        r#"
+pub fn main() {
+  wibble()
+}
+
+@external(erlang, "wibble", "wobble")
+@external(javascript, "./wibble.mjs", "wobble")
+fn wibble() -> Int
+"#
+    );
+}
+
+#[test]
+fn main_function_with_target_attribute() {
+    assert_lenses!(
+        "
+@target(erlang)
+pub fn main() {
+  1
+}
+
+@target(javascript)
+pub fn main() {
+  2
+}
+"
+    );
+}
+
+#[test]
+fn no_lenses_for_private_main_or_main_with_arguments() {
+    let code = "
+fn main() {
+  1
+}
+
+pub fn wibble_test() {
+  1
+}
+";
+    let lenses = TestProject::for_source(code).at(Position::default(), |engine, params, _| {
+        code_lenses(engine, params.text_document)
+    });
+    assert_eq!(lenses, vec![]);
+
+    let code = "
+pub fn main(argument) {
+  argument
+}
+";
+    let lenses = TestProject::for_source(code).at(Position::default(), |engine, params, _| {
+        code_lenses(engine, params.text_document)
+    });
+    assert_eq!(lenses, vec![]);
+}
+
+#[test]
+fn test_functions_in_test_module() {
+    let test = r#"
+pub fn main() {
+  1
+}
+
+pub fn wibble_test() {
+  2
+}
+
+@external(erlang, "wibble", "wobble")
+pub fn wobble_test() -> Nil
+
+fn private_test() {
+  3
+}
+
+pub fn helper() {
+  4
+}
+"#;
+
+    let output = pretty_test_lenses(
+        TestProject::for_source("").add_test_module("app_test", test),
+        "app_test",
+        test,
+    );
+    insta::assert_snapshot!(insta::internals::AutoName, output);
+}
+
+#[test]
+fn test_module_runnable_on_every_target() {
+    let test = r#"
+pub fn wibble_test() {
+  1
+}
+
+pub fn wobble_test() {
+  2
+}
+"#;
+
+    let output = pretty_test_lenses(
+        TestProject::for_source("").add_test_module("app_test", test),
+        "app_test",
+        test,
+    );
+    insta::assert_snapshot!(insta::internals::AutoName, output);
+}
+
+#[test]
+fn test_module_with_targeted_test() {
+    let test = r#"
+pub fn wibble_test() {
+  1
+}
+
+@target(erlang)
+pub fn wobble_test() {
+  2
+}
+"#;
+
+    let output = pretty_test_lenses(
+        TestProject::for_source("").add_test_module("app_test", test),
+        "app_test",
+        test,
+    );
+    insta::assert_snapshot!(insta::internals::AutoName, output);
+}
@@ -0,0 +1,304 @@
+use lsp_types::{GotoDefinitionResponse, Location, Position, Range};
+
+use super::*;
+
+fn implementation(tester: &TestProject<'_>, position: Position) -> Option<Vec<Location>> {
+    tester.at(position, |engine, param, _| {
+        let params = lsp_types::request::GotoImplementationParams {
+            text_document_position_params: param,
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+        };
+        match engine.goto_implementation(params).result.unwrap()? {
+            GotoDefinitionResponse::Scalar(location) => Some(vec![location]),
+            GotoDefinitionResponse::Array(locations) => Some(locations),
+            GotoDefinitionResponse::Link(_) => panic!("unexpected location links"),
+        }
+    })
+}
+
+fn pretty_implementation(project: TestProject<'_>, position_finder: PositionFinder) -> String {
+    let position = position_finder.find_position(project.src);
+    let locations = implementation(&project, position).expect("an implementation to jump to");
+
+    let src = hover::show_hover(
+        project.src,
+        Range {
+            start: position,
+            end: position,
+        },
+        position,
+    );
+    let mut output = format!("----- Jumping from `src/app.gleam`\n{src}");
+
+    for location in locations {
+        let pretty_destination = location
+            .uri
+            .path_segments()
+            .expect("a location to jump to")
+            // To make snapshots the same both on windows and unix systems we
+            // need to discard windows' `C:` path segment at the beginning of
+            // a uri.
+            .skip_while(|segment| *segment == "C:")
+            .join("/");
+        let (_, native_src) = project
+            .native_files
+            .iter()
+            .find(|(path, _)| pretty_destination == format!("src/{path}"))
+            .expect("a native file to jump to");
+        let destination = hover::show_hover(native_src, location.range, location.range.start);
+        output.push_str(&format!(
+            "----- Jumped to `{pretty_destination}`\n{destination}"
+        ));
+    }
+
+    output
+}
+
+macro_rules! assert_goto_implementation {
+    ($project:expr, $position:expr) => {
+        let output = pretty_implementation($project, $position);
+        insta::assert_snapshot!(insta::internals::AutoName, output);
+    };
+}
+
+#[test]
+fn goto_implementation_of_erlang_external_definition() {
+    let code = r#"
+@external(erlang, "wibble_ffi", "wobble")
+pub fn wobble() -> Int
+"#;
+
+    assert_goto_implementation!(
+        TestProject::for_source(code).add_native_file(
+            "wibble_ffi.erl",
+            "-module(wibble_ffi).
+-export([wobble/0]).
+
+wobble() ->
+    1.
+"
+        ),
+        find_position_of("wobble()")
+    );
+}
+
+#[test]
+fn goto_implementation_of_erlang_external_in_nested_directory() {
+    let code = r#"
+@external(erlang, "wibble_ffi", "wobble")
+pub fn wobble() -> Int
+
+pub fn main() {
+  wobble()
+}
+"#;
+
+    assert_goto_implementation!(
+        TestProject::for_source(code).add_native_file(
+            "nested/wibble_ffi.erl",
+            "-module(wibble_ffi).
+-export([wobble/0]).
+
+wobble() ->
+    1.
+"
+        ),
+        find_position_of("wobble()").nth_occurrence(2)
+    );
+}
+
+#[test]
+fn goto_implementation_of_javascript_external() {
+    let code = r#"
+@external(javascript, "./wibble_ffi.mjs", "wobble")
+pub fn wobble() -> Int {
+  1
+}
+
+pub fn main() {
+  wobble()
+}
+"#;
+
+    assert_goto_implementation!(
+        TestProject::for_source(code).add_native_file(
+            "wibble_ffi.mjs",
+            "import { Ok } from \"./gleam.mjs\";
+
+export function wobble() {
+  return 1;
+}
+"
+        ),
+        find_position_of("wobble()").nth_occurrence(2)
+    );
+}
+
+#[test]
+fn goto_implementation_of_go_external() {
+    let code = r#"
+@external(go, "", "Wobble")
+pub fn wobble() -> Int {
+  1
+}
+
+pub fn main() {
+  wobble()
+}
+"#;
+
+    assert_goto_implementation!(
+        TestProject::for_source(code)
+            .add_native_file(
+                "app/other.go",
+                "package app
+
+func Other() int {
+	return 2
+}
+"
+            )
+            .add_native_file(
+                "app/ffi.go",
+                "package app
+
+func Wobble() int {
+	return 1
+}
+"
+            ),
+        find_position_of("wobble()").nth_occurrence(2)
+    );
+}
+
+#[test]
+fn goto_implementation_of_external_for_multiple_targets() {
+    let code = r#"
+@external(erlang, "wibble_ffi", "wobble")
+@external(javascript, "./wibble_ffi.mjs", "wobble")
+@external(go, "example.com/my_project/app/helpers", "Wobble")
+pub fn wobble() -> Int
+"#;
+
+    assert_goto_implementation!(
+        TestProject::for_source(code)
+            .add_native_file(
+                "wibble_ffi.erl",
+                "-module(wibble_ffi).
+-export([wobble/0]).
+
+wobble() ->
+    1.
+"
+            )
+            .add_native_file(
+                "wibble_ffi.mjs",
+                "export function wobble() {
+  return 1;
+}
+"
+            )
+            .add_native_file(
+                "helpers/helpers.go",
+                "package helpers
+
+func Wobble() int {
+	return 1
+}
+"
+            ),
+        find_position_of("wobble()")
+    );
+}
+
+#[test]
+fn goto_implementation_of_go_external_in_the_source_root() {
+    let code = r#"
+@external(go, "example.com/my_project/app", "Wobble")
+pub fn wobble() -> Int {
+  1
+}
+
+pub fn main() {
+  wobble()
+}
+"#;
+
+    assert_goto_implementation!(
+        TestProject::for_source(code).add_native_file(
+            "ffi.go",
+            "package app
+
+func Wobble() int {
+	return 1
+}
+"
+        ),
+        find_position_of("wobble()").nth_occurrence(2)
+    );
+}
+
+#[test]
+fn goto_implementation_of_qualified_external() {
+    let code = "
+import wibble
+
+pub fn main() {
+  wibble.wobble()
+}
+";
+
+    assert_goto_implementation!(
+        TestProject::for_source(code)
+            .add_module(
+                "wibble",
+                r#"@external(erlang, "wibble_ffi", "wobble")
+pub fn wobble() -> Int
+"#
+            )
+            .add_native_file(
+                "wibble_ffi.erl",
+                "-module(wibble_ffi).
+-export([wobble/0]).
+
+wobble() ->
+    1.
+"
+            ),
+        find_position_of("wobble")
+    );
+}
+
+#[test]
+fn goto_implementation_with_missing_native_file() {
+    let code = r#"
+@external(erlang, "wibble_ffi", "wobble")
+pub fn wobble() -> Int
+"#;
+    let position = find_position_of("wobble()").find_position(code);
+    assert_eq!(
+        implementation(&TestProject::for_source(code), position),
+        None
+    );
+}
+
+#[test]
+fn goto_implementation_of_function_without_externals() {
+    let code = "
+pub fn wobble() -> Int {
+  1
+}
+";
+    let project = TestProject::for_source(code).add_native_file(
+        "wibble_ffi.erl",
+        "-module(wibble_ffi).
+-export([wobble/0]).
+
+wobble() ->
+    1.
+",
+    );
+    let position = find_position_of("wobble()").find_position(code);
+    assert_eq!(implementation(&project, position), None);
+}
@@ -0,0 +1,203 @@
+use lsp_types::{InlayHint, InlayHintLabel, InlayHintParams, Range};
+
+use super::*;
+
+fn inlay_hints(tester: &TestProject<'_>, range: Range) -> Vec<InlayHint> {
+    tester.at(Position::default(), |engine, params, _| {
+        let params = InlayHintParams {
+            text_document: params.text_document,
+            work_done_progress_params: Default::default(),
+            range,
+        };
+        engine.inlay_hints(params).result.unwrap()
+    })
+}
+
+/// Shows the code with each hint inserted where the editor would display it,
+/// wrapped in `⟨` and `⟩`.
+fn show_inlay_hints(code: &str, hints: &[InlayHint]) -> String {
+    let mut lines = code.lines().map(String::from).collect_vec();
+    for hint in hints.iter().rev() {
+        let InlayHintLabel::String(label) = &hint.label else {
+            panic!("Unexpected label parts");
+        };
+        let padding = if hint.padding_left == Some(true) {
+            " "
+        } else {
+            ""
+        };
+        let line = &mut lines[hint.position.line as usize];
+        let index = line
+            .char_indices()
+            .nth(hint.position.character as usize)
+            .map(|(index, _)| index)
+            .unwrap_or(line.len());
+        line.insert_str(index, &format!("{padding}⟨{label}⟩"));
+    }
+    lines.join("\n")
+}
+
+fn pretty_inlay_hints(project: TestProject<'_>) -> String {
+    let range = Range::new(Position::new(0, 0), Position::new(u32::MAX, 0));
+    let hints = inlay_hints(&project, range);
+    show_inlay_hints(project.src, &hints)
+}
+
+macro_rules! assert_inlay_hints {
+    ($src:literal $(,)?) => {
+        let project = TestProject::for_source($src);
+        let output = pretty_inlay_hints(project);
+        insta::assert_snapshot!(insta::internals::AutoName, output);
+    };
+}
+
+#[test]
+fn inlay_hints_for_let_bindings() {
+    assert_inlay_hints!(
+        "
+pub fn main() {
+  let x = 1
+  let #(a, b) = #(x, \"wibble\")
+  let y: Int = x
+  let assert [first, ..] = [1.0]
+  b
+}
+"
+    );
+}
+
+#[test]
+fn inlay_hints_for_anonymous_function_parameters() {
+    assert_inlay_hints!(
+        "
+pub fn main() {
+  let add = fn(a, b: Int) { a + b }
+  let capture = add(1, _)
+  capture
+}
+"
+    );
+}
+
+#[test]
+fn inlay_hints_for_use_parameters() {
+    assert_inlay_hints!(
+        "
+fn wibble(f: fn(Int, String) -> a) -> a {
+  f(1, \"wobble\")
+}
+
+pub fn main() {
+  use x, y <- wibble
+  y
+}
+"
+    );
+}
+
+#[test]
+fn inlay_hints_for_generic_types() {
+    assert_inlay_hints!(
+        "
+pub fn main() {
+  let identity = fn(x) { x }
+  let list = []
+  #(identity, list)
+}
+"
+    );
+}
+
+#[test]
+fn inlay_hints_for_multiline_pipeline() {
+    assert_inlay_hints!(
+        "
+fn to_string(x: Int) -> String {
+  todo
+}
+
+fn double(x: Int) -> Int {
+  x * 2
+}
+
+pub fn main() {
+  1
+  |> double
+  |> double |> double
+  |> to_string
+}
+"
+    );
+}
+
+#[test]
+fn no_inlay_hints_for_single_line_pipeline() {
+    assert_inlay_hints!(
+        "
+fn double(x: Int) -> Int {
+  x * 2
+}
+
+pub fn main() {
+  1 |> double |> double
+}
+"
+    );
+}
+
+#[test]
+fn inlay_hints_use_import_aliases() {
+    let project = TestProject::for_source(
+        "
+import wibble.{type Wibble as Wubble}
+import wibble as wobble
+
+pub fn main() {
+  let x = wobble.new()
+  let y = wobble.other()
+  #(x, y)
+}
+",
+    )
+    .add_module(
+        "wibble",
+        "
+pub type Wibble {
+  Wibble
+}
+
+pub type Other {
+  Other
+}
+
+pub fn new() -> Wibble {
+  Wibble
+}
+
+pub fn other() -> Other {
+  Other
+}
+",
+    );
+
+    let output = pretty_inlay_hints(project);
+    insta::assert_snapshot!(output);
+}
+
+#[test]
+fn inlay_hints_only_in_requested_range() {
+    let project = TestProject::for_source(
+        "
+pub fn main() {
+  let x = 1
+  let y = 2
+  let z = 3
+  x + y + z
+}
+",
+    );
+
+    let range = Range::new(Position::new(3, 0), Position::new(3, 100));
+    let hints = inlay_hints(&project, range);
+    insta::assert_snapshot!(show_inlay_hints(project.src, &hints));
+}
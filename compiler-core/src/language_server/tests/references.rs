@@ -0,0 +1,428 @@
+use lsp_types::{
+    Location, PartialResultParams, Position, Range, ReferenceContext, ReferenceParams,
+    WorkDoneProgressParams,
+};
+
+use super::*;
+
+fn references(
+    tester: &TestProject<'_>,
+    position: Position,
+    include_declaration: bool,
+) -> Option<Vec<Location>> {
+    tester.at(position, |engine, params, _| {
+        let params = ReferenceParams {
+            text_document_position: params,
+            work_done_progress_params: WorkDoneProgressParams::default(),
+            partial_result_params: PartialResultParams::default(),
+            context: ReferenceContext {
+                include_declaration,
+            },
+        };
+        engine.find_references(params).result.unwrap()
+    })
+}
+
+fn pretty_references(
+    project: TestProject<'_>,
+    position_finder: PositionFinder,
+    include_declaration: bool,
+) -> String {
+    let position = position_finder.find_position(project.src);
+    let locations =
+        references(&project, position, include_declaration).expect("references to be found");
+
+    let mut output = format!(
+        "----- Finding references from `src/app.gleam`\n{}",
+        hover::show_hover(project.src, Range::new(position, position), position)
+    );
+
+    for (uri, locations) in &locations
+        .into_iter()
+        .chunk_by(|location| location.uri.clone())
+    {
+        let path = uri
+            .path_segments()
+            .expect("a module path")
+            // To make snapshots the same both on windows and unix systems we
+            // need to discard windows' `C:` path segment at the beginning of a
+            // uri.
+            .skip_while(|segment| segment.is_empty() || *segment == "C:")
+            .join("/");
+        let src = project.src_from_module_url(&uri).expect("a module source");
+        let ranges = locations.map(|location| location.range).collect_vec();
+        output.push_str(&format!("\n----- `{path}`\n{}", show_ranges(src, &ranges)));
+    }
+
+    output
+}
+
+macro_rules! assert_references {
+    ($src:literal, $position:expr $(,)?) => {
+        let project = TestProject::for_source($src);
+        assert_references!(project, $position);
+    };
+    ($project:expr, $position:expr $(,)?) => {
+        let output = pretty_references($project, $position, true);
+        insta::assert_snapshot!(insta::internals::AutoName, output);
+    };
+}
+
+macro_rules! assert_references_without_declaration {
+    ($src:literal, $position:expr $(,)?) => {
+        let project = TestProject::for_source($src);
+        assert_references_without_declaration!(project, $position);
+    };
+    ($project:expr, $position:expr $(,)?) => {
+        let output = pretty_references($project, $position, false);
+        insta::assert_snapshot!(insta::internals::AutoName, output);
+    };
+}
+
+#[test]
+fn references_to_function_in_same_module() {
+    assert_references!(
+        "
+pub fn wibble(x) {
+  x + 1
+}
+
+pub fn main() {
+  wibble(1) + wibble(2)
+  1 |> wibble
+}
+",
+        find_position_of("wibble(1)")
+    );
+}
+
+#[test]
+fn references_to_function_from_its_definition() {
+    assert_references!(
+        "
+pub fn wibble(x) {
+  x + 1
+}
+
+pub fn main() {
+  wibble(1)
+}
+",
+        find_position_of("wibble(x)")
+    );
+}
+
+#[test]
+fn references_to_function_without_declaration() {
+    assert_references_without_declaration!(
+        "
+pub fn wibble(x) {
+  x + 1
+}
+
+pub fn main() {
+  wibble(1)
+}
+",
+        find_position_of("wibble(x)")
+    );
+}
+
+#[test]
+fn references_to_function_in_other_modules() {
+    let project = TestProject::for_source(
+        "
+import wibble
+
+pub fn main() {
+  wibble.wobble()
+}
+",
+    )
+    .add_module(
+        "wibble",
+        "
+pub fn wobble() {
+  Nil
+}
+
+pub fn wubble() {
+  wobble()
+}
+",
+    )
+    .add_module(
+        "other",
+        "
+import wibble.{wobble}
+
+pub fn main() {
+  wobble()
+  wibble.wobble
+}
+",
+    );
+
+    assert_references!(project, find_position_of("wobble"));
+}
+
+#[test]
+fn references_to_function_include_test_modules() {
+    let project = TestProject::for_source(
+        "
+pub fn wibble() {
+  Nil
+}
+",
+    )
+    .add_test_module(
+        "app_test",
+        "
+import app
+
+pub fn wibble_test() {
+  app.wibble()
+}
+",
+    );
+
+    assert_references!(project, find_position_of("wibble"));
+}
+
+#[test]
+fn references_to_aliased_unqualified_import() {
+    let project = TestProject::for_source(
+        "
+import wibble.{wobble as wubble}
+
+pub fn main() {
+  wubble()
+}
+",
+    )
+    .add_module(
+        "wibble",
+        "
+pub fn wobble() {
+  Nil
+}
+",
+    );
+
+    assert_references!(project, find_position_of("wobble"));
+}
+
+#[test]
+fn references_to_constant() {
+    assert_references!(
+        "
+const wibble = 1
+
+const wobble = [wibble, 2]
+
+pub fn main() {
+  wibble + 1
+}
+",
+        find_position_of("wibble").nth_occurrence(3)
+    );
+}
+
+#[test]
+fn references_to_record_constructor() {
+    let project = TestProject::for_source(
+        "
+import wibble.{Wobble}
+
+const value = Wobble(1)
+
+pub fn main(x) {
+  case x {
+    Wobble(_) -> wibble.Wobble(2)
+    wibble.Wubble -> Wobble(3)
+  }
+}
+",
+    )
+    .add_module(
+        "wibble",
+        "
+pub type Wibble {
+  Wobble(Int)
+  Wubble
+}
+",
+    );
+
+    assert_references!(project, find_position_of("Wobble(3)"));
+}
+
+#[test]
+fn references_to_constant_in_guard() {
+    let project = TestProject::for_source(
+        "
+import wibble
+
+pub fn main(x) {
+  case x {
+    _ if x == wibble.wobble -> wibble.wobble
+    _ -> 0
+  }
+}
+",
+    )
+    .add_module("wibble", "pub const wobble = 1");
+
+    assert_references!(project, find_position_of("wobble").nth_occurrence(2));
+}
+
+#[test]
+fn references_to_record_constructor_from_its_definition() {
+    assert_references!(
+        "
+pub type Wibble {
+  Wobble(Int)
+  Wubble
+}
+
+pub fn main() {
+  Wobble(1)
+}
+",
+        find_position_of("Wobble(Int)")
+    );
+}
+
+#[test]
+fn references_to_type() {
+    let project = TestProject::for_source(
+        "
+import wibble.{type Wibble}
+
+pub type Box {
+  Box(Wibble)
+}
+
+pub fn main(x: Wibble) -> List(wibble.Wibble) {
+  let y: Wibble = x
+  [y]
+}
+",
+    )
+    .add_module(
+        "wibble",
+        "
+pub type Wibble {
+  Wibble
+}
+
+pub type Alias =
+  Wibble
+",
+    );
+
+    assert_references!(project, find_position_of("Wibble").nth_occurrence(2));
+}
+
+#[test]
+fn references_to_type_from_its_definition() {
+    assert_references!(
+        "
+pub type Wibble {
+  Wibble
+}
+
+pub fn main(x: Wibble) -> fn(Wibble) -> #(Wibble, Int) {
+  todo
+}
+",
+        find_position_of("Wibble")
+    );
+}
+
+#[test]
+fn references_to_type_alias() {
+    assert_references!(
+        "
+pub type Wibble =
+  Int
+
+pub fn main(x: Wibble) -> Int {
+  x
+}
+",
+        find_position_of("x: Wibble").under_char('W')
+    );
+}
+
+#[test]
+fn no_references_to_local_variable() {
+    let project = TestProject::for_source(
+        "
+pub fn main() {
+  let x = 1
+  x
+}
+",
+    );
+    let position = find_position_of("x")
+        .nth_occurrence(2)
+        .find_position(project.src);
+
+    assert_eq!(references(&project, position, true), None);
+}
+
+// Definitions are only referenced from their name, so that finding references
+// anywhere else in the head of a definition, such as on its `fn` keyword,
+// doesn't find the references of the definition itself.
+#[test]
+fn no_references_to_function_from_its_keyword() {
+    let project = TestProject::for_source(
+        "
+pub fn wibble() -> Int {
+  1
+}
+
+pub fn main() {
+  wibble()
+}
+",
+    );
+    let position = find_position_of("fn wibble").find_position(project.src);
+
+    assert_eq!(references(&project, position, true), None);
+}
+
+#[test]
+fn no_references_to_record_constructor_from_its_fields() {
+    let project = TestProject::for_source(
+        "
+pub type Wibble {
+  Wibble(wobble: Int)
+}
+
+pub fn main() {
+  Wibble(1)
+}
+",
+    );
+    let position = find_position_of("wobble").find_position(project.src);
+
+    assert_eq!(references(&project, position, true), None);
+}
+
+#[test]
+fn no_references_to_constant_from_its_keyword() {
+    let project = TestProject::for_source(
+        "
+pub const wibble: Int = 1
+
+pub fn main() {
+  wibble
+}
+",
+    );
+    let position = find_position_of("const").find_position(project.src);
+
+    assert_eq!(references(&project, position, true), None);
+}
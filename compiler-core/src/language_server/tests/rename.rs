@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use itertools::Itertools;
 use lsp_types::{Position, RenameParams, TextDocumentPositionParams, Url, WorkDoneProgressParams};
 
 use crate::language_server::tests::{find_position_of, TestProject};
@@ -7,7 +8,7 @@ use crate::language_server::tests::{find_position_of, TestProject};
 use super::hover;
 
 fn rename(
-    tester: TestProject<'_>,
+    tester: &TestProject<'_>,
     new_name: &str,
     position: Position,
 ) -> Option<lsp_types::WorkspaceEdit> {
@@ -33,18 +34,41 @@ fn rename(
 }
 
 fn apply_rename(tester: TestProject<'_>, new_name: &str, position: Position) -> String {
-    let src = tester.src;
-    let changes = rename(tester, new_name, position)
+    let changes = rename(&tester, new_name, position)
         .expect("Rename failed")
         .changes
         .expect("No text edit found");
-    apply_code_edit(src, changes)
-}
+    apply_code_edit(&tester, changes)
+}
+
+/// Applies the edits to the modules they are for. The edited `app` module is
+/// shown first, followed by any other module that was edited.
+fn apply_code_edit(
+    tester: &TestProject<'_>,
+    changes: HashMap<Url, Vec<lsp_types::TextEdit>>,
+) -> String {
+    let mut result = tester.src.to_string();
+    let mut other_modules = vec![];
+    for (uri, change) in changes {
+        let path = uri
+            .path_segments()
+            .expect("a module path")
+            // To make snapshots the same both on windows and unix systems we
+            // need to discard windows' `C:` path segment at the beginning of a
+            // uri.
+            .skip_while(|segment| segment.is_empty() || *segment == "C:")
+            .join("/");
+        if path == "src/app.gleam" {
+            result = super::apply_code_edit(result.as_str(), change);
+        } else {
+            let src = tester.src_from_module_url(&uri).expect("a module source");
+            other_modules.push((path, super::apply_code_edit(src, change)));
+        }
+    }
 
-fn apply_code_edit(src: &str, changes: HashMap<Url, Vec<lsp_types::TextEdit>>) -> String {
-    let mut result = src.to_string();
-    for (_, change) in changes {
-        result = super::apply_code_edit(result.as_str(), change);
+    other_modules.sort();
+    for (path, src) in other_modules {
+        result.push_str(&format!("\n\n----- AFTER RENAME in `{path}`\n{src}"));
     }
     result
 }
@@ -77,7 +101,7 @@ macro_rules! assert_no_rename {
     ($project:expr, $new_name:literal, $range:expr $(,)?) => {
         let src = $project.src;
         let range = $range.find_range(src);
-        let result = rename($project, $new_name, range.start);
+        let result = rename(&$project, $new_name, range.start);
         assert_eq!(result, None);
     };
 }
@@ -342,3 +366,365 @@ pub fn main() {
         find_position_of("wibble").nth_occurrence(2).to_selection()
     );
 }
+
+#[test]
+fn rename_function_from_definition() {
+    let project = TestProject::for_source(
+        "
+pub fn wibble() {
+  wibble()
+}
+",
+    )
+    .add_module(
+        "other",
+        "
+import app.{wibble}
+
+pub fn main() {
+  wibble()
+  app.wibble()
+}
+",
+    )
+    .add_test_module(
+        "app_test",
+        "
+import app
+
+pub fn wibble_test() {
+  app.wibble()
+}
+",
+    );
+
+    assert_rename!(project, "wobble", find_position_of("wibble").to_selection());
+}
+
+#[test]
+fn rename_function_from_qualified_use() {
+    let project = TestProject::for_source(
+        "
+import wibble
+
+pub fn main() {
+  wibble.wobble()
+}
+",
+    )
+    .add_module(
+        "wibble",
+        "
+pub fn wobble() {
+  Nil
+}
+",
+    );
+
+    assert_rename!(project, "wubble", find_position_of("wobble").to_selection());
+}
+
+#[test]
+fn rename_function_imported_with_alias() {
+    let project = TestProject::for_source(
+        "
+import wibble.{wobble as wubble}
+
+pub fn main() {
+  wubble()
+}
+",
+    )
+    .add_module(
+        "wibble",
+        "
+pub fn wobble() {
+  Nil
+}
+",
+    );
+
+    assert_rename!(
+        project,
+        "wabble",
+        find_position_of("wubble()").to_selection()
+    );
+}
+
+#[test]
+fn rename_function_used_with_use() {
+    assert_rename!(
+        "
+fn wibble(f) {
+  f(1)
+}
+
+pub fn main() {
+  use x <- wibble
+  use y <- wibble()
+  x + y
+}
+",
+        "wobble",
+        find_position_of("wibble(f)").to_selection()
+    );
+}
+
+#[test]
+fn rename_constant() {
+    let project = TestProject::for_source(
+        "
+pub const wibble = 1
+
+const wobble = [wibble]
+
+pub fn main() {
+  wibble
+}
+",
+    )
+    .add_module(
+        "other",
+        "
+import app
+
+pub fn main(x) {
+  case x {
+    _ if x == app.wibble -> app.wibble
+    _ -> 0
+  }
+}
+",
+    );
+
+    assert_rename!(project, "wubble", find_position_of("wibble").to_selection());
+}
+
+#[test]
+fn rename_record_constructor() {
+    let project = TestProject::for_source(
+        "
+pub type Wibble {
+  Wobble(Int)
+  Wubble
+}
+
+const value = Wobble(1)
+
+pub fn main(x) {
+  case x {
+    Wobble(_) -> Wobble(2)
+    Wubble -> value
+  }
+}
+",
+    )
+    .add_module(
+        "other",
+        "
+import app.{Wobble}
+
+pub fn main(x) {
+  case x {
+    app.Wobble(y) -> Wobble(y)
+    _ -> app.Wobble(1)
+  }
+}
+",
+    );
+
+    assert_rename!(
+        project,
+        "Wabble",
+        find_position_of("Wobble(2)").to_selection()
+    );
+}
+
+#[test]
+fn rename_type() {
+    let project = TestProject::for_source(
+        "
+pub type Wibble {
+  Wobble(List(Wibble))
+}
+
+pub type Alias =
+  Wibble
+
+pub fn main(x: Wibble) -> Wibble {
+  let y: Wibble = x
+  y
+}
+",
+    )
+    .add_module(
+        "other",
+        "
+import app.{type Wibble}
+
+pub fn main(x: Wibble) -> app.Wibble {
+  x
+}
+",
+    );
+
+    assert_rename!(
+        project,
+        "Wubble",
+        find_position_of("x: Wibble").under_char('W').to_selection()
+    );
+}
+
+#[test]
+fn no_rename_function_from_dependency() {
+    let project = TestProject::for_source(
+        "
+import dep
+
+pub fn main() {
+  dep.wibble()
+}
+",
+    )
+    .add_dep_module("dep", "pub fn wibble() { Nil }");
+
+    assert_no_rename!(project, "wobble", find_position_of("wibble").to_selection());
+}
+
+#[test]
+fn no_rename_function_invalid_name() {
+    assert_no_rename!(
+        "
+pub fn wibble() {
+  Nil
+}
+",
+        "Wobble",
+        find_position_of("wibble").to_selection()
+    );
+}
+
+#[test]
+fn no_rename_type_invalid_name() {
+    assert_no_rename!(
+        "
+pub type Wibble {
+  Wobble
+}
+",
+        "wibble",
+        find_position_of("Wibble").to_selection()
+    );
+}
+
+#[test]
+fn no_rename_function_to_name_of_other_function() {
+    assert_no_rename!(
+        "
+pub fn wibble() {
+  wobble()
+}
+
+fn wobble() {
+  Nil
+}
+",
+        "wobble",
+        find_position_of("wibble").to_selection()
+    );
+}
+
+#[test]
+fn no_rename_function_to_name_of_unqualified_import() {
+    let project = TestProject::for_source(
+        "
+import other.{wobble}
+
+pub fn wibble() {
+  wobble()
+}
+",
+    )
+    .add_module("other", "pub fn wobble() { Nil }");
+
+    assert_no_rename!(project, "wobble", find_position_of("wibble").to_selection());
+}
+
+#[test]
+fn no_rename_function_to_name_of_imported_module() {
+    let project = TestProject::for_source(
+        "
+import other
+
+pub fn wibble() {
+  other.wobble()
+}
+",
+    )
+    .add_module("other", "pub fn wobble() { Nil }");
+
+    assert_no_rename!(project, "other", find_position_of("wibble").to_selection());
+}
+
+#[test]
+fn no_rename_function_clashing_where_imported_unqualified() {
+    let project = TestProject::for_source(
+        "
+pub fn wibble() {
+  Nil
+}
+",
+    )
+    .add_module(
+        "other",
+        "
+import app.{wibble}
+
+pub fn wobble() {
+  wibble()
+}
+",
+    );
+
+    assert_no_rename!(project, "wobble", find_position_of("wibble").to_selection());
+}
+
+#[test]
+fn no_rename_type_to_name_of_other_type() {
+    assert_no_rename!(
+        "
+pub type Wibble {
+  Wibble
+}
+
+pub type Wobble {
+  Wobble
+}
+",
+        "Wobble",
+        find_position_of("Wibble").to_selection()
+    );
+}
+
+#[test]
+fn rename_function_to_name_only_used_where_it_is_qualified() {
+    let project = TestProject::for_source(
+        "
+pub fn wibble() {
+  Nil
+}
+",
+    )
+    .add_module(
+        "other",
+        "
+import app
+
+pub fn wobble() {
+  app.wibble()
+}
+",
+    );
+
+    assert_rename!(project, "wobble", find_position_of("wibble").to_selection());
+}
@@ -0,0 +1,208 @@
+use lsp_server::{Connection, Message, Notification, Request};
+use lsp_types::{
+    notification::{Exit, Initialized, LogMessage, Notification as _},
+    request::{CodeLensRequest, ExecuteCommand, Initialize, Request as _, Shutdown},
+    CodeLensParams, ExecuteCommandParams, InitializeParams, LogMessageParams, MessageType,
+    TextDocumentIdentifier,
+};
+use serde_json::{json, Value};
+
+use crate::language_server::{code_lens::RUN_COMMAND, LanguageServer};
+
+use super::*;
+
+/// Runs a language server over an in-memory connection, with an editor that
+/// opens the project at the root of the file system and then asks the server
+/// to execute the given command, and returns the messages it logged while
+/// doing so.
+fn execute_command(
+    io: &LanguageServerTestIO,
+    command: &str,
+    arguments: Vec<Value>,
+) -> Vec<(MessageType, String)> {
+    io.write(
+        &io.paths.root_config(),
+        &format!("name = \"{LSP_TEST_ROOT_PACKAGE_NAME}\"\nversion = \"1.0.0\"\n"),
+    )
+    .unwrap();
+    let module = io.src_module("app", "pub fn main() {\n  Nil\n}\n");
+    let code_lens = CodeLensParams {
+        text_document: TextDocumentIdentifier::new(Url::from_file_path(module).unwrap()),
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+    };
+
+    let (server, client) = Connection::memory();
+    let params = ExecuteCommandParams {
+        command: command.into(),
+        arguments,
+        work_done_progress_params: Default::default(),
+    };
+    let editor = std::thread::spawn(move || {
+        let request = |id: i32, method: &str, params| {
+            let request = Request::new(id.into(), method.into(), params);
+            client.sender.send(Message::Request(request)).unwrap();
+        };
+        let notification = |method: &str| {
+            let notification = Notification::new(method.into(), json!({}));
+            client
+                .sender
+                .send(Message::Notification(notification))
+                .unwrap();
+        };
+        let mut logged = vec![];
+        let mut response = |id: i32| loop {
+            match client.receiver.recv().unwrap() {
+                Message::Response(response) if response.id == id.into() => break,
+                Message::Notification(notification)
+                    if notification.method == LogMessage::METHOD =>
+                {
+                    let params: LogMessageParams =
+                        serde_json::from_value(notification.params).unwrap();
+                    logged.push((params.typ, params.message));
+                }
+                _ => (),
+            }
+        };
+
+        let initialise = serde_json::to_value(InitializeParams::default()).unwrap();
+        request(0, Initialize::METHOD, initialise);
+        response(0);
+        notification(Initialized::METHOD);
+
+        request(
+            1,
+            CodeLensRequest::METHOD,
+            serde_json::to_value(code_lens).unwrap(),
+        );
+        response(1);
+
+        request(
+            2,
+            ExecuteCommand::METHOD,
+            serde_json::to_value(params).unwrap(),
+        );
+        response(2);
+
+        request(3, Shutdown::METHOD, Value::Null);
+        response(3);
+        notification(Exit::METHOD);
+        logged
+    });
+
+    LanguageServer::new(&server, io.clone())
+        .unwrap()
+        .run()
+        .unwrap();
+    editor.join().unwrap()
+}
+
+fn run_gleam_commands(io: LanguageServerTestIO) -> Vec<Action> {
+    io.into_actions()
+        .into_iter()
+        .filter(|action| matches!(action, Action::RunGleamCommand { .. }))
+        .collect()
+}
+
+#[test]
+fn run_main_streams_output() {
+    let mut io = LanguageServerTestIO::new();
+    io.gleam_command_output = vec![
+        CommandOutput::Line("  Compiling app".into()),
+        CommandOutput::Line("Hello, Joe!".into()),
+        CommandOutput::Exited(0),
+    ];
+    let root = io.paths.root().to_path_buf();
+    let arguments = json!({
+        "root": root,
+        "kind": "main",
+        "module": "app/wobble",
+        "target": "javascript",
+    });
+
+    let logged = execute_command(&io, RUN_COMMAND, vec![arguments]);
+
+    assert_eq!(
+        logged,
+        vec![
+            (MessageType::LOG, "  Compiling app".into()),
+            (MessageType::LOG, "Hello, Joe!".into()),
+            (
+                MessageType::INFO,
+                "`gleam run --target javascript --module app/wobble` finished successfully".into()
+            ),
+        ]
+    );
+    assert_eq!(
+        run_gleam_commands(io),
+        vec![Action::RunGleamCommand {
+            root,
+            arguments: vec![
+                "run".into(),
+                "--target".into(),
+                "javascript".into(),
+                "--module".into(),
+                "app/wobble".into(),
+            ],
+        }]
+    );
+}
+
+#[test]
+fn run_tests_reports_failure() {
+    let mut io = LanguageServerTestIO::new();
+    io.gleam_command_output = vec![
+        CommandOutput::Line("1 tests, 1 failures".into()),
+        CommandOutput::Exited(1),
+    ];
+    let root = io.paths.root().to_path_buf();
+    let arguments = json!({ "root": root, "kind": "tests", "target": "erlang" });
+
+    let logged = execute_command(&io, RUN_COMMAND, vec![arguments]);
+
+    assert_eq!(
+        logged,
+        vec![
+            (MessageType::LOG, "1 tests, 1 failures".into()),
+            (
+                MessageType::ERROR,
+                "`gleam test --target erlang` exited with code 1".into()
+            ),
+        ]
+    );
+    assert_eq!(
+        run_gleam_commands(io),
+        vec![Action::RunGleamCommand {
+            root,
+            arguments: vec!["test".into(), "--target".into(), "erlang".into()],
+        }]
+    );
+}
+
+#[test]
+fn invalid_arguments_run_nothing() {
+    let io = LanguageServerTestIO::new();
+    let root = io.paths.root().to_path_buf();
+
+    let arguments = json!({ "root": root, "kind": "tests", "target": "cobol" });
+    assert_eq!(execute_command(&io, RUN_COMMAND, vec![arguments]), vec![]);
+    assert_eq!(execute_command(&io, RUN_COMMAND, vec![]), vec![]);
+
+    let arguments = json!({ "root": root, "kind": "tests", "target": "erlang" });
+    assert_eq!(
+        execute_command(&io, "gleam.wibble", vec![arguments]),
+        vec![]
+    );
+
+    assert_eq!(run_gleam_commands(io), vec![]);
+}
+
+#[test]
+fn unknown_project_root_runs_nothing() {
+    let io = LanguageServerTestIO::new();
+
+    let arguments = json!({ "root": "/wibble", "kind": "tests", "target": "erlang" });
+    assert_eq!(execute_command(&io, RUN_COMMAND, vec![arguments]), vec![]);
+
+    assert_eq!(run_gleam_commands(io), vec![]);
+}
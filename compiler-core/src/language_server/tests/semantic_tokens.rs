@@ -0,0 +1,257 @@
+use lsp_types::{
+    Range, SemanticTokens, SemanticTokensLegend, SemanticTokensParams, SemanticTokensRangeParams,
+};
+
+use super::*;
+use crate::language_server::semantic_tokens::legend;
+
+fn semantic_tokens(tester: &TestProject<'_>, range: Option<Range>) -> SemanticTokens {
+    tester.at(Position::default(), |engine, params, _| {
+        let response = match range {
+            None => engine.semantic_tokens_full(SemanticTokensParams {
+                work_done_progress_params: Default::default(),
+                partial_result_params: Default::default(),
+                text_document: params.text_document,
+            }),
+            Some(range) => engine.semantic_tokens_range(SemanticTokensRangeParams {
+                work_done_progress_params: Default::default(),
+                partial_result_params: Default::default(),
+                text_document: params.text_document,
+                range,
+            }),
+        };
+        response.result.unwrap().expect("semantic tokens")
+    })
+}
+
+/// Decodes the tokens, showing each one's text followed by its type and
+/// modifiers.
+fn show_semantic_tokens(code: &str, tokens: &SemanticTokens) -> String {
+    let SemanticTokensLegend {
+        token_types,
+        token_modifiers,
+    } = legend();
+    let lines = code.lines().collect_vec();
+
+    let mut output = format!("{code}\n----- Semantic tokens\n");
+    let (mut line, mut start) = (0, 0);
+    for token in &tokens.data {
+        if token.delta_line == 0 {
+            start += token.delta_start;
+        } else {
+            line += token.delta_line;
+            start = token.delta_start;
+        }
+        let text = lines[line as usize]
+            .chars()
+            .skip(start as usize)
+            .take(token.length as usize)
+            .collect::<String>();
+        let type_ = token_types[token.token_type as usize].as_str();
+        let modifiers = token_modifiers
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| token.token_modifiers_bitset & (1 << index) != 0)
+            .map(|(_, modifier)| modifier.as_str())
+            .join(", ");
+        output.push_str(&format!("{}:{start} `{text}` {type_}", line));
+        if !modifiers.is_empty() {
+            output.push_str(&format!(" [{modifiers}]"));
+        }
+        output.push('\n');
+    }
+    output
+}
+
+macro_rules! assert_semantic_tokens {
+    ($src:literal $(,)?) => {
+        let project = TestProject::for_source($src);
+        assert_semantic_tokens!(project);
+    };
+    ($project:expr $(,)?) => {
+        let project = $project;
+        let tokens = semantic_tokens(&project, None);
+        let output = show_semantic_tokens(project.src, &tokens);
+        insta::assert_snapshot!(insta::internals::AutoName, output);
+    };
+}
+
+#[test]
+fn semantic_tokens_for_functions_and_variables() {
+    assert_semantic_tokens!(
+        "
+pub fn add(x: Int, to y: Int) -> Int {
+  let sum = x + y
+  sum
+}
+
+pub fn main() {
+  add(1, to: 2)
+  |> add(to: 3)
+}
+"
+    );
+}
+
+#[test]
+fn semantic_tokens_for_types_and_constructors() {
+    assert_semantic_tokens!(
+        "
+pub type Wibble(a) {
+  Wobble(field: a)
+  Wubble
+}
+
+pub type Alias =
+  Wibble(Int)
+
+pub fn main(x: Wibble(a)) -> Alias {
+  case x {
+    Wobble(field: _) -> Wobble(field: 1)
+    Wubble -> Wubble
+  }
+}
+
+pub fn field(x: Wibble(Int)) {
+  let assert Wobble(..) as wobble = x
+  wobble.field
+}
+"
+    );
+}
+
+#[test]
+fn semantic_tokens_for_constants() {
+    assert_semantic_tokens!(
+        "
+pub type Wibble {
+  Wibble(Int)
+}
+
+const one = 1
+
+pub const wibble = Wibble(one)
+
+pub fn main() {
+  one
+}
+"
+    );
+}
+
+#[test]
+fn semantic_tokens_for_modules() {
+    let project = TestProject::for_source(
+        "
+import wibble.{type Wobble, wubble as other}
+import wibble as wib
+
+pub fn main(x: wibble.Wobble) -> Wobble {
+  other()
+  wib.wubble()
+  wibble.constant
+}
+",
+    )
+    .add_module(
+        "wibble",
+        "
+pub type Wobble
+
+pub const constant = 1
+
+pub fn wubble() -> Wobble {
+  todo
+}
+",
+    );
+
+    assert_semantic_tokens!(project);
+}
+
+#[test]
+fn semantic_tokens_for_attributes_and_deprecated() {
+    assert_semantic_tokens!(
+        "
+@deprecated(\"Use wobble\")
+pub fn wibble() {
+  Nil
+}
+
+@external(erlang, \"wibble\", \"wobble\")
+pub fn wobble() -> Nil
+
+pub fn main() {
+  wibble()
+  wobble()
+}
+"
+    );
+}
+
+#[test]
+fn semantic_tokens_for_unused_bindings() {
+    assert_semantic_tokens!(
+        "
+import gleam
+
+fn private(a, b) {
+  a
+}
+
+pub fn main() {
+  let x = 1
+  let y = 2
+  y
+}
+"
+    );
+}
+
+#[test]
+fn semantic_tokens_for_anonymous_functions_and_use() {
+    assert_semantic_tokens!(
+        "
+fn apply(f: fn(Int) -> a) -> a {
+  f(1)
+}
+
+pub fn main() {
+  let double = fn(n) { n * 2 }
+  use x <- apply
+  double(x)
+}
+"
+    );
+}
+
+#[test]
+fn semantic_tokens_for_guards_and_string_prefixes() {
+    assert_semantic_tokens!(
+        "
+pub fn main(x, y) {
+  case x {
+    \"a\" as a <> rest if rest == y -> a
+    _ -> y
+  }
+}
+"
+    );
+}
+
+#[test]
+fn semantic_tokens_in_range() {
+    let project = TestProject::for_source(
+        "
+pub fn main() {
+  let x = 1
+  let y = x
+  y
+}
+",
+    );
+
+    let range = Range::new(Position::new(3, 0), Position::new(3, 100));
+    let tokens = semantic_tokens(&project, Some(range));
+    insta::assert_snapshot!(show_semantic_tokens(project.src, &tokens));
+}
@@ -0,0 +1,202 @@
+use lsp_types::{GotoDefinitionResponse, Location, Position, Range};
+
+use super::*;
+
+fn type_definition(tester: &TestProject<'_>, position: Position) -> Vec<Location> {
+    tester.at(position, |engine, param, _| {
+        let params = lsp_types::request::GotoTypeDefinitionParams {
+            text_document_position_params: param,
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+        };
+        match engine.goto_type_definition(params).result.unwrap() {
+            None => vec![],
+            Some(GotoDefinitionResponse::Scalar(location)) => vec![location],
+            Some(GotoDefinitionResponse::Array(locations)) => locations,
+            Some(GotoDefinitionResponse::Link(_)) => panic!("unexpected location links"),
+        }
+    })
+}
+
+fn pretty_type_definition(project: TestProject<'_>, position_finder: PositionFinder) -> String {
+    let position = position_finder.find_position(project.src);
+    let locations = type_definition(&project, position);
+    assert!(!locations.is_empty(), "expected a type to jump to");
+
+    let src = hover::show_hover(
+        project.src,
+        Range {
+            start: position,
+            end: position,
+        },
+        position,
+    );
+    let mut output = format!("----- Jumping from `src/app.gleam`\n{src}");
+
+    for location in locations {
+        let pretty_destination = location
+            .uri
+            .path_segments()
+            .expect("a location to jump to")
+            // To make snapshots the same both on windows and unix systems we
+            // need to discard windows' `C:` path segment at the beginning of
+            // a uri.
+            .skip_while(|segment| *segment == "C:")
+            .join("/");
+        let destination = hover::show_hover(
+            project
+                .src_from_module_url(&location.uri)
+                .expect("a module to jump to"),
+            location.range,
+            location.range.start,
+        );
+        output.push_str(&format!(
+            "----- Jumped to `{pretty_destination}`\n{destination}"
+        ));
+    }
+
+    output
+}
+
+macro_rules! assert_goto_type {
+    ($src:literal, $position:expr) => {
+        let project = TestProject::for_source($src);
+        assert_goto_type!(project, $position);
+    };
+    ($project:expr, $position:expr) => {
+        let output = pretty_type_definition($project, $position);
+        insta::assert_snapshot!(insta::internals::AutoName, output);
+    };
+}
+
+#[test]
+fn goto_type_definition_of_local_variable() {
+    assert_goto_type!(
+        "
+pub type Wibble {
+  Wibble(Int)
+}
+
+pub fn main() {
+  let wibble = Wibble(1)
+  wibble
+}",
+        find_position_of("wibble").nth_occurrence(2)
+    );
+}
+
+#[test]
+fn goto_type_definition_of_type_from_other_module() {
+    let code = "
+import wibble
+
+pub fn main() {
+  wibble.new()
+}";
+
+    assert_goto_type!(
+        TestProject::for_source(code).add_module(
+            "wibble",
+            "pub type Wibble {
+  Wibble
+}
+
+pub fn new() -> Wibble {
+  Wibble
+}"
+        ),
+        find_position_of("new()").under_char('(')
+    );
+}
+
+#[test]
+fn goto_type_definition_of_type_from_dependency() {
+    let code = "
+import wibble
+
+pub fn main() {
+  let value = wibble.Wibble
+  value
+}";
+
+    assert_goto_type!(
+        TestProject::for_source(code).add_dep_module(
+            "wibble",
+            "pub type Wibble {
+  Wibble
+}"
+        ),
+        find_position_of("value").nth_occurrence(2)
+    );
+}
+
+#[test]
+fn goto_type_definition_of_list_offers_element_type() {
+    assert_goto_type!(
+        "
+pub type Wibble {
+  Wibble
+}
+
+pub type Wobble(a) {
+  Wobble(a)
+}
+
+pub fn main() {
+  let wobbles = [Wobble(Wibble)]
+  wobbles
+}",
+        find_position_of("wobbles").nth_occurrence(2)
+    );
+}
+
+#[test]
+fn goto_type_definition_of_result_offers_both_types() {
+    assert_goto_type!(
+        "
+pub type Wibble {
+  Wibble
+}
+
+pub type Wobble {
+  Wobble
+}
+
+pub fn main() {
+  let result: Result(Wibble, Wobble) = Ok(Wibble)
+  result
+}",
+        find_position_of("result").nth_occurrence(2)
+    );
+}
+
+#[test]
+fn goto_type_definition_of_function_argument() {
+    assert_goto_type!(
+        "
+pub type Wibble {
+  Wibble
+}
+
+pub fn main(wibble: Wibble) {
+  wibble
+}",
+        find_position_of("wibble").nth_occurrence(2)
+    );
+}
+
+#[test]
+fn goto_type_definition_of_prelude_type() {
+    let code = "
+pub fn main() {
+  let number = 1
+  number
+}";
+    let position = find_position_of("number")
+        .nth_occurrence(2)
+        .find_position(code);
+    assert_eq!(
+        type_definition(&TestProject::for_source(code), position),
+        vec![]
+    );
+}
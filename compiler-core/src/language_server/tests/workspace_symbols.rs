@@ -0,0 +1,215 @@
+use lsp_types::{SymbolInformation, WorkspaceSymbolParams};
+
+use super::*;
+
+fn workspace_symbols(tester: &TestProject<'_>, query: &str) -> Vec<SymbolInformation> {
+    tester.at(Position::default(), |engine, _, _| {
+        let params = WorkspaceSymbolParams {
+            query: query.into(),
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+        };
+        engine.workspace_symbol(params).result.unwrap()
+    })
+}
+
+/// Shows one symbol per line with its kind, container and where it is
+/// defined.
+fn pretty_workspace_symbols(tester: TestProject<'_>, query: &str) -> String {
+    let mut output = format!("----- Searching for `{query}`\n");
+    for symbol in workspace_symbols(&tester, query) {
+        let path = symbol
+            .location
+            .uri
+            .path_segments()
+            .expect("a module path")
+            .skip_while(|segment| *segment != "src" && *segment != "test")
+            .join("/");
+        let start = symbol.location.range.start;
+        output.push_str(&format!(
+            "{} {:?} in {} at {path}:{}:{}\n",
+            symbol.name,
+            symbol.kind,
+            symbol.container_name.unwrap_or_default(),
+            start.line,
+            start.character,
+        ));
+    }
+    output
+}
+
+macro_rules! assert_workspace_symbols {
+    ($src:literal, $query:literal $(,)?) => {
+        let project = TestProject::for_source($src);
+        assert_workspace_symbols!(project, $query);
+    };
+    ($project:expr, $query:literal $(,)?) => {
+        let output = pretty_workspace_symbols($project, $query);
+        insta::assert_snapshot!(insta::internals::AutoName, output);
+    };
+}
+
+#[test]
+fn workspace_symbols_empty_query_finds_everything() {
+    assert_workspace_symbols!(
+        "
+pub type Wibble {
+  Wobble(field: Int)
+  Wubble
+}
+
+type Alias =
+  Wibble
+
+const wibble = 1
+
+pub fn main() {
+  wibble
+}
+
+fn private() {
+  Nil
+}
+",
+        "",
+    );
+}
+
+#[test]
+fn workspace_symbols_fuzzy_match() {
+    assert_workspace_symbols!(
+        "
+pub fn wibble_wobble() {
+  Nil
+}
+
+pub fn wobble() {
+  Nil
+}
+
+pub fn wubble() {
+  Nil
+}
+
+pub fn a_wobble() {
+  Nil
+}
+",
+        "wobble",
+    );
+}
+
+#[test]
+fn workspace_symbols_is_case_insensitive() {
+    assert_workspace_symbols!(
+        "
+pub type WibbleWobble
+
+pub fn wibble_wobble() {
+  Nil
+}
+",
+        "wibwob",
+    );
+}
+
+#[test]
+fn workspace_symbols_finds_constructors() {
+    assert_workspace_symbols!(
+        "
+pub type Wibble {
+  Wobble(wobble: Int)
+  Wubble
+}
+",
+        "wob",
+    );
+}
+
+#[test]
+fn workspace_symbols_across_modules() {
+    let project = TestProject::for_source(
+        "
+pub fn wibble() {
+  Nil
+}
+",
+    )
+    .add_module(
+        "wobble",
+        "
+pub const wibble = 1
+
+fn wibble_wobble() {
+  Nil
+}
+",
+    )
+    .add_test_module(
+        "app_test",
+        "
+pub fn wibble_test() {
+  Nil
+}
+",
+    )
+    .add_dep_module(
+        "dep",
+        "
+pub fn wibble() {
+  Nil
+}
+",
+    );
+
+    assert_workspace_symbols!(project, "wibble");
+}
+
+#[test]
+fn workspace_symbols_no_match() {
+    let project = TestProject::for_source(
+        "
+pub fn wibble() {
+  Nil
+}
+",
+    );
+
+    assert_eq!(workspace_symbols(&project, "wobble"), vec![]);
+}
+
+#[test]
+fn workspace_symbols_in_dependency_modules() {
+    let project = TestProject::for_source(
+        "
+pub fn main() {
+  Nil
+}
+",
+    )
+    .add_hex_module(
+        "hex",
+        "
+pub type Wibble {
+  Wibble(field: Int)
+  Wobble
+}
+
+type Private {
+  PrivateWibble
+}
+
+pub const wibble = 1
+
+pub fn wibble_wobble() {
+  Nil
+}
+
+fn private_wibble() {
+  Nil
+}
+",
+    );
+
+    assert_workspace_symbols!(project, "wibble");
+}
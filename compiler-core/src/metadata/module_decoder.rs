@@ -117,6 +117,7 @@ impl ModuleDecoder {
             type_,
             deprecation,
             documentation: self.optional_string(self.str(reader.get_documentation()?)?),
+            go_wrapped: reader.get_go_wrapped(),
         })
     }
 
@@ -202,6 +202,7 @@ impl<'a> ModuleEncoder<'a> {
                 .map(EcoString::as_str)
                 .unwrap_or_default(),
         );
+        builder.set_go_wrapped(constructor.go_wrapped);
     }
 
     fn build_type_value_constructor(
@@ -145,6 +145,7 @@ fn module_with_private_type() {
                 parameters: vec![],
                 deprecation: Deprecation::NotDeprecated,
                 documentation: None,
+                go_wrapped: false,
             },
         )]
         .into(),
@@ -176,6 +177,7 @@ fn module_with_app_type() {
                 parameters: vec![],
                 deprecation: Deprecation::NotDeprecated,
                 documentation: None,
+                go_wrapped: false,
             },
         )]
         .into(),
@@ -207,6 +209,7 @@ fn module_with_fn_type() {
                 parameters: vec![],
                 deprecation: Deprecation::NotDeprecated,
                 documentation: None,
+                go_wrapped: false,
             },
         )]
         .into(),
@@ -238,6 +241,7 @@ fn module_with_tuple_type() {
                 parameters: vec![],
                 deprecation: Deprecation::NotDeprecated,
                 documentation: None,
+                go_wrapped: false,
             },
         )]
         .into(),
@@ -275,6 +279,7 @@ fn module_with_generic_type() {
                     parameters: vec![t1, t2],
                     deprecation: Deprecation::NotDeprecated,
                     documentation: None,
+                    go_wrapped: false,
                 },
             )]
             .into(),
@@ -312,6 +317,7 @@ fn module_with_type_links() {
                     parameters: vec![],
                     deprecation: Deprecation::NotDeprecated,
                     documentation: None,
+                    go_wrapped: false,
                 },
             )]
             .into(),
@@ -349,6 +355,7 @@ fn module_with_type_constructor_documentation() {
                     parameters: vec![],
                     deprecation: Deprecation::NotDeprecated,
                     documentation: Some("type documentation".into()),
+                    go_wrapped: false,
                 },
             )]
             .into(),
@@ -389,6 +396,7 @@ fn module_with_type_constructor_origin() {
                     parameters: vec![],
                     deprecation: Deprecation::NotDeprecated,
                     documentation: None,
+                    go_wrapped: false,
                 },
             )]
             .into(),
@@ -1233,6 +1241,38 @@ fn constant_bit_array_native() {
     assert_eq!(roundtrip(&module), module);
 }
 
+#[test]
+fn go_wrapped_type() {
+    let module = ModuleInterface {
+        warnings: vec![],
+        is_internal: false,
+        package: "some_package".into(),
+        origin: Origin::Src,
+        name: "a/b".into(),
+        types: [(
+            "Counts".into(),
+            TypeConstructor {
+                type_: type_::named("some_package", "a/b", "Counts", Publicity::Public, vec![]),
+                publicity: Publicity::Public,
+                origin: Default::default(),
+                module: "a/b".into(),
+                parameters: vec![],
+                deprecation: Deprecation::NotDeprecated,
+                documentation: None,
+                go_wrapped: true,
+            },
+        )]
+        .into(),
+        types_value_constructors: HashMap::new(),
+        values: HashMap::new(),
+        accessors: HashMap::new(),
+        line_numbers: LineNumbers::new(""),
+        src_path: "some_path".into(),
+        minimum_required_version: Version::new(0, 1, 0),
+    };
+    assert_eq!(roundtrip(&module), module);
+}
+
 #[test]
 fn deprecated_type() {
     let module = ModuleInterface {
@@ -1253,6 +1293,7 @@ fn deprecated_type() {
                     message: "oh no".into(),
                 },
                 documentation: None,
+                go_wrapped: false,
             },
         )]
         .into(),
@@ -1512,6 +1553,7 @@ fn type_with_inferred_variant() {
                 parameters: vec![],
                 deprecation: Deprecation::NotDeprecated,
                 documentation: None,
+                go_wrapped: false,
             },
         )]
         .into(),
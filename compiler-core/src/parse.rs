@@ -52,7 +52,7 @@
 pub mod error;
 pub mod extra;
 pub mod lexer;
-mod token;
+pub mod token;
 
 use crate::analyse::Inferred;
 use crate::ast::{
@@ -164,6 +164,17 @@ pub fn parse_module(
     let mut parser = Parser::new(lex);
     let mut parsed = parser.parse_module()?;
     parsed.extra = parser.extra;
+    parsed.extra.targeted_functions = parsed
+        .module
+        .definitions
+        .iter()
+        .filter_map(|targeted| match (&targeted.definition, targeted.target) {
+            (Definition::Function(function), Some(target)) => {
+                Some((function.location.start, target))
+            }
+            _ => None,
+        })
+        .collect();
 
     let src = EcoString::from(src);
     for warning in parser.warnings {
@@ -177,10 +188,9 @@ pub fn parse_module(
     Ok(parsed)
 }
 
-//
-// Test Interface
-//
-#[cfg(test)]
+/// Parses a sequence of statements, such as the body of a function, that
+/// makes up all of the source. The shell uses this to check whether an entry
+/// is complete, looking for an `UnexpectedEof` error.
 pub fn parse_statement_sequence(src: &str) -> Result<Vec1<UntypedStatement>, ParseError> {
     let lex = lexer::make_tokenizer(src);
     let mut parser = Parser::new(lex);
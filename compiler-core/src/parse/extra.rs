@@ -2,7 +2,7 @@ use std::cmp::Ordering;
 
 use ecow::EcoString;
 
-use crate::ast::SrcSpan;
+use crate::{ast::SrcSpan, build::Target};
 
 #[derive(Debug, PartialEq, Eq, Default)]
 pub struct ModuleExtra {
@@ -11,6 +11,9 @@ pub struct ModuleExtra {
     pub comments: Vec<SrcSpan>,
     pub empty_lines: Vec<u32>,
     pub new_lines: Vec<u32>,
+    /// The start of each function that has a `@target` attribute, along with
+    /// its target. The attribute isn't kept once the module is type checked.
+    pub targeted_functions: Vec<(u32, Target)>,
 }
 
 impl ModuleExtra {
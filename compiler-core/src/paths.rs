@@ -82,6 +82,11 @@ impl ProjectPaths {
         self.build_directory().join("erlang-shipment")
     }
 
+    pub fn go_binary_directory(&self, mode: Mode) -> Utf8PathBuf {
+        self.build_directory_for_target(mode, Target::Go)
+            .join("bin")
+    }
+
     pub fn build_documentation_directory(&self, package: &str) -> Utf8PathBuf {
         self.build_directory_for_mode(Mode::Dev)
             .join("docs")
@@ -37,10 +37,9 @@ use error::*;
 use hydrator::Hydrator;
 use itertools::Itertools;
 use std::{
-    cell::RefCell,
     collections::{HashMap, HashSet},
     ops::Deref,
-    sync::Arc,
+    sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard, TryLockError},
 };
 
 pub trait HasType {
@@ -102,7 +101,7 @@ pub enum Type {
 
     /// A type variable. See the contained `TypeVar` enum for more information.
     ///
-    Var { type_: Arc<RefCell<TypeVar>> },
+    Var { type_: Arc<TypeVarCell> },
 
     /// A tuple is an ordered collection of 0 or more values, each of which
     /// can have a different type, so the `tuple` type is the sum of all the
@@ -1208,6 +1207,62 @@ impl TypeVar {
     }
 }
 
+/// The mutable cell a type variable lives in while it is being inferred.
+///
+/// Once a module has been analysed its types are only ever read, and code
+/// generation reads them from several threads at once, so this is a lock
+/// rather than a `RefCell`. It offers the same `borrow` and `borrow_mut`
+/// methods as a `RefCell` would, and like a `RefCell` it never waits: a borrow
+/// that conflicts with one already held panics instead of deadlocking. Type
+/// variables are only mutated by the single thread inferring them, and only
+/// read by code generation, so a conflicting borrow is always a bug.
+///
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+pub struct TypeVarCell(RwLock<TypeVar>);
+
+impl TypeVarCell {
+    pub fn new(type_var: TypeVar) -> Self {
+        Self(RwLock::new(type_var))
+    }
+
+    pub fn borrow(&self) -> RwLockReadGuard<'_, TypeVar> {
+        self.try_borrow()
+            .expect("type variable already mutably borrowed")
+    }
+
+    pub fn borrow_mut(&self) -> RwLockWriteGuard<'_, TypeVar> {
+        match self.0.try_write() {
+            Ok(type_var) => type_var,
+            Err(TryLockError::Poisoned(error)) => error.into_inner(),
+            Err(TryLockError::WouldBlock) => panic!("type variable already borrowed"),
+        }
+    }
+
+    /// Returns `None` if the type variable is currently being mutated.
+    pub fn try_borrow(&self) -> Option<RwLockReadGuard<'_, TypeVar>> {
+        match self.0.try_read() {
+            Ok(type_var) => Some(type_var),
+            Err(TryLockError::Poisoned(error)) => Some(error.into_inner()),
+            Err(TryLockError::WouldBlock) => None,
+        }
+    }
+}
+
+impl std::fmt::Debug for TypeVarCell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.borrow().fmt(f)
+    }
+}
+
+impl PartialEq for TypeVarCell {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other) || *self.borrow() == *other.borrow()
+    }
+}
+
+impl Eq for TypeVarCell {}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TypeConstructor {
     pub publicity: Publicity,
@@ -1217,6 +1272,10 @@ pub struct TypeConstructor {
     pub type_: Arc<Type>,
     pub deprecation: Deprecation,
     pub documentation: Option<EcoString>,
+    /// Whether the type is an external Go type that is wrapped in a struct
+    /// when compiled to Go. Values of it are unwrapped when they are passed
+    /// to Go functions, which every module has to know to call them.
+    pub go_wrapped: bool,
 }
 impl TypeConstructor {
     pub(crate) fn with_location(mut self, location: SrcSpan) -> Self {
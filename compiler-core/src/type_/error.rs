@@ -452,6 +452,34 @@ pub enum Error {
         name: EcoString,
     },
 
+    /// A function's Go implementation refers to a Go FFI file of the package,
+    /// but no file declares a function with that name.
+    UnknownGoExternalFunction {
+        location: SrcSpan,
+        package: EcoString,
+        function: EcoString,
+    },
+
+    /// A function's Go implementation takes a different number of arguments
+    /// than the Gleam function.
+    IncorrectGoExternalArity {
+        location: SrcSpan,
+        function: EcoString,
+        expected: usize,
+        given: usize,
+        variadic: bool,
+    },
+
+    /// A parameter of a function's Go implementation can't accept the value
+    /// of the corresponding Gleam argument.
+    IncompatibleGoExternalParameter {
+        location: SrcSpan,
+        function: EcoString,
+        position: usize,
+        gleam_type: Arc<Type>,
+        go_type: EcoString,
+    },
+
     /// A case expression is missing one or more patterns to match all possible
     /// values of the type.
     InexhaustiveCaseExpression {
@@ -1053,6 +1081,9 @@ impl Error {
             | Error::InvalidExternalJavascriptFunction { location, .. }
             | Error::InvalidExternalGoPackage { location, .. }
             | Error::InvalidExternalGoFunction { location, .. }
+            | Error::UnknownGoExternalFunction { location, .. }
+            | Error::IncorrectGoExternalArity { location, .. }
+            | Error::IncompatibleGoExternalParameter { location, .. }
             | Error::InexhaustiveCaseExpression { location, .. }
             | Error::MissingCaseBody { location }
             | Error::InexhaustiveLetAssignment { location, .. }
@@ -161,46 +161,57 @@ impl FieldMap {
     /// ```
     ///
     pub fn missing_labels<A: std::fmt::Debug>(&self, args: &[CallArg<A>]) -> Vec<EcoString> {
-        let mut arg_position_to_label = self
-            .fields
-            .iter()
-            .map(|(label, position)| (position, label.clone()))
-            .collect::<HashMap<_, _>>();
+        let given = self
+            .argument_positions(args)
+            .into_iter()
+            .flatten()
+            .collect::<HashSet<_>>();
 
-        // We first get rid of all the labels taken by the positional arguments
-        // that have been supplied.
-        let mut position = 0;
-        for arg in args {
-            if arg.label.is_none() && !arg.is_use_implicit_callback() {
-                let _ = arg_position_to_label.remove(&position);
-                position += 1;
-            } else {
-                // As soon as we find an unlabelled argument we break out of the
-                // loop, we know that now there's only going to be labelled
-                // arguments
-                break;
-            }
-        }
-
-        let mut arg_label_to_position = arg_position_to_label
-            .iter()
-            .map(|(position, label)| (label.clone(), position))
-            .collect::<HashMap<_, _>>();
-
-        // Now we're just left with labelled args, we remove those from the
-        // remaining labels.
-        for arg in args.iter().skip(position as usize) {
-            if let Some(label) = &arg.label {
-                let _ = arg_label_to_position.remove(label);
-            }
-        }
-
-        arg_label_to_position
+        self.fields
             .iter()
+            .filter(|(_, position)| !given.contains(*position))
             .sorted_by_key(|(_, position)| *position)
             .map(|(label, _position)| label.clone())
             .collect_vec()
     }
+
+    /// This returns the position of the parameter each argument of an
+    /// argument list is passed to, whatever order labelled arguments are
+    /// given in.
+    /// Unlabelled arguments take the positions in order until the first
+    /// labelled one, the following ones have no position as they can't be
+    /// matched to a parameter. Neither can an argument with an unknown label,
+    /// or the implicit callback of a `use`.
+    ///
+    /// ## Examples
+    ///
+    /// ```gleam
+    /// pub fn wibble(label1 a, label2 b, label3 c) { todo }
+    ///
+    /// wibble(1, label3: 2, label2: 3) // -> positions: [0, 2, 1]
+    /// ```
+    ///
+    pub fn argument_positions<A: std::fmt::Debug>(&self, args: &[CallArg<A>]) -> Vec<Option<u32>> {
+        let mut positional = true;
+        let mut position = 0;
+        args.iter()
+            .map(|arg| match &arg.label {
+                _ if arg.is_use_implicit_callback() => {
+                    positional = false;
+                    None
+                }
+                None if positional => {
+                    position += 1;
+                    Some(position - 1)
+                }
+                None => None,
+                Some(label) => {
+                    positional = false;
+                    self.fields.get(label).copied()
+                }
+            })
+            .collect_vec()
+    }
 }
 
 #[derive(Debug)]
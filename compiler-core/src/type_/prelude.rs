@@ -10,10 +10,10 @@ use crate::{
 
 use super::{
     ModuleInterface, Type, TypeConstructor, TypeValueConstructor, TypeValueConstructorField,
-    TypeVar, TypeVariantConstructors, ValueConstructor, ValueConstructorVariant,
+    TypeVar, TypeVarCell, TypeVariantConstructors, ValueConstructor, ValueConstructorVariant,
 };
 use crate::type_::Deprecation::NotDeprecated;
-use std::{cell::RefCell, collections::HashMap, sync::Arc};
+use std::{collections::HashMap, sync::Arc};
 
 const BIT_ARRAY: &str = "BitArray";
 const BOOL: &str = "Bool";
@@ -201,20 +201,20 @@ pub fn utf_codepoint() -> Arc<Type> {
 
 pub fn generic_var(id: u64) -> Arc<Type> {
     Arc::new(Type::Var {
-        type_: Arc::new(RefCell::new(TypeVar::Generic { id })),
+        type_: Arc::new(TypeVarCell::new(TypeVar::Generic { id })),
     })
 }
 
 pub fn unbound_var(id: u64) -> Arc<Type> {
     Arc::new(Type::Var {
-        type_: Arc::new(RefCell::new(TypeVar::Unbound { id })),
+        type_: Arc::new(TypeVarCell::new(TypeVar::Unbound { id })),
     })
 }
 
 #[cfg(test)]
 pub fn link(type_: Arc<Type>) -> Arc<Type> {
     Arc::new(Type::Var {
-        type_: Arc::new(RefCell::new(TypeVar::Link { type_ })),
+        type_: Arc::new(TypeVarCell::new(TypeVar::Link { type_ })),
     })
 }
 
@@ -254,6 +254,7 @@ pub fn build_prelude(ids: &UniqueIdGenerator) -> ModuleInterface {
                     publicity: Publicity::Public,
                     deprecation: NotDeprecated,
                     documentation: None,
+                    go_wrapped: false,
                 };
                 let _ = prelude.types.insert(BIT_ARRAY.into(), v.clone());
             }
@@ -317,6 +318,7 @@ pub fn build_prelude(ids: &UniqueIdGenerator) -> ModuleInterface {
                         publicity: Publicity::Public,
                         deprecation: NotDeprecated,
                         documentation: None,
+                        go_wrapped: false,
                     },
                 );
             }
@@ -332,6 +334,7 @@ pub fn build_prelude(ids: &UniqueIdGenerator) -> ModuleInterface {
                         publicity: Publicity::Public,
                         deprecation: NotDeprecated,
                         documentation: None,
+                        go_wrapped: false,
                     },
                 );
             }
@@ -347,6 +350,7 @@ pub fn build_prelude(ids: &UniqueIdGenerator) -> ModuleInterface {
                         publicity: Publicity::Public,
                         deprecation: NotDeprecated,
                         documentation: None,
+                        go_wrapped: false,
                     },
                 );
             }
@@ -363,6 +367,7 @@ pub fn build_prelude(ids: &UniqueIdGenerator) -> ModuleInterface {
                         publicity: Publicity::Public,
                         deprecation: NotDeprecated,
                         documentation: None,
+                        go_wrapped: false,
                     },
                 );
             }
@@ -394,6 +399,7 @@ pub fn build_prelude(ids: &UniqueIdGenerator) -> ModuleInterface {
                         publicity: Publicity::Public,
                         deprecation: NotDeprecated,
                         documentation: None,
+                        go_wrapped: false,
                     },
                 );
                 let _ = prelude.types_value_constructors.insert(
@@ -423,6 +429,7 @@ pub fn build_prelude(ids: &UniqueIdGenerator) -> ModuleInterface {
                         publicity: Publicity::Public,
                         deprecation: NotDeprecated,
                         documentation: None,
+                        go_wrapped: false,
                     },
                 );
                 let _ = prelude.types_value_constructors.insert(
@@ -494,6 +501,7 @@ pub fn build_prelude(ids: &UniqueIdGenerator) -> ModuleInterface {
                         publicity: Publicity::Public,
                         deprecation: NotDeprecated,
                         documentation: None,
+                        go_wrapped: false,
                     },
                 );
             }
@@ -509,6 +517,7 @@ pub fn build_prelude(ids: &UniqueIdGenerator) -> ModuleInterface {
                         publicity: Publicity::Public,
                         deprecation: NotDeprecated,
                         documentation: None,
+                        go_wrapped: false,
                     },
                 );
             }
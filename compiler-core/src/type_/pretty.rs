@@ -8,8 +8,6 @@ use std::sync::Arc;
 
 #[cfg(test)]
 use super::*;
-#[cfg(test)]
-use std::cell::RefCell;
 
 #[cfg(test)]
 use pretty_assertions::assert_eq;
@@ -324,7 +322,7 @@ fn pretty_print_test() {
     );
     assert_string!(
         Type::Var {
-            type_: Arc::new(RefCell::new(TypeVar::Link {
+            type_: Arc::new(TypeVarCell::new(TypeVar::Link {
                 type_: Arc::new(Type::Named {
                     args: vec![],
                     module: "whatever".into(),
@@ -339,17 +337,17 @@ fn pretty_print_test() {
     );
     assert_string!(
         Type::Var {
-            type_: Arc::new(RefCell::new(TypeVar::Unbound { id: 2231 })),
+            type_: Arc::new(TypeVarCell::new(TypeVar::Unbound { id: 2231 })),
         },
         "a",
     );
     assert_string!(
         fn_(
             vec![Arc::new(Type::Var {
-                type_: Arc::new(RefCell::new(TypeVar::Unbound { id: 78 })),
+                type_: Arc::new(TypeVarCell::new(TypeVar::Unbound { id: 78 })),
             })],
             Arc::new(Type::Var {
-                type_: Arc::new(RefCell::new(TypeVar::Unbound { id: 2 })),
+                type_: Arc::new(TypeVarCell::new(TypeVar::Unbound { id: 2 })),
             }),
         ),
         "fn(a) -> b",
@@ -357,10 +355,10 @@ fn pretty_print_test() {
     assert_string!(
         fn_(
             vec![Arc::new(Type::Var {
-                type_: Arc::new(RefCell::new(TypeVar::Generic { id: 78 })),
+                type_: Arc::new(TypeVarCell::new(TypeVar::Generic { id: 78 })),
             })],
             Arc::new(Type::Var {
-                type_: Arc::new(RefCell::new(TypeVar::Generic { id: 2 })),
+                type_: Arc::new(TypeVarCell::new(TypeVar::Generic { id: 2 })),
             }),
         ),
         "fn(a) -> b",
@@ -505,7 +505,7 @@ fn test_generic_type_annotation() {
     let mut printer = Printer::new(&names);
 
     let type_ = Type::Var {
-        type_: Arc::new(std::cell::RefCell::new(TypeVar::Generic { id: 0 })),
+        type_: Arc::new(crate::type_::TypeVarCell::new(TypeVar::Generic { id: 0 })),
     };
 
     assert_eq!(printer.print_type(&type_), "one");
@@ -517,11 +517,11 @@ fn test_generic_type_var() {
     let mut printer = Printer::new(&names);
 
     let type_ = Type::Var {
-        type_: Arc::new(std::cell::RefCell::new(TypeVar::Unbound { id: 0 })),
+        type_: Arc::new(crate::type_::TypeVarCell::new(TypeVar::Unbound { id: 0 })),
     };
 
     let typ2 = Type::Var {
-        type_: Arc::new(std::cell::RefCell::new(TypeVar::Unbound { id: 1 })),
+        type_: Arc::new(crate::type_::TypeVarCell::new(TypeVar::Unbound { id: 1 })),
     };
 
     assert_eq!(printer.print_type(&type_), "a");
@@ -627,7 +627,7 @@ fn test_type_alias_and_generics() {
     let type_ = Type::Named {
         name: "Tiger".into(),
         args: vec![Arc::new(Type::Var {
-            type_: Arc::new(std::cell::RefCell::new(TypeVar::Generic { id: 0 })),
+            type_: Arc::new(crate::type_::TypeVarCell::new(TypeVar::Generic { id: 0 })),
         })],
         module: "mod".into(),
         publicity: crate::ast::Publicity::Public,
@@ -651,7 +651,7 @@ fn test_unqualified_import_and_generic() {
     let type_ = Type::Named {
         name: "Cat".into(),
         args: vec![Arc::new(Type::Var {
-            type_: Arc::new(std::cell::RefCell::new(TypeVar::Generic { id: 0 })),
+            type_: Arc::new(crate::type_::TypeVarCell::new(TypeVar::Generic { id: 0 })),
         })],
         module: "mod".into(),
         publicity: crate::ast::Publicity::Public,
@@ -747,10 +747,10 @@ fn test_multiple_generic_annotations() {
         name: "Tiger".into(),
         args: vec![
             Arc::new(Type::Var {
-                type_: Arc::new(std::cell::RefCell::new(TypeVar::Generic { id: 0 })),
+                type_: Arc::new(crate::type_::TypeVarCell::new(TypeVar::Generic { id: 0 })),
             }),
             Arc::new(Type::Var {
-                type_: Arc::new(std::cell::RefCell::new(TypeVar::Generic { id: 1 })),
+                type_: Arc::new(crate::type_::TypeVarCell::new(TypeVar::Generic { id: 1 })),
             }),
         ],
         module: "tigermodule".into(),
@@ -760,7 +760,7 @@ fn test_multiple_generic_annotations() {
     };
 
     let typ1 = Type::Var {
-        type_: Arc::new(std::cell::RefCell::new(TypeVar::Generic { id: 2 })),
+        type_: Arc::new(crate::type_::TypeVarCell::new(TypeVar::Generic { id: 2 })),
     };
 
     assert_eq!(printer.print_type(&type_), "tigermodule.Tiger(one, two)");
@@ -777,7 +777,7 @@ fn test_variable_name_already_in_scope() {
     let mut printer = Printer::new(&names);
 
     let type_ = |id| Type::Var {
-        type_: Arc::new(std::cell::RefCell::new(TypeVar::Generic { id })),
+        type_: Arc::new(crate::type_::TypeVarCell::new(TypeVar::Generic { id })),
     };
 
     assert_eq!(printer.print_type(&type_(0)), "c");
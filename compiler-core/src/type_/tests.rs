@@ -750,6 +750,33 @@ fn field_map_reorder_test() {
     .test();
 }
 
+#[test]
+fn field_map_argument_positions_test() {
+    let arg = |label: Option<&str>| CallArg {
+        implicit: None,
+        location: Default::default(),
+        label: label.map(EcoString::from),
+        value: UntypedExpr::Int {
+            value: "1".into(),
+            int_value: 1.into(),
+            location: Default::default(),
+        },
+    };
+    let fm = FieldMap {
+        arity: 3,
+        fields: [("b".into(), 1), ("c".into(), 2)].into(),
+    };
+
+    assert_eq!(
+        fm.argument_positions(&[arg(None), arg(Some("c")), arg(Some("b"))]),
+        vec![Some(0), Some(2), Some(1)]
+    );
+    assert_eq!(
+        fm.argument_positions(&[arg(Some("c")), arg(None), arg(Some("d"))]),
+        vec![Some(2), None, None]
+    );
+}
+
 #[test]
 fn infer_module_type_retention_test() {
     let module: UntypedModule = crate::ast::Module {
@@ -3084,3 +3111,30 @@ type Wibble {
 "
     );
 }
+
+#[test]
+fn type_variable_can_be_borrowed_many_times() {
+    let type_var = TypeVarCell::new(TypeVar::Unbound { id: 0 });
+    let first = type_var.borrow();
+    let second = type_var.borrow();
+    assert_eq!(*first, *second);
+    drop((first, second));
+    assert!(type_var.try_borrow().is_some());
+}
+
+#[test]
+#[should_panic(expected = "type variable already borrowed")]
+fn mutably_borrowing_borrowed_type_variable_panics() {
+    let type_var = TypeVarCell::new(TypeVar::Unbound { id: 0 });
+    let _borrowed = type_var.borrow();
+    drop(type_var.borrow_mut());
+}
+
+#[test]
+#[should_panic(expected = "type variable already mutably borrowed")]
+fn borrowing_mutably_borrowed_type_variable_panics() {
+    let type_var = TypeVarCell::new(TypeVar::Unbound { id: 0 });
+    let _borrowed = type_var.borrow_mut();
+    assert!(type_var.try_borrow().is_none());
+    drop(type_var.borrow());
+}
@@ -4,17 +4,54 @@ import (
 	"bytes"
 	"encoding/binary"
 	"fmt"
-	"hash"
-	"hash/fnv"
 	"math"
+	"reflect"
+	"strconv"
+	"strings"
+	"unicode"
 )
 
 const Use_Import byte = 0
 
-var hostIsBigEndian = false // TODO: detect
+// Values are hashed with 32-bit FNV-1 over their little-endian bytes. The
+// hash state is a plain uint32 threaded through the HashWrite functions, so
+// hashing never allocates.
+const (
+	hashOffset uint32 = 2166136261
+	hashPrime  uint32 = 16777619
+)
+
+func NewHash() uint32 {
+	return hashOffset
+}
+
+func HashWriteByte(h uint32, b byte) uint32 {
+	return (h * hashPrime) ^ uint32(b)
+}
+
+func HashWrite32(h uint32, v uint32) uint32 {
+	h = (h * hashPrime) ^ (v & 0xff)
+	h = (h * hashPrime) ^ (v >> 8 & 0xff)
+	h = (h * hashPrime) ^ (v >> 16 & 0xff)
+	return (h * hashPrime) ^ (v >> 24)
+}
 
-func NewHash() hash.Hash32 {
-	return fnv.New32()
+func HashWrite64(h uint32, v uint64) uint32 {
+	return HashWrite32(HashWrite32(h, uint32(v)), uint32(v >> 32))
+}
+
+func HashWriteString(h uint32, s string) uint32 {
+	for i := 0; i < len(s); i++ {
+		h = HashWriteByte(h, s[i])
+	}
+	return h
+}
+
+func HashWriteBytes(h uint32, b []byte) uint32 {
+	for _, c := range b {
+		h = HashWriteByte(h, c)
+	}
+	return h
 }
 
 type Type[T any] interface {
@@ -32,43 +69,21 @@ func (d Dynamic_t) Equal(o Dynamic_t) bool { return d.Value == o.Value }
 func HashTuple(valueHashes ...uint32) uint32 {
 	h := NewHash()
 	for _, hash := range valueHashes {
-		if _, err := h.Write([]byte{
-			byte(hash),
-			byte(hash >> 8),
-			byte(hash >> 16),
-			byte(hash >> 24),
-		}); err != nil {
-			panic(err)
-		}
+		h = HashWrite32(h, hash)
 	}
-	return h.Sum32()
+	return h
 }
 
 func HashConstructor(tag uint32, valueHashes ...uint32) uint32 {
-	h := NewHash()
-	if _, err := h.Write([]byte{
-		byte(tag),
-		byte(tag >> 8),
-		byte(tag >> 16),
-		byte(tag >> 24),
-	}); err != nil {
-		panic(err)
-	}
+	h := HashWrite32(NewHash(), tag)
 	for _, hash := range valueHashes {
-		if _, err := h.Write([]byte{
-			byte(hash),
-			byte(hash >> 8),
-			byte(hash >> 16),
-			byte(hash >> 24),
-		}); err != nil {
-			panic(err)
-		}
+		h = HashWrite32(h, hash)
 	}
-	return h.Sum32()
+	return h
 }
 
 type OrderedCollectionHasher struct {
-	h hash.Hash32
+	h uint32
 }
 
 func NewOrderedCollectionHasher() OrderedCollectionHasher {
@@ -76,18 +91,11 @@ func NewOrderedCollectionHasher() OrderedCollectionHasher {
 }
 
 func (a *OrderedCollectionHasher) WriteHash(elemHash uint32) {
-	if _, err := a.h.Write([]byte{
-		byte(elemHash),
-		byte(elemHash >> 8),
-		byte(elemHash >> 16),
-		byte(elemHash >> 24),
-	}); err != nil {
-		panic(err)
-	}
+	a.h = HashWrite32(a.h, elemHash)
 }
 
 func (a *OrderedCollectionHasher) Sum() uint32 {
-	return a.h.Sum32()
+	return a.h
 }
 
 type UnorderedCollectionHasher struct {
@@ -103,16 +111,82 @@ func (m *UnorderedCollectionHasher) WriteHash(elemHash uint32) {
 }
 
 func (m *UnorderedCollectionHasher) Sum() uint32 {
-	h := NewHash()
-	if _, err := h.Write([]byte{
-		byte(m.h),
-		byte(m.h >> 8),
-		byte(m.h >> 16),
-		byte(m.h >> 24),
-	}); err != nil {
-		panic(err)
+	return HashWrite32(NewHash(), m.h)
+}
+
+// Go external types that don't implement Type are wrapped in a struct whose
+// Hash and Equal methods use HashExternal and EqualExternal. Values are equal
+// if they are deeply equal, and the hash follows the same structure, giving up
+// below a fixed depth so that cyclic values can be hashed too.
+const externalHashDepth = 8
+
+func HashExternal(value any) uint32 {
+	return hashReflect(NewHash(), reflect.ValueOf(value), externalHashDepth)
+}
+
+func EqualExternal(a, b any) bool {
+	return reflect.DeepEqual(a, b)
+}
+
+func hashReflect(h uint32, v reflect.Value, depth int) uint32 {
+	if !v.IsValid() || depth == 0 {
+		return h
+	}
+	h = HashWriteByte(h, byte(v.Kind()))
+	switch v.Kind() {
+	case reflect.Bool:
+		if v.Bool() {
+			return HashWriteByte(h, 1)
+		}
+		return HashWriteByte(h, 0)
+	case reflect.Int, reflect.Int8, reflect.Int16, reflect.Int32, reflect.Int64:
+		return HashWrite64(h, uint64(v.Int()))
+	case reflect.Uint, reflect.Uint8, reflect.Uint16, reflect.Uint32, reflect.Uint64, reflect.Uintptr:
+		return HashWrite64(h, v.Uint())
+	case reflect.Float32, reflect.Float64:
+		f := v.Float()
+		if f == 0 {
+			// 0.0 and -0.0 are equal.
+			f = 0
+		}
+		return HashWrite64(h, math.Float64bits(f))
+	case reflect.Complex64, reflect.Complex128:
+		c := v.Complex()
+		return HashWrite64(HashWrite64(h, math.Float64bits(real(c))), math.Float64bits(imag(c)))
+	case reflect.String:
+		return HashWriteString(h, v.String())
+	case reflect.Array, reflect.Slice:
+		if v.Kind() == reflect.Slice && v.IsNil() {
+			return h
+		}
+		for i := 0; i < v.Len(); i++ {
+			h = hashReflect(h, v.Index(i), depth-1)
+		}
+		return h
+	case reflect.Map:
+		hasher := NewUnorderedCollectionHasher()
+		iter := v.MapRange()
+		for iter.Next() {
+			entry := hashReflect(NewHash(), iter.Key(), depth-1)
+			hasher.WriteHash(hashReflect(entry, iter.Value(), depth-1))
+		}
+		return HashWrite32(h, hasher.Sum())
+	case reflect.Struct:
+		for i := 0; i < v.NumField(); i++ {
+			h = hashReflect(h, v.Field(i), depth-1)
+		}
+		return h
+	case reflect.Pointer, reflect.Interface:
+		if v.IsNil() {
+			return h
+		}
+		return hashReflect(h, v.Elem(), depth-1)
+	case reflect.Chan, reflect.UnsafePointer:
+		return HashWrite64(h, uint64(v.Pointer()))
+	default:
+		// Functions are only deeply equal if they are both nil.
+		return h
 	}
-	return h.Sum32()
 }
 
 type Int_t int64
@@ -122,53 +196,22 @@ type String_t string
 type Bool_t bool
 
 func (i Int_t) Hash() uint32 {
-	h := NewHash()
-	if _, err := h.Write([]byte{
-		byte(i),
-		byte(i >> 8),
-		byte(i >> 16),
-		byte(i >> 24),
-		byte(i >> 32),
-		byte(i >> 40),
-		byte(i >> 48),
-		byte(i >> 56),
-	}); err != nil {
-		panic(err)
-	}
-	return h.Sum32()
+	return HashWrite64(NewHash(), uint64(i))
 }
 func (i Int_t) Equal(o Int_t) bool { return i == o }
 
 func (f Float_t) Hash() uint32 {
-	h := NewHash()
-	if err := binary.Write(h,
-		binary.LittleEndian, f); err != nil {
-		panic(err)
-	}
-	return h.Sum32()
+	return HashWrite64(NewHash(), math.Float64bits(float64(f)))
 }
 func (f Float_t) Equal(o Float_t) bool { return f == o }
 
 func (c UtfCodepoint_t) Hash() uint32 {
-	h := NewHash()
-	if _, err := h.Write([]byte{
-		byte(c),
-		byte(c >> 8),
-		byte(c >> 16),
-		byte(c >> 24),
-	}); err != nil {
-		panic(err)
-	}
-	return h.Sum32()
+	return HashWrite32(NewHash(), uint32(c))
 }
 func (c UtfCodepoint_t) Equal(o UtfCodepoint_t) bool { return c == o }
 
 func (s String_t) Hash() uint32 {
-	h := NewHash()
-	if _, err := h.Write([]byte(s)); err != nil {
-		panic(err)
-	}
-	return h.Sum32()
+	return HashWriteString(NewHash(), string(s))
 }
 func (s String_t) Equal(o String_t) bool { return s == o }
 
@@ -538,6 +581,14 @@ type Nil_t = Nil_c
 func (Nil_c) Hash() uint32     { return NilHash }
 func (Nil_c) Equal(Nil_c) bool { return true }
 
+// Unbound_t is used for type variables that are never resolved, such as the
+// error type of `Ok(1)` when nothing else constrains it. No value of this type
+// is ever constructed.
+type Unbound_t struct{}
+
+func (Unbound_t) Hash() uint32         { return NilHash }
+func (Unbound_t) Equal(Unbound_t) bool { return true }
+
 type Result_t[T Type[T], E Type[E]] interface {
 	iResult_t(T, E)
 	IsOk() Bool_t
@@ -598,8 +649,7 @@ var _ Result_dyn = Error_c[Nil_t, Nil_t]{}
 
 type List_t[T Type[T]] interface {
 	iList_t(T)
-	HasLength(int) Bool_t
-	AtLeastLength(int) Bool_t
+	IsEmpty() Bool_t
 	Head() T
 	Tail() List_t[T]
 	List_dyn
@@ -614,8 +664,7 @@ type List_dyn interface {
 type Empty_c[T Type[T]] struct{}
 
 func (Empty_c[T]) iList_t(T)                    {}
-func (Empty_c[T]) HasLength(n int) Bool_t       { return n == 0 }
-func (Empty_c[T]) AtLeastLength(n int) Bool_t   { return n <= 0 }
+func (Empty_c[T]) IsEmpty() Bool_t              { return true }
 func (Empty_c[T]) Head() T                      { panic("Empty list") }
 func (Empty_c[T]) Tail() List_t[T]              { panic("Empty list") }
 func (Empty_c[T]) ToDynamic() List_t[Dynamic_t] { return Empty_c[Dynamic_t]{} }
@@ -630,11 +679,11 @@ type Nonempty_c[T Type[T]] struct {
 	P_1 List_t[T]
 }
 
-func (Nonempty_c[T]) iList_t(T)                    {}
-func (l Nonempty_c[T]) HasLength(n int) Bool_t     { return n > 0 && l.P_1.HasLength(n-1) }
-func (l Nonempty_c[T]) AtLeastLength(n int) Bool_t { return n <= 1 || l.P_1.AtLeastLength(n-1) }
-func (l Nonempty_c[T]) Head() T                    { return l.P_0 }
-func (l Nonempty_c[T]) Tail() List_t[T]            { return l.P_1 }
+func (Nonempty_c[T]) iList_t(T)         {}
+func (Nonempty_c[T]) IsEmpty() Bool_t   { return false }
+func (l Nonempty_c[T]) Head() T         { return l.P_0 }
+func (l Nonempty_c[T]) Tail() List_t[T] { return l.P_1 }
+
 func (l Nonempty_c[T]) ToDynamic() List_t[Dynamic_t] {
 	return Nonempty_c[Dynamic_t]{Dynamic_t{l.P_0}, l.P_1.ToDynamic()}
 }
@@ -671,14 +720,55 @@ func ListPrepend[T Type[T]](x T, xs List_t[T]) List_t[T] {
 	return Nonempty_c[T]{P_0: x, P_1: xs}
 }
 
+// SliceToList and ListToSlice convert between Gleam lists and Go slices,
+// converting each element with the given function. They are used by the Go
+// facade packages generated for Gleam libraries.
+func SliceToList[A any, T Type[T]](xs []A, convert func(A) T) List_t[T] {
+	var list List_t[T] = Empty_c[T]{}
+	for i := len(xs) - 1; i >= 0; i-- {
+		list = Nonempty_c[T]{P_0: convert(xs[i]), P_1: list}
+	}
+	return list
+}
+
+func ListToSlice[T Type[T], A any](list List_t[T], convert func(T) A) []A {
+	xs := []A{}
+	for ; !list.IsEmpty(); list = list.Tail() {
+		xs = append(xs, convert(list.Head()))
+	}
+	return xs
+}
+
+// ResultError is the Go error for a Gleam Error value, which it holds.
+type ResultError struct {
+	Value any
+}
+
+func (e ResultError) Error() string { return Inspect(e.Value) }
+
+// ResultToGo converts a Gleam result to the Go convention of returning a
+// value along with an error, converting an Ok value with the given function.
+func ResultToGo[T Type[T], E Type[E], A any](result Result_t[T, E], convert func(T) A) (A, error) {
+	if result.IsError() {
+		var zero A
+		return zero, ResultError{result.AsError().P_0}
+	}
+	return convert(result.AsOk().P_0), nil
+}
+
+// ResultErrorToGo converts a Gleam result with no Ok value worth returning
+// to a Go error, which is nil for an Ok.
+func ResultErrorToGo[T Type[T], E Type[E]](result Result_t[T, E]) error {
+	if result.IsError() {
+		return ResultError{result.AsError().P_0}
+	}
+	return nil
+}
+
 type BitArray_t []byte
 
 func (b BitArray_t) Hash() uint32 {
-	h := NewHash()
-	if _, err := h.Write(b); err != nil {
-		panic(err)
-	}
-	return h.Sum32()
+	return HashWriteBytes(NewHash(), b)
 }
 func (b BitArray_t) Equal(o BitArray_t) bool { return bytes.Equal(b, o) }
 
@@ -694,31 +784,32 @@ func (b BitArray_t) Buffer() []byte {
 	return b
 }
 
-func (b BitArray_t) ByteAt(i int) int64 {
+func (b BitArray_t) ByteAt(i int) Int_t {
 	if i >= len(b) {
 		return -1
 	}
-	return int64(b[i])
+	return Int_t(b[i])
 }
 
-func SizedInt(value int64, size int64, isBigEndian Bool_t) []byte {
+// SizedInt encodes the low `size` bits of an integer, which must be a whole
+// number of bytes. Sizes larger than 64 bits are sign extended.
+func SizedInt(value Int_t, size Int_t, isBigEndian Bool_t) []byte {
+	if size <= 0 {
+		return []byte{}
+	}
 	bytes := make([]byte, size/8)
-	if size == 32 {
-		bits := uint32(value)
-		if hostIsBigEndian == bool(isBigEndian) {
-			binary.LittleEndian.PutUint32(bytes, bits)
-		} else {
-			binary.BigEndian.PutUint32(bytes, bits)
+	for i := range bytes {
+		var b byte
+		if i < 8 {
+			b = byte(uint64(value) >> (8 * i))
+		} else if value < 0 {
+			b = 0xff
 		}
-	} else if size == 64 {
-		bits := uint64(value)
-		if hostIsBigEndian == bool(isBigEndian) {
-			binary.LittleEndian.PutUint64(bytes, bits)
+		if isBigEndian {
+			bytes[len(bytes)-1-i] = b
 		} else {
-			binary.BigEndian.PutUint64(bytes, bits)
+			bytes[i] = b
 		}
-	} else {
-		panic(fmt.Sprintf("Sized floats must be 32-bit or 64-bit on Go, got size of %d bits", size))
 	}
 	return bytes
 }
@@ -727,17 +818,17 @@ func SizedFloat(value Float_t, size Int_t, isBigEndian Bool_t) []byte {
 	bytes := make([]byte, size/8)
 	if size == 32 {
 		bits := math.Float32bits(float32(value))
-		if hostIsBigEndian == bool(isBigEndian) {
-			binary.LittleEndian.PutUint32(bytes, bits)
-		} else {
+		if isBigEndian {
 			binary.BigEndian.PutUint32(bytes, bits)
+		} else {
+			binary.LittleEndian.PutUint32(bytes, bits)
 		}
 	} else if size == 64 {
 		bits := math.Float64bits(float64(value))
-		if hostIsBigEndian == bool(isBigEndian) {
-			binary.LittleEndian.PutUint64(bytes, bits)
-		} else {
+		if isBigEndian {
 			binary.BigEndian.PutUint64(bytes, bits)
+		} else {
+			binary.LittleEndian.PutUint64(bytes, bits)
 		}
 	} else {
 		panic(fmt.Sprintf("Sized floats must be 32-bit or 64-bit on Go, got size of %d bits", size))
@@ -745,7 +836,7 @@ func SizedFloat(value Float_t, size Int_t, isBigEndian Bool_t) []byte {
 	return bytes
 }
 
-func StringBits(s string) []byte {
+func StringBits(s String_t) []byte {
 	return []byte(s)
 }
 
@@ -784,7 +875,7 @@ func byteArrayToInt(byteArray BitArray_t, start Int_t, end Int_t, isBigEndian Bo
 	// For signed integers, check if the high bit is set and if so then
 	// reinterpret as two's complement
 	if isSigned {
-		highBit := Int_t(2) << (byteSize*8 - 1)
+		highBit := Int_t(1) << (byteSize*8 - 1)
 		if value >= highBit {
 			value -= highBit * 2
 		}
@@ -798,24 +889,24 @@ func byteArrayToFloat(byteArray BitArray_t, start, end Int_t, isBigEndian Bool_t
 
 	if byteSize == 8 {
 		bytes := byteArray[start:end]
-		if hostIsBigEndian == bool(isBigEndian) {
-			return Float_t(math.Float64frombits(binary.LittleEndian.Uint64(bytes)))
-		} else {
+		if isBigEndian {
 			return Float_t(math.Float64frombits(binary.BigEndian.Uint64(bytes)))
+		} else {
+			return Float_t(math.Float64frombits(binary.LittleEndian.Uint64(bytes)))
 		}
 	} else if byteSize == 4 {
 		bytes := byteArray[start:end]
-		if hostIsBigEndian == bool(isBigEndian) {
-			return Float_t(math.Float32frombits(binary.LittleEndian.Uint32(bytes)))
-		} else {
+		if isBigEndian {
 			return Float_t(math.Float32frombits(binary.BigEndian.Uint32(bytes)))
+		} else {
+			return Float_t(math.Float32frombits(binary.LittleEndian.Uint32(bytes)))
 		}
 	} else {
 		panic(fmt.Sprintf("Sized floats must be 32-bit or 64-bit on Go, got size of %d bits", byteSize*8))
 	}
 }
 
-func CodepointBits(codepoint rune) []byte {
+func CodepointBits(codepoint UtfCodepoint_t) []byte {
 	return []byte(string(codepoint))
 }
 
@@ -833,6 +924,19 @@ func RemainderInt(a Int_t, b Int_t) Int_t {
 	return a % b
 }
 
+// Floats behave the same on Go as they do on Erlang:
+//
+//   - Dividing by zero returns zero rather than infinity or NaN.
+//   - An operation whose result overflows to infinity panics, where Erlang
+//     raises a badarith error. The generated code passes the result of every
+//     float +. -. *. and /. through CheckFloat.
+//   - A clause guard whose float arithmetic overflows doesn't match, as an
+//     error in an Erlang guard makes it fail. Such guards are run with
+//     CheckGuard.
+//
+// Gleam code therefore never sees an infinite or NaN float. The contract is
+// documented for all targets in docs/runtime-errors.md.
+
 func DivideFloat(a Float_t, b Float_t) Float_t {
 	if b == 0.0 {
 		return 0.0
@@ -840,6 +944,136 @@ func DivideFloat(a Float_t, b Float_t) Float_t {
 	return a / b
 }
 
+func CheckFloat(f Float_t, module string, line int, fn string) Float_t {
+	if math.IsInf(float64(f), 0) || math.IsNaN(float64(f)) {
+		floatOverflow(f, module, line, fn)
+	}
+	return f
+}
+
+// floatOverflowError is the value CheckFloat panics with, so that CheckGuard
+// can tell it apart from any other panic.
+type floatOverflowError struct {
+	error
+}
+
+// floatOverflow is kept out of CheckFloat so that CheckFloat can be inlined.
+func floatOverflow(f Float_t, module string, line int, fn string) {
+	panic(floatOverflowError{MakeError("badarith", module, line, fn, "Float arithmetic overflowed", map[string]any{"value": float64(f)})})
+}
+
+func CheckGuard(guard func() Bool_t) (matches Bool_t) {
+	defer func() {
+		if r := recover(); r != nil {
+			if _, ok := r.(floatOverflowError); !ok {
+				panic(r)
+			}
+			matches = false
+		}
+	}()
+	return guard()
+}
+
 func MakeError(variant string, module string, line int, fn string, message string, extra any) error {
 	return fmt.Errorf("%s: %s:%d:%s: %s (%#v)", variant, module, line, fn, message, extra)
 }
+
+// Inspect formats a value as Gleam source code, the way `string.inspect`
+// does on the other targets. Records are recognised by the `_c` suffix of
+// the names of the structs they are generated as.
+func Inspect(value any) string {
+	var b strings.Builder
+	inspect(&b, reflect.ValueOf(value))
+	return b.String()
+}
+
+func inspect(b *strings.Builder, v reflect.Value) {
+	switch v.Kind() {
+	case reflect.Invalid:
+		b.WriteString("Nil")
+	case reflect.Interface, reflect.Pointer:
+		inspect(b, v.Elem())
+	case reflect.Bool:
+		if v.Bool() {
+			b.WriteString("True")
+		} else {
+			b.WriteString("False")
+		}
+	case reflect.Int64:
+		b.WriteString(strconv.FormatInt(v.Int(), 10))
+	case reflect.Int32:
+		fmt.Fprintf(b, "//utfcodepoint(%c)", rune(v.Int()))
+	case reflect.Float64:
+		f := strconv.FormatFloat(v.Float(), 'f', -1, 64)
+		if !strings.Contains(f, ".") {
+			f += ".0"
+		}
+		b.WriteString(f)
+	case reflect.String:
+		b.WriteString(strconv.Quote(v.String()))
+	case reflect.Slice:
+		if v.Type().Elem().Kind() != reflect.Uint8 {
+			fmt.Fprintf(b, "//go(%v)", v)
+			return
+		}
+		b.WriteString("<<")
+		for i := 0; i < v.Len(); i++ {
+			if i > 0 {
+				b.WriteString(", ")
+			}
+			b.WriteString(strconv.FormatUint(v.Index(i).Uint(), 10))
+		}
+		b.WriteString(">>")
+	case reflect.Func:
+		b.WriteString("//fn(...) { ... }")
+	case reflect.Struct:
+		inspectStruct(b, v)
+	default:
+		fmt.Fprintf(b, "//go(%v)", v)
+	}
+}
+
+func inspectStruct(b *strings.Builder, v reflect.Value) {
+	name, _, _ := strings.Cut(v.Type().Name(), "[")
+	switch {
+	case name == "Dynamic_t":
+		inspect(b, v.Field(0))
+		return
+	case name == "Nil_c":
+		b.WriteString("Nil")
+		return
+	case name == "Empty_c" || name == "Nonempty_c":
+		b.WriteString("[")
+		for first := true; strings.HasPrefix(v.Type().Name(), "Nonempty_c"); first = false {
+			if !first {
+				b.WriteString(", ")
+			}
+			inspect(b, v.Field(0))
+			v = v.Field(1).Elem()
+		}
+		b.WriteString("]")
+		return
+	case strings.HasPrefix(name, "Tuple"):
+		b.WriteString("#")
+	case name == "":
+		// Anonymous structs only come from Go code, as the value of an
+		// external type, and are not Gleam records.
+		fmt.Fprintf(b, "//go(%v)", v)
+		return
+	default:
+		constructor := []rune(strings.TrimSuffix(name, "_c"))
+		constructor[0] = unicode.ToUpper(constructor[0])
+		b.WriteString(string(constructor))
+		if v.NumField() == 0 {
+			return
+		}
+	}
+	b.WriteString("(")
+	for i := 0; i < v.NumField(); i++ {
+		if i > 0 {
+			b.WriteString(", ")
+		}
+		inspect(b, v.Field(i))
+	}
+	b.WriteString(")")
+}
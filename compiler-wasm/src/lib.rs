@@ -153,6 +153,25 @@ pub fn read_compiled_erlang(project_id: usize, module_name: &str) -> Option<Stri
     fs.read(&Utf8PathBuf::from(path)).ok()
 }
 
+/// Get the compiled Go output for a given module.
+///
+/// You need to call `compile_package` before calling this function.
+///
+#[wasm_bindgen]
+pub fn read_compiled_go(project_id: usize, module_name: &str) -> Option<String> {
+    let fs = get_filesystem(project_id);
+    let path = format!("/build/{module_name}/gleam_generated.go");
+    fs.read(&Utf8PathBuf::from(path)).ok()
+}
+
+/// Get the Go prelude, which the compiled Go modules import as the `gleam`
+/// package.
+///
+#[wasm_bindgen]
+pub fn read_go_prelude() -> String {
+    gleam_core::go::PRELUDE.into()
+}
+
 /// Clear any stored warnings. This is performed automatically when before compilation.
 ///
 #[wasm_bindgen]
@@ -188,6 +207,7 @@ fn do_compile_package(project: Project, target: Target) -> Result<(), Error> {
         },
         Target::Go => TargetCodegenConfiguration::Go {
             go_module_path: "example.com/todo".into(), // TODO
+            package_names: Default::default(),
         },
     };
 
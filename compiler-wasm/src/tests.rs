@@ -75,6 +75,67 @@ export function go() {
     );
 }
 
+#[wasm_bindgen_test]
+fn test_compile_package_go() {
+    reset_filesystem(0);
+    write_module(0, "one/two", "pub const x = 1");
+    write_module(0, "up/down", "import one/two pub fn go() { two.x }");
+    assert!(compile_package(0, "go").is_ok());
+
+    assert_eq!(
+        read_compiled_go(0, "one/two"),
+        Some(
+            r#"package one_two_P
+
+import (
+  gleam_P "example.com/todo/gleam"
+)
+
+const _ = gleam_P.Use_Import
+const Use_Import byte = 0
+
+var X gleam_P.Int_t = 1
+"#
+            .into()
+        )
+    );
+
+    assert_eq!(
+        read_compiled_go(0, "up/down"),
+        Some(
+            r#"package up_down_P
+
+import (
+  gleam_P "example.com/todo/gleam"
+  one_two_P "example.com/todo/library/one/two"
+)
+
+const _ = gleam_P.Use_Import
+const _ = one_two_P.Use_Import
+const Use_Import byte = 0
+
+func Go() gleam_P.Int_t {
+  return one_two_P.X
+}
+"#
+            .into()
+        )
+    );
+
+    // The other targets' output is not written
+    assert_eq!(read_compiled_javascript(0, "up/down"), None);
+    assert_eq!(read_compiled_erlang(0, "up/down"), None);
+
+    // And now an error!
+    write_module(0, "up/down", "import one/two/three");
+    assert!(compile_package(0, "go").is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_read_go_prelude() {
+    assert!(read_go_prelude().starts_with("package gleam\n"));
+}
+
 #[wasm_bindgen_test]
 fn test_compile_package_js_unsupported_feature() {
     reset_filesystem(0);
@@ -0,0 +1,7 @@
+package one_P
+
+import gleam_P "example.com/todo/gleam"
+
+func Length(text string) gleam_P.Int_t {
+	return gleam_P.Int_t(len(text))
+}
@@ -0,0 +1,16 @@
+package helpers
+
+import gleam "example.com/todo/gleam"
+
+// Join concatenates the parts, placing the separator between each of them.
+func Join(parts gleam.List_t[gleam.String_t], separator gleam.String_t) gleam.String_t {
+	result := gleam.String_t("")
+	for !parts.IsEmpty() {
+		result += parts.Head()
+		parts = parts.Tail()
+		if !parts.IsEmpty() {
+			result += separator
+		}
+	}
+	return result
+}
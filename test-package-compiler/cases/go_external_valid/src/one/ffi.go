@@ -0,0 +1,11 @@
+package one_P
+
+import gleam_P "example.com/todo/gleam"
+
+func Length(text gleam_P.String_t) gleam_P.Int_t {
+	return gleam_P.Int_t(len(text))
+}
+
+func Identity[A gleam_P.Type[A]](x A) A {
+	return x
+}
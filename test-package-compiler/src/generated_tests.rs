@@ -111,6 +111,72 @@ fn erlang_nested_qualified_constant() {
     );
 }
 
+#[rustfmt::skip]
+#[test]
+fn go_external_incompatible_parameter() {
+    let output = crate::prepare("./cases/go_external_incompatible_parameter");
+    insta::assert_snapshot!(
+        "go_external_incompatible_parameter",
+        output,
+        "./cases/go_external_incompatible_parameter",
+    );
+}
+
+#[rustfmt::skip]
+#[test]
+fn go_external_incorrect_arity() {
+    let output = crate::prepare("./cases/go_external_incorrect_arity");
+    insta::assert_snapshot!(
+        "go_external_incorrect_arity",
+        output,
+        "./cases/go_external_incorrect_arity",
+    );
+}
+
+#[rustfmt::skip]
+#[test]
+fn go_external_unknown_function() {
+    let output = crate::prepare("./cases/go_external_unknown_function");
+    insta::assert_snapshot!(
+        "go_external_unknown_function",
+        output,
+        "./cases/go_external_unknown_function",
+    );
+}
+
+#[rustfmt::skip]
+#[test]
+fn go_external_valid() {
+    let output = crate::prepare("./cases/go_external_valid");
+    insta::assert_snapshot!(
+        "go_external_valid",
+        output,
+        "./cases/go_external_valid",
+    );
+}
+
+#[rustfmt::skip]
+#[test]
+fn go_package_name_clash() {
+    let output = crate::prepare("./cases/go_package_name_clash");
+    insta::assert_snapshot!(
+        "go_package_name_clash",
+        output,
+        "./cases/go_package_name_clash",
+    );
+}
+
+#[rustfmt::skip]
+#[test]
+fn go_package_names() {
+    let output = crate::prepare("./cases/go_package_names");
+    insta::assert_snapshot!(
+        "go_package_names",
+        output,
+        "./cases/go_package_names",
+    );
+}
+
 #[rustfmt::skip]
 #[test]
 fn hello_joe() {
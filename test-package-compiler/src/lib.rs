@@ -35,6 +35,7 @@ pub fn prepare(path: &str) -> String {
         },
         Target::Go => TargetCodegenConfiguration::Go {
             go_module_path: "example.com/todo".into(), // TODO
+            package_names: config.go.package_names.clone(),
         },
     };
 
@@ -0,0 +1,156 @@
+//! Benchmarks type checking a project, and generating code for it on a single
+//! thread against generating it on all available threads.
+//!
+//! By default the small project in `benches/project` is compiled. A larger
+//! one can be given with the `GLEAM_BENCH_PROJECT` environment variable, for
+//! example a checkout of the standard library:
+//!
+//! ```sh
+//! git clone https://github.com/gleam-lang/stdlib /tmp/stdlib
+//! GLEAM_BENCH_PROJECT=/tmp/stdlib cargo bench -p test-project-compiler
+//! ```
+//!
+//! Only the project's own modules are generated, so it shouldn't have any
+//! dependencies.
+
+use camino::{Utf8Path, Utf8PathBuf};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use gleam_core::{
+    analyse::TargetSupport,
+    build::{Codegen, Compile, Mode, Module, NullTelemetry, Options, ProjectCompiler, Target},
+    codegen::{Erlang, Go, JavaScript, TypeScriptDeclarations},
+    config::PackageConfig,
+    io::{memory::InMemoryFileSystem, FileSystemWriter},
+    paths::ProjectPaths,
+    warning::VectorWarningEmitterIO,
+};
+use std::rc::Rc;
+
+struct Project {
+    root: Utf8PathBuf,
+    config: PackageConfig,
+    files: Vec<(Utf8PathBuf, Vec<u8>)>,
+}
+
+impl Project {
+    /// Reads the project's files, keeping their full paths so that they are
+    /// found relative to the project root, and skipping any earlier build
+    /// output.
+    fn read(root: Utf8PathBuf) -> Self {
+        let toml = std::fs::read_to_string(root.join("gleam.toml")).unwrap();
+        let config = toml::from_str(&toml).unwrap();
+        let files = walkdir::WalkDir::new(&root)
+            .into_iter()
+            .filter_entry(|entry| entry.file_name() != "build")
+            .filter_map(Result::ok)
+            .filter(|entry| entry.file_type().is_file())
+            .map(|entry| {
+                let path = Utf8PathBuf::from_path_buf(entry.into_path()).unwrap();
+                let content = std::fs::read(&path).unwrap();
+                (path, content)
+            })
+            .collect();
+        Self {
+            root,
+            config,
+            files,
+        }
+    }
+
+    /// Parses and type checks the project, without generating any code.
+    fn analyse(&self, target: Target) -> Option<Vec<Module>> {
+        let filesystem = InMemoryFileSystem::new();
+        for (path, content) in &self.files {
+            filesystem.write_bytes(path, content).unwrap();
+        }
+        let options = Options {
+            mode: Mode::Dev,
+            target: Some(target),
+            compile: Compile::All,
+            codegen: Codegen::None,
+            warnings_as_errors: false,
+            root_target_support: TargetSupport::Enforced,
+            no_print_progress: true,
+            entrypoint: None,
+        };
+        let compiler = ProjectCompiler::new(
+            self.config.clone(),
+            options,
+            vec![],
+            &NullTelemetry,
+            Rc::new(VectorWarningEmitterIO::default()),
+            ProjectPaths::new(self.root.clone()),
+            filesystem,
+        );
+        let built = compiler.compile().ok()?;
+        Some(built.root_package.modules)
+    }
+
+    fn generate(&self, target: Target, modules: &[Module]) {
+        let writer = InMemoryFileSystem::new();
+        let out = Utf8Path::new("/out");
+        let result = match target {
+            Target::Erlang => Erlang::new(out, out).render(writer.clone(), modules),
+            Target::JavaScript => JavaScript::new(
+                out,
+                TypeScriptDeclarations::None,
+                out,
+                TargetSupport::Enforced,
+            )
+            .render(&writer, modules),
+            Target::Go => Go::new(
+                out,
+                "example.com/bench",
+                &self.config.go.package_names,
+                TargetSupport::Enforced,
+            )
+            .render(&writer, &im::HashMap::new(), modules),
+        };
+        result.unwrap();
+    }
+}
+
+fn type_check(c: &mut Criterion, project: &Project) {
+    let mut group = c.benchmark_group("type check");
+    for target in [Target::Erlang, Target::JavaScript, Target::Go] {
+        if project.analyse(target).is_none() {
+            eprintln!("Skipping {target}, the project doesn't compile for it");
+            continue;
+        }
+        let _ = group.bench_function(target.to_string(), |b| b.iter(|| project.analyse(target)));
+    }
+    group.finish();
+}
+
+fn codegen(c: &mut Criterion, project: &Project) {
+    let single_thread = rayon::ThreadPoolBuilder::new()
+        .num_threads(1)
+        .build()
+        .unwrap();
+    let all_threads = rayon::ThreadPoolBuilder::new().build().unwrap();
+
+    let mut group = c.benchmark_group("codegen");
+    for target in [Target::Erlang, Target::JavaScript, Target::Go] {
+        let Some(modules) = project.analyse(target) else {
+            eprintln!("Skipping {target}, the project doesn't compile for it");
+            continue;
+        };
+        for (threads, pool) in [("1 thread", &single_thread), ("all threads", &all_threads)] {
+            let _ = group.bench_function(BenchmarkId::new(target.to_string(), threads), |b| {
+                b.iter(|| pool.install(|| project.generate(target, &modules)))
+            });
+        }
+    }
+    group.finish();
+}
+
+fn benchmarks(c: &mut Criterion) {
+    let root = std::env::var("GLEAM_BENCH_PROJECT")
+        .unwrap_or_else(|_| concat!(env!("CARGO_MANIFEST_DIR"), "/benches/project").into());
+    let project = Project::read(Utf8PathBuf::from(root).canonicalize_utf8().unwrap());
+    type_check(c, &project);
+    codegen(c, &project);
+}
+
+criterion_group!(benches, benchmarks);
+criterion_main!(benches);
@@ -0,0 +1,10 @@
+package dead_code_P
+
+import (
+	dead_code_by_go_P "example.com/todo/dead_code/dead_code/by_go"
+	gleam_P "example.com/todo/gleam"
+)
+
+func Call() gleam_P.Int_t {
+	return dead_code_by_go_P.CalledFromGo()
+}
@@ -0,0 +1,5 @@
+import { called_from_javascript } from "./dead_code/by_javascript.mjs";
+
+export function call() {
+  return called_from_javascript();
+}
@@ -3,6 +3,39 @@
 
 use gleam_core::build::Mode;
 
+#[rustfmt::skip]
+#[test]
+fn dead_code_dev() {
+    let output = crate::prepare("./cases/dead_code", Mode::Dev);
+    insta::assert_snapshot!(
+        "dead_code_dev",
+        output,
+        "./cases/dead_code",
+    );
+}
+
+#[rustfmt::skip]
+#[test]
+fn dead_code_prod() {
+    let output = crate::prepare("./cases/dead_code", Mode::Prod);
+    insta::assert_snapshot!(
+        "dead_code_prod",
+        output,
+        "./cases/dead_code",
+    );
+}
+
+#[rustfmt::skip]
+#[test]
+fn dead_code_lsp() {
+    let output = crate::prepare("./cases/dead_code", Mode::Lsp);
+    insta::assert_snapshot!(
+        "dead_code_lsp",
+        output,
+        "./cases/dead_code",
+    );
+}
+
 #[rustfmt::skip]
 #[test]
 fn with_dep_dev() {
@@ -1,20 +1,69 @@
 #[cfg(test)]
 mod generated_tests;
+#[cfg(test)]
+mod tests;
 
 use camino::Utf8PathBuf;
+use ecow::EcoString;
 use gleam_core::{
     analyse::TargetSupport,
-    build::{Codegen, Compile, Mode, NullTelemetry, Options, ProjectCompiler, Telemetry},
+    build::{Codegen, Compile, Mode, NullTelemetry, Options, ProjectCompiler, Target, Telemetry},
     config::PackageConfig,
-    io::{FileSystemReader, FileSystemWriter},
+    io::{memory::InMemoryFileSystem, FileSystemReader, FileSystemWriter},
     paths::ProjectPaths,
     warning::VectorWarningEmitterIO,
 };
 use std::rc::Rc;
+use test_helpers_rs::TestCompileOutput;
 
 pub fn prepare(path: &str, mode: Mode) -> String {
     let root = Utf8PathBuf::from(path).canonicalize_utf8().unwrap();
     let filesystem = test_helpers_rs::to_in_memory_filesystem(&root);
+    compile(root, filesystem, mode, None, None).as_overview_text()
+}
+
+/// Compiles the project in production mode for the given target, with the
+/// code unreachable from the entrypoint module's `main` function left out.
+/// Only the modules generated for the project itself are returned.
+pub fn prepare_with_entrypoint(path: &str, target: Target, entrypoint: &str) -> String {
+    let root = Utf8PathBuf::from(path).canonicalize_utf8().unwrap();
+
+    // The files are placed under the project's full path so that its modules
+    // are found in its source directory.
+    let filesystem = InMemoryFileSystem::new();
+    for path in test_helpers_rs::to_in_memory_filesystem(&root).files() {
+        let content = std::fs::read(root.join(&path)).unwrap();
+        filesystem.write_bytes(&root.join(path), &content).unwrap();
+    }
+
+    let toml = std::fs::read_to_string(root.join("gleam.toml")).unwrap();
+    let config: PackageConfig = toml::from_str(&toml).unwrap();
+    let package = ProjectPaths::new(root.clone()).build_directory_for_package(
+        Mode::Prod,
+        target,
+        &config.name,
+    );
+
+    let mut output = compile(
+        root,
+        filesystem,
+        Mode::Prod,
+        Some(target),
+        Some(entrypoint.into()),
+    );
+    output.files.retain(|path, _| {
+        path.starts_with(&package) && !path.components().any(|c| c.as_str() == "_gleam_artefacts")
+    });
+    output.as_overview_text()
+}
+
+fn compile(
+    root: Utf8PathBuf,
+    filesystem: InMemoryFileSystem,
+    mode: Mode,
+    target: Option<Target>,
+    entrypoint: Option<EcoString>,
+) -> TestCompileOutput {
     let initial_files = filesystem.files();
 
     let toml = std::fs::read_to_string(root.join("gleam.toml")).unwrap();
@@ -24,12 +73,13 @@ pub fn prepare(path: &str, mode: Mode) -> String {
 
     let options = Options {
         mode,
-        target: None,
+        target,
         compile: Compile::All,
         codegen: Codegen::All,
         warnings_as_errors: false,
         root_target_support: TargetSupport::Enforced,
         no_print_progress: true,
+        entrypoint,
     };
 
     let compiler = ProjectCompiler::new(
@@ -51,5 +101,5 @@ pub fn prepare(path: &str, mode: Mode) -> String {
     }
     let files = filesystem.into_contents();
     let warnings = warnings.take();
-    test_helpers_rs::TestCompileOutput { files, warnings }.as_overview_text()
+    TestCompileOutput { files, warnings }
 }
@@ -0,0 +1,14 @@
+use gleam_core::build::Target;
+
+#[test]
+fn dead_code_javascript() {
+    let output =
+        crate::prepare_with_entrypoint("./cases/dead_code", Target::JavaScript, "dead_code");
+    insta::assert_snapshot!(output);
+}
+
+#[test]
+fn dead_code_go() {
+    let output = crate::prepare_with_entrypoint("./cases/dead_code", Target::Go, "dead_code");
+    insta::assert_snapshot!(output);
+}
@@ -0,0 +1,148 @@
+package gleam
+
+import (
+	"encoding/binary"
+	"hash/fnv"
+	"math"
+	"testing"
+)
+
+// fnvSum hashes bytes with the standard library's FNV-1, which the inline
+// hash combiner must agree with.
+func fnvSum(b []byte) uint32 {
+	h := fnv.New32()
+	if _, err := h.Write(b); err != nil {
+		panic(err)
+	}
+	return h.Sum32()
+}
+
+func TestHashMatchesFNV(t *testing.T) {
+	for _, i := range []Int_t{0, 1, -1, 1 << 40, math.MinInt64} {
+		want := fnvSum(binary.LittleEndian.AppendUint64(nil, uint64(i)))
+		if got := i.Hash(); got != want {
+			t.Errorf("hash of %d was %d, expected %d", i, got, want)
+		}
+	}
+
+	for _, f := range []Float_t{0, 1.5, -2.25, math.MaxFloat64} {
+		want := fnvSum(binary.LittleEndian.AppendUint64(nil, math.Float64bits(float64(f))))
+		if got := f.Hash(); got != want {
+			t.Errorf("hash of %v was %d, expected %d", f, got, want)
+		}
+	}
+
+	for _, s := range []String_t{"", "a", "Hello, Joe!", "🌵"} {
+		if got, want := s.Hash(), fnvSum([]byte(s)); got != want {
+			t.Errorf("hash of %q was %d, expected %d", s, got, want)
+		}
+	}
+
+	bits := BitArray_t{1, 2, 3}
+	if got, want := bits.Hash(), fnvSum(bits); got != want {
+		t.Errorf("hash of %v was %d, expected %d", bits, got, want)
+	}
+
+	want := fnvSum(binary.LittleEndian.AppendUint32(
+		binary.LittleEndian.AppendUint32(binary.LittleEndian.AppendUint32(nil, 1), 2),
+		3,
+	))
+	if got := HashConstructor(1, 2, 3); got != want {
+		t.Errorf("constructor hash was %d, expected %d", got, want)
+	}
+	if got := HashTuple(1, 2, 3); got != want {
+		t.Errorf("tuple hash was %d, expected %d", got, want)
+	}
+}
+
+func TestHashDoesNotAllocate(t *testing.T) {
+	i := Int_t(42)
+	s := String_t("Hello, Joe!")
+	xs := intList(10)
+	cases := map[string]func(){
+		"Int":       func() { i.Hash() },
+		"String":    func() { s.Hash() },
+		"Tuple":     func() { Tuple2_c[Int_t, String_t]{P_0: i, P_1: s}.Hash() },
+		"List":      func() { xs.Hash() },
+		"Ok":        func() { Ok_c[Int_t, String_t]{P_0: i}.Hash() },
+		"Unordered": func() { h := NewUnorderedCollectionHasher(); h.WriteHash(1); h.Sum() },
+	}
+	for name, hash := range cases {
+		if allocs := testing.AllocsPerRun(100, hash); allocs != 0 {
+			t.Errorf("hashing %s allocated %v times", name, allocs)
+		}
+	}
+}
+
+// The hash.Hash32 based hashing the prelude used to do, kept as a baseline
+// for the benchmarks below.
+func fnvIntHash(i Int_t) uint32 {
+	h := fnv.New32()
+	if _, err := h.Write(binary.LittleEndian.AppendUint64(nil, uint64(i))); err != nil {
+		panic(err)
+	}
+	return h.Sum32()
+}
+
+func BenchmarkHashInt(b *testing.B) {
+	b.Run("inline", func(b *testing.B) {
+		b.ReportAllocs()
+		for i := 0; i < b.N; i++ {
+			Int_t(i).Hash()
+		}
+	})
+	b.Run("hash.Hash32", func(b *testing.B) {
+		b.ReportAllocs()
+		for i := 0; i < b.N; i++ {
+			fnvIntHash(Int_t(i))
+		}
+	})
+}
+
+func BenchmarkHashString(b *testing.B) {
+	s := String_t("The quick brown fox jumps over the lazy dog")
+	b.Run("inline", func(b *testing.B) {
+		b.ReportAllocs()
+		for i := 0; i < b.N; i++ {
+			s.Hash()
+		}
+	})
+	b.Run("hash.Hash32", func(b *testing.B) {
+		b.ReportAllocs()
+		for i := 0; i < b.N; i++ {
+			fnvSum([]byte(s))
+		}
+	})
+}
+
+func TestHashExternal(t *testing.T) {
+	type node struct {
+		Name  string
+		Tags  map[string]int
+		Next  *node
+		Score float64
+	}
+	a := node{Name: "a", Tags: map[string]int{"x": 1, "y": 2}, Score: 0}
+	b := node{Name: "a", Tags: map[string]int{"y": 2, "x": 1}, Score: math.Copysign(0, -1)}
+	if !EqualExternal(a, b) || HashExternal(a) != HashExternal(b) {
+		t.Error("deeply equal values must be equal and hash the same")
+	}
+	b.Tags["x"] = 3
+	if EqualExternal(a, b) {
+		t.Error("values with different maps must not be equal")
+	}
+
+	cyclic := &node{Name: "cycle"}
+	cyclic.Next = cyclic
+	if HashExternal(cyclic) != HashExternal(cyclic) {
+		t.Error("cyclic values must be hashable")
+	}
+}
+
+func BenchmarkHashList(b *testing.B) {
+	xs := intList(1_000)
+	b.ReportAllocs()
+	for i := 0; i < b.N; i++ {
+		xs.Hash()
+	}
+}
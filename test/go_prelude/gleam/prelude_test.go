@@ -0,0 +1,167 @@
+package gleam
+
+import (
+	"bytes"
+	"fmt"
+	"math"
+	"testing"
+)
+
+func intList(n int) List_t[Int_t] {
+	xs := make([]Int_t, n)
+	for i := range xs {
+		xs[i] = Int_t(i)
+	}
+	return ToList(xs...)
+}
+
+func TestIsEmpty(t *testing.T) {
+	if !intList(0).IsEmpty() {
+		t.Error("expected [] to be empty")
+	}
+	if intList(1).IsEmpty() {
+		t.Error("expected [0] not to be empty")
+	}
+}
+
+func TestDivideFloatByZero(t *testing.T) {
+	for _, a := range []Float_t{0, 1.5, -1.5} {
+		if got := DivideFloat(a, 0); got != 0 {
+			t.Errorf("%v /. 0.0 was %v, expected 0.0 as on Erlang", a, got)
+		}
+	}
+}
+
+func TestCheckFloat(t *testing.T) {
+	if got := CheckFloat(1.5, "wibble", 1, "wobble"); got != 1.5 {
+		t.Errorf("CheckFloat(1.5) was %v", got)
+	}
+
+	max := Float_t(math.MaxFloat64)
+	overflowing := map[string]Float_t{
+		"+Inf": max * 2,
+		"-Inf": -max * 2,
+		"NaN":  Float_t(math.NaN()),
+	}
+	for name, f := range overflowing {
+		func() {
+			defer func() {
+				if recover() == nil {
+					t.Errorf("CheckFloat(%s) did not panic, Erlang raises badarith", name)
+				}
+			}()
+			CheckFloat(f, "wibble", 1, "wobble")
+		}()
+	}
+}
+
+func TestCheckGuard(t *testing.T) {
+	if !CheckGuard(func() Bool_t { return CheckFloat(1.5, "wibble", 1, "wobble") > 1 }) {
+		t.Errorf("CheckGuard was false for a guard that matches")
+	}
+
+	max := Float_t(math.MaxFloat64)
+	if CheckGuard(func() Bool_t { return CheckFloat(max*2, "wibble", 1, "wobble") > 1 }) {
+		t.Errorf("CheckGuard was true for an overflowing guard, Erlang fails the guard")
+	}
+
+	defer func() {
+		if recover() == nil {
+			t.Errorf("CheckGuard recovered from a panic other than a float overflow")
+		}
+	}()
+	CheckGuard(func() Bool_t { panic("wibble") })
+}
+
+func TestInspect(t *testing.T) {
+	cases := []struct {
+		value any
+		want  string
+	}{
+		{Int_t(-1), "-1"},
+		{Float_t(2), "2.0"},
+		{Float_t(2.5), "2.5"},
+		{String_t("Hello, \"Joe\""), `"Hello, \"Joe\""`},
+		{Bool_t(true), "True"},
+		{Nil_c{}, "Nil"},
+		{BitArray_t{1, 2}, "<<1, 2>>"},
+		{intList(0), "[]"},
+		{intList(3), "[0, 1, 2]"},
+		{Tuple2_c[Int_t, String_t]{P_0: 1, P_1: "a"}, `#(1, "a")`},
+		{Ok_c[Int_t, Nil_t]{P_0: 1}, "Ok(1)"},
+		{Dynamic_t{Int_t(1)}, "1"},
+		{[]string{"a"}, "//go([a])"},
+		{struct{ Value Int_t }{1}, "//go({1})"},
+	}
+	for _, c := range cases {
+		if got := Inspect(c.value); got != c.want {
+			t.Errorf("Inspect(%#v) was %s, expected %s", c.value, got, c.want)
+		}
+	}
+}
+
+func TestSliceListConversions(t *testing.T) {
+	list := SliceToList([]int64{1, 2, 3}, func(i int64) Int_t { return Int_t(i) })
+	if got := Inspect(list); got != "[1, 2, 3]" {
+		t.Errorf("SliceToList gave %s", got)
+	}
+	slice := ListToSlice(list, func(i Int_t) int64 { return int64(i) })
+	if fmt.Sprint(slice) != "[1 2 3]" {
+		t.Errorf("ListToSlice gave %v", slice)
+	}
+	if empty := ListToSlice(intList(0), func(i Int_t) int64 { return int64(i) }); len(empty) != 0 {
+		t.Errorf("ListToSlice of an empty list gave %v", empty)
+	}
+}
+
+func TestResultToGo(t *testing.T) {
+	toInt := func(i Int_t) int64 { return int64(i) }
+	if value, err := ResultToGo[Int_t, String_t](Ok_c[Int_t, String_t]{P_0: 1}, toInt); value != 1 || err != nil {
+		t.Errorf("ResultToGo of Ok gave %v, %v", value, err)
+	}
+	value, err := ResultToGo[Int_t, String_t](Error_c[Int_t, String_t]{P_0: "no"}, toInt)
+	if value != 0 || err == nil || err.Error() != `"no"` {
+		t.Errorf("ResultToGo of Error gave %v, %v", value, err)
+	}
+	if err := ResultErrorToGo[Nil_t, Nil_t](Ok_c[Nil_t, Nil_t]{}); err != nil {
+		t.Errorf("ResultErrorToGo of Ok gave %v", err)
+	}
+	if err := ResultErrorToGo[Nil_t, Int_t](Error_c[Nil_t, Int_t]{P_0: 2}); err == nil || err.(ResultError).Value != Int_t(2) {
+		t.Errorf("ResultErrorToGo of Error gave %v", err)
+	}
+}
+
+func TestSizedInt(t *testing.T) {
+	cases := []struct {
+		value       Int_t
+		size        Int_t
+		isBigEndian Bool_t
+		want        []byte
+	}{
+		{257, 8, true, []byte{1}},
+		{257, 24, true, []byte{0, 1, 1}},
+		{4_294_967_297, 40, true, []byte{1, 0, 0, 0, 1}},
+		{100_000, 24, false, []byte{160, 134, 1}},
+		{-1, 32, true, []byte{255, 255, 255, 255}},
+		{-1, 72, true, []byte{255, 255, 255, 255, 255, 255, 255, 255, 255}},
+		{256, -1, true, []byte{}},
+	}
+	for _, c := range cases {
+		if got := SizedInt(c.value, c.size, c.isBigEndian); !bytes.Equal(got, c.want) {
+			t.Errorf("SizedInt(%d, %d, %v) was %v, expected %v", c.value, c.size, c.isBigEndian, got, c.want)
+		}
+	}
+}
+
+func TestIntFromSlice(t *testing.T) {
+	bits := BitArray_t{255, 255, 255, 255, 240, 216, 255}
+	if got := bits.IntFromSlice(0, 2, true, false); got != 65_535 {
+		t.Errorf("unsigned 16-bit read was %d", got)
+	}
+	if got := bits.IntFromSlice(2, 7, false, true); got != -655_294_465 {
+		t.Errorf("signed 40-bit little endian read was %d", got)
+	}
+	if got := (BitArray_t{255, 255, 255, 255, 255, 255, 255, 255}).IntFromSlice(0, 8, true, true); got != -1 {
+		t.Errorf("signed 64-bit read was %d", got)
+	}
+}
@@ -0,0 +1,3 @@
+module example.com/todo
+
+go 1.24rc2
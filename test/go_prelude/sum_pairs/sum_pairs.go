@@ -0,0 +1,31 @@
+package my_mod_P
+
+import (
+  gleam_P "example.com/todo/gleam"
+)
+
+const _ = gleam_P.Use_Import
+const Use_Import byte = 0
+
+func SumPairs(loop_xs gleam_P.List_t[gleam_P.Int_t], loop_acc gleam_P.Int_t) gleam_P.Int_t {
+  for {
+    var xs gleam_P.List_t[gleam_P.Int_t] = loop_xs
+    var acc gleam_P.Int_t = loop_acc
+    if !xs.IsEmpty() && !xs.Tail().IsEmpty() {
+      var a gleam_P.Int_t = xs.Head()
+      _ = a
+      var b gleam_P.Int_t = xs.Tail().Head()
+      _ = b
+      var rest gleam_P.List_t[gleam_P.Int_t] = xs.Tail().Tail()
+      _ = rest
+      loop_xs = rest
+      loop_acc = (acc + a) + b
+    } else if !xs.IsEmpty() && xs.Tail().IsEmpty() {
+      var a gleam_P.Int_t = xs.Head()
+      _ = a
+      return acc + a
+    } else {
+      return acc
+    }
+  }
+}
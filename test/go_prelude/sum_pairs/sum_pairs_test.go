@@ -0,0 +1,43 @@
+package my_mod_P
+
+import (
+	"fmt"
+	"testing"
+
+	gleam_P "example.com/todo/gleam"
+)
+
+// sum_pairs.go is the compiler's output for `sum_pairs`, kept up to date by
+// the `sum_pairs_go_fixture_is_up_to_date` test in compiler-core.
+
+func intList(n int) gleam_P.List_t[gleam_P.Int_t] {
+	xs := make([]gleam_P.Int_t, n)
+	for i := range xs {
+		xs[i] = gleam_P.Int_t(i)
+	}
+	return gleam_P.ToList(xs...)
+}
+
+func TestSumPairs(t *testing.T) {
+	for _, size := range []int{0, 1, 2, 3, 10, 11} {
+		want := gleam_P.Int_t(size * (size - 1) / 2)
+		if got := SumPairs(intList(size), 0); got != want {
+			t.Errorf("SumPairs of a %d element list was %d, expected %d", size, got, want)
+		}
+	}
+}
+
+// List pattern matching must cost time proportional to the depth of the
+// pattern, not the length of the list, so ns/element stays flat as the list
+// grows. A quadratic regression shows up as ns/element growing with size.
+func BenchmarkListPattern(b *testing.B) {
+	for _, size := range []int{100, 1_000, 10_000, 100_000} {
+		xs := intList(size)
+		b.Run(fmt.Sprint(size), func(b *testing.B) {
+			for i := 0; i < b.N; i++ {
+				SumPairs(xs, 0)
+			}
+			b.ReportMetric(float64(b.Elapsed().Nanoseconds())/float64(b.N*size), "ns/element")
+		})
+	}
+}
@@ -0,0 +1,47 @@
+package ffi_P
+
+import (
+	"fmt"
+	"os"
+
+	gleam_P "example.com/todo/gleam"
+)
+
+type Dynamic_t = gleam_P.Dynamic_t
+
+func Append(a gleam_P.String_t, b gleam_P.String_t) gleam_P.String_t {
+	return a + b
+}
+
+func Print(s gleam_P.String_t) gleam_P.Nil_t {
+	fmt.Print(string(s))
+	return gleam_P.Nil_t{}
+}
+
+func ToString[A gleam_P.Type[A]](a A) gleam_P.String_t {
+	return gleam_P.String_t(gleam_P.Inspect(a))
+}
+
+func FileExists(path gleam_P.String_t) gleam_P.Bool_t {
+	info, err := os.Stat(string(path))
+	return gleam_P.Bool_t(err == nil && info.Mode().IsRegular())
+}
+
+func Halt(code gleam_P.Int_t) gleam_P.Nil_t {
+	os.Exit(int(code))
+	return gleam_P.Nil_t{}
+}
+
+func ToDynamic[X gleam_P.Type[X]](x X) Dynamic_t {
+	return Dynamic_t{Value: x}
+}
+
+func Crashes[A gleam_P.Type[A]](f gleam_P.Func0_t[A]) (crashed gleam_P.Bool_t) {
+	defer func() {
+		if recover() != nil {
+			crashed = true
+		}
+	}()
+	f()
+	return false
+}
@@ -50,3 +50,12 @@ export function halt(code) {
 export function toDynamic(a) {
   return a;
 }
+
+export function crashes(f) {
+  try {
+    f();
+    return false;
+  } catch {
+    return true;
+  }
+}